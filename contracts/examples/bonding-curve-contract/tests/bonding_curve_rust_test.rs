@@ -0,0 +1,380 @@
+use bonding_curve_contract::*;
+use dharitri_wasm::{
+    dharitri_codec::multi_types::OptionalValue,
+    types::{Address, BigUint, ManagedVec},
+};
+use dharitri_wasm_debug::{
+    managed_biguint, managed_token_id, rust_biguint, testing_framework::*, DebugApi,
+};
+use dharitri_wasm_modules::bonding_curve::{
+    curves::{
+        exponential_function::ExponentialFunction,
+        linear_function::LinearFunction,
+        logarithmic_function::LogarithmicFunction,
+        piecewise_linear_function::{CurvePoint, PiecewiseLinearFunction},
+        power_function::PowerFunction,
+        sigmoid_function::SigmoidFunction,
+    },
+    function_selector::FunctionSelector,
+    utils::{owner_endpoints::OwnerEndpointsModule, user_endpoints::UserEndpointsModule},
+};
+
+const CURVE_TOKEN_ID: &[u8] = b"CURVE-123456";
+const PAYMENT_TOKEN_ID: &[u8] = b"PAY-123456";
+const DEPOSIT_AMOUNT: u64 = 1_000;
+const BUYER_BALANCE: u64 = 100_000_000;
+const BATCH_AMOUNT: u64 = 100;
+const WASM_PATH: &str = "output/bonding-curve-contract.wasm";
+
+struct BondingCurveSetup<BondingCurveObjBuilder>
+where
+    BondingCurveObjBuilder: 'static + Copy + Fn() -> bonding_curve_contract::ContractObj<DebugApi>,
+{
+    pub blockchain_wrapper: BlockchainStateWrapper,
+    pub owner_address: Address,
+    pub buyer_address: Address,
+    pub bc_wrapper:
+        ContractObjWrapper<bonding_curve_contract::ContractObj<DebugApi>, BondingCurveObjBuilder>,
+}
+
+fn setup_bonding_curve<BondingCurveObjBuilder>(
+    bc_builder: BondingCurveObjBuilder,
+    curve_builder: fn() -> FunctionSelector<DebugApi>,
+) -> BondingCurveSetup<BondingCurveObjBuilder>
+where
+    BondingCurveObjBuilder: 'static + Copy + Fn() -> bonding_curve_contract::ContractObj<DebugApi>,
+{
+    let rust_zero = rust_biguint!(0u64);
+    let mut blockchain_wrapper = BlockchainStateWrapper::new();
+    let owner_address = blockchain_wrapper.create_user_account(&rust_zero);
+    let buyer_address = blockchain_wrapper.create_user_account(&rust_zero);
+    let bc_wrapper = blockchain_wrapper.create_sc_account(
+        &rust_zero,
+        Some(&owner_address),
+        bc_builder,
+        WASM_PATH,
+    );
+
+    blockchain_wrapper.set_dct_balance(
+        &owner_address,
+        CURVE_TOKEN_ID,
+        &rust_biguint!(DEPOSIT_AMOUNT),
+    );
+    blockchain_wrapper.set_dct_balance(
+        &buyer_address,
+        PAYMENT_TOKEN_ID,
+        &rust_biguint!(BUYER_BALANCE),
+    );
+
+    blockchain_wrapper
+        .execute_tx(&owner_address, &bc_wrapper, &rust_zero, |sc| {
+            sc.init();
+        })
+        .assert_ok();
+
+    blockchain_wrapper
+        .execute_dct_transfer(
+            &owner_address,
+            &bc_wrapper,
+            CURVE_TOKEN_ID,
+            0,
+            &rust_biguint!(DEPOSIT_AMOUNT),
+            |sc| {
+                sc.deposit(
+                    managed_biguint!(DEPOSIT_AMOUNT),
+                    managed_token_id!(CURVE_TOKEN_ID),
+                    0,
+                    OptionalValue::Some(managed_token_id!(PAYMENT_TOKEN_ID)),
+                );
+            },
+        )
+        .assert_ok();
+
+    blockchain_wrapper
+        .execute_tx(&owner_address, &bc_wrapper, &rust_zero, |sc| {
+            sc.set_bonding_curve(managed_token_id!(CURVE_TOKEN_ID), curve_builder(), true);
+        })
+        .assert_ok();
+
+    BondingCurveSetup {
+        blockchain_wrapper,
+        owner_address,
+        buyer_address,
+        bc_wrapper,
+    }
+}
+
+impl<BondingCurveObjBuilder> BondingCurveSetup<BondingCurveObjBuilder>
+where
+    BondingCurveObjBuilder: 'static + Copy + Fn() -> bonding_curve_contract::ContractObj<DebugApi>,
+{
+    fn buy_price(&mut self, amount: u64) -> u64 {
+        let mut price = 0u64;
+        self.blockchain_wrapper
+            .execute_query(&self.bc_wrapper, |sc| {
+                price = sc
+                    .get_buy_price(managed_biguint!(amount), managed_token_id!(CURVE_TOKEN_ID))
+                    .to_u64()
+                    .unwrap();
+            })
+            .assert_ok();
+        price
+    }
+
+    fn sell_price(&mut self, amount: u64) -> u64 {
+        let mut price = 0u64;
+        self.blockchain_wrapper
+            .execute_query(&self.bc_wrapper, |sc| {
+                price = sc
+                    .get_sell_price(managed_biguint!(amount), managed_token_id!(CURVE_TOKEN_ID))
+                    .to_u64()
+                    .unwrap();
+            })
+            .assert_ok();
+        price
+    }
+
+    fn payment_balance(&self) -> u64 {
+        let balance =
+            self.blockchain_wrapper
+                .get_dct_balance(&self.buyer_address, PAYMENT_TOKEN_ID, 0);
+        balance.to_u64_digits().first().copied().unwrap_or_default()
+    }
+
+    /// Buys `amount` tokens, returns how much was paid.
+    fn buy(&mut self, amount: u64) -> u64 {
+        let balance_before = self.payment_balance();
+        let offered = balance_before;
+        self.blockchain_wrapper
+            .execute_dct_transfer(
+                &self.buyer_address,
+                &self.bc_wrapper,
+                PAYMENT_TOKEN_ID,
+                0,
+                &rust_biguint!(offered),
+                |sc| {
+                    sc.buy_token(
+                        managed_biguint!(offered),
+                        managed_token_id!(PAYMENT_TOKEN_ID),
+                        managed_biguint!(amount),
+                        managed_token_id!(CURVE_TOKEN_ID),
+                        OptionalValue::None,
+                    );
+                },
+            )
+            .assert_ok();
+        balance_before - self.payment_balance()
+    }
+
+    /// Sells `amount` tokens back, returns how much was received.
+    fn sell(&mut self, amount: u64) -> u64 {
+        let balance_before = self.payment_balance();
+        self.blockchain_wrapper
+            .execute_dct_transfer(
+                &self.buyer_address,
+                &self.bc_wrapper,
+                CURVE_TOKEN_ID,
+                0,
+                &rust_biguint!(amount),
+                |sc| {
+                    sc.sell_token(
+                        managed_biguint!(amount),
+                        0,
+                        managed_token_id!(CURVE_TOKEN_ID),
+                    );
+                },
+            )
+            .assert_ok();
+        self.payment_balance() - balance_before
+    }
+}
+
+/// Buys two batches, then sells them back.
+/// Each sale has to pay back exactly what the matching purchase cost.
+fn check_round_trip(
+    curve_builder: fn() -> FunctionSelector<DebugApi>,
+    expected_first_batch_price: f64,
+) {
+    let mut bc_setup = setup_bonding_curve(bonding_curve_contract::contract_obj, curve_builder);
+
+    let first_batch_price = bc_setup.buy_price(BATCH_AMOUNT);
+    let relative_error =
+        (first_batch_price as f64 - expected_first_batch_price).abs() / expected_first_batch_price;
+    assert!(
+        relative_error < 0.000_1,
+        "price {} too far from the expected {}",
+        first_batch_price,
+        expected_first_batch_price
+    );
+
+    assert_eq!(bc_setup.buy(BATCH_AMOUNT), first_batch_price);
+    let second_batch_price = bc_setup.buy_price(BATCH_AMOUNT);
+    assert_eq!(bc_setup.buy(BATCH_AMOUNT), second_batch_price);
+    bc_setup.blockchain_wrapper.check_dct_balance(
+        &bc_setup.buyer_address,
+        CURVE_TOKEN_ID,
+        &rust_biguint!(2 * BATCH_AMOUNT),
+    );
+
+    assert_eq!(bc_setup.sell_price(BATCH_AMOUNT), second_batch_price);
+    assert_eq!(bc_setup.sell(BATCH_AMOUNT), second_batch_price);
+    assert_eq!(bc_setup.sell_price(BATCH_AMOUNT), first_batch_price);
+    assert_eq!(bc_setup.sell(BATCH_AMOUNT), first_batch_price);
+
+    assert_eq!(bc_setup.payment_balance(), BUYER_BALANCE);
+    bc_setup.blockchain_wrapper.check_dct_balance(
+        bc_setup.bc_wrapper.address_ref(),
+        PAYMENT_TOKEN_ID,
+        &rust_biguint!(0),
+    );
+}
+
+#[test]
+fn linear_round_trip_test() {
+    check_round_trip(
+        || {
+            FunctionSelector::Linear(LinearFunction {
+                initial_price: managed_biguint!(10),
+                linear_coefficient: managed_biguint!(1),
+            })
+        },
+        // sum of x + 10, for x in 0..100
+        5_950.0,
+    );
+}
+
+#[test]
+fn power_round_trip_test() {
+    check_round_trip(
+        || {
+            FunctionSelector::Power(PowerFunction {
+                coefficient: managed_biguint!(3),
+                exponent: 2,
+            })
+        },
+        // integral of 3x^2 over [0, 100]
+        1_000_000.0,
+    );
+}
+
+#[test]
+fn exponential_round_trip_test() {
+    check_round_trip(
+        || {
+            FunctionSelector::Exponential(ExponentialFunction {
+                initial_price: managed_biguint!(1_000),
+                scale: managed_biguint!(50),
+            })
+        },
+        // integral of 1000 * e^(x / 50) over [0, 100]
+        1_000.0 * 50.0 * (2f64.exp() - 1.0),
+    );
+}
+
+#[test]
+fn logarithmic_round_trip_test() {
+    check_round_trip(
+        || {
+            FunctionSelector::Logarithmic(LogarithmicFunction {
+                coefficient: managed_biguint!(1_000),
+                scale: managed_biguint!(100),
+            })
+        },
+        // integral of 1000 * ln(1 + x / 100) over [0, 100]
+        1_000.0 * (200.0 * 2f64.ln() - 100.0),
+    );
+}
+
+#[test]
+fn sigmoid_round_trip_test() {
+    check_round_trip(
+        || {
+            FunctionSelector::Sigmoid(SigmoidFunction {
+                max_price: managed_biguint!(1_000),
+                midpoint: managed_biguint!(100),
+                scale: managed_biguint!(20),
+            })
+        },
+        // integral of 1000 / (1 + e^(-(x - 100) / 20)) over [0, 100]
+        1_000.0 * 20.0 * (2f64.ln() - (1.0 + (-5f64).exp()).ln()),
+    );
+}
+
+#[test]
+fn piecewise_linear_round_trip_test() {
+    check_round_trip(
+        || {
+            let mut points = ManagedVec::new();
+            points.push(CurvePoint {
+                supply: managed_biguint!(0),
+                price: managed_biguint!(10),
+            });
+            points.push(CurvePoint {
+                supply: managed_biguint!(50),
+                price: managed_biguint!(110),
+            });
+            points.push(CurvePoint {
+                supply: managed_biguint!(150),
+                price: managed_biguint!(110),
+            });
+            FunctionSelector::PiecewiseLinear(PiecewiseLinearFunction { points })
+        },
+        // trapezoid from 10 to 110 over [0, 50], then flat at 110 over [50, 100]
+        3_000.0 + 5_500.0,
+    );
+}
+
+#[test]
+fn invalid_curve_test() {
+    let rust_zero = rust_biguint!(0u64);
+    let mut bc_setup = setup_bonding_curve(bonding_curve_contract::contract_obj, || {
+        FunctionSelector::Linear(LinearFunction {
+            initial_price: managed_biguint!(10),
+            linear_coefficient: managed_biguint!(1),
+        })
+    });
+
+    bc_setup
+        .blockchain_wrapper
+        .execute_tx(
+            &bc_setup.owner_address,
+            &bc_setup.bc_wrapper,
+            &rust_zero,
+            |sc| {
+                let mut points = ManagedVec::new();
+                points.push(CurvePoint {
+                    supply: managed_biguint!(50),
+                    price: managed_biguint!(10),
+                });
+                points.push(CurvePoint {
+                    supply: managed_biguint!(50),
+                    price: managed_biguint!(20),
+                });
+                sc.set_bonding_curve(
+                    managed_token_id!(CURVE_TOKEN_ID),
+                    FunctionSelector::PiecewiseLinear(PiecewiseLinearFunction { points }),
+                    true,
+                );
+            },
+        )
+        .assert_user_error("Invalid bonding curve parameters");
+
+    bc_setup
+        .blockchain_wrapper
+        .execute_tx(
+            &bc_setup.owner_address,
+            &bc_setup.bc_wrapper,
+            &rust_zero,
+            |sc| {
+                sc.set_bonding_curve(
+                    managed_token_id!(CURVE_TOKEN_ID),
+                    FunctionSelector::Exponential(ExponentialFunction {
+                        initial_price: managed_biguint!(10),
+                        scale: BigUint::zero(),
+                    }),
+                    true,
+                );
+            },
+        )
+        .assert_user_error("Invalid bonding curve parameters");
+}
@@ -0,0 +1,55 @@
+use dharitri_wasm::contract_base::ContractAbiProvider;
+use dharitri_wasm_debug::{coverage::*, *};
+
+const CONTRACT_EXPR: &str = "file:output/ping-pong-moax.wasm";
+
+fn world(report_dir: &str) -> BlockchainMock {
+    let mut blockchain = BlockchainMock::new();
+    blockchain.set_current_dir_from_workspace("contracts/examples/ping-pong-moax");
+    blockchain.enable_coverage(report_dir);
+
+    blockchain.register_contract_builder(CONTRACT_EXPR, ping_pong_moax::ContractBuilder);
+    blockchain.register_coverage_abi(CONTRACT_EXPR, &ping_pong_moax::AbiProvider::abi());
+    blockchain
+}
+
+#[test]
+fn ping_pong_coverage_rs() {
+    let report_dir = std::env::temp_dir().join("ping-pong-moax-denali-coverage");
+    let _ = std::fs::remove_dir_all(&report_dir);
+    let report_dir_str = report_dir.to_str().unwrap();
+
+    dharitri_wasm_debug::denali_rs(
+        "denali/ping-pong-call-ping.scen.json",
+        world(report_dir_str),
+    );
+    dharitri_wasm_debug::denali_rs(
+        "denali/ping-pong-call-ping-twice.scen.json",
+        world(report_dir_str),
+    );
+
+    let json = std::fs::read_to_string(report_dir.join(COVERAGE_JSON_FILE_NAME)).unwrap();
+    let report = CoverageReport::from_json_str(json.as_str());
+    let contract_coverage = &report.contracts[CONTRACT_EXPR];
+
+    let init_coverage = &contract_coverage.endpoints["init"];
+    assert_eq!(init_coverage.calls, 2);
+
+    let ping_coverage = &contract_coverage.endpoints["ping"];
+    assert_eq!(ping_coverage.calls, 3);
+    assert_eq!(ping_coverage.user_errors["can only ping once"], 1);
+    assert_eq!(ping_coverage.scenarios.len(), 2);
+
+    let uncovered = contract_coverage.uncovered_endpoints();
+    assert!(uncovered.contains(&"pong"));
+    assert!(uncovered.contains(&"pongAll"));
+    assert!(!uncovered.contains(&"ping"));
+
+    assert!(contract_coverage.storage_writes.contains("pingAmount"));
+    assert!(contract_coverage.storage_writes.contains("userStatus[..]"));
+    assert!(contract_coverage.storage_reads.contains("deadline"));
+
+    let summary = std::fs::read_to_string(report_dir.join(COVERAGE_SUMMARY_FILE_NAME)).unwrap();
+    assert!(summary.contains("    pong: NOT COVERED"));
+    assert!(summary.contains("        user error \"can only ping once\": 1"));
+}
@@ -0,0 +1,56 @@
+dharitri_wasm::derive_imports!();
+
+/// Encodes a u32 on a single byte.
+pub mod compact_u32 {
+    use dharitri_wasm::dharitri_codec::{
+        DecodeErrorHandler, EncodeErrorHandler, NestedDecode, NestedDecodeInput, NestedEncode,
+        NestedEncodeOutput,
+    };
+
+    pub type AbiType = u8;
+
+    pub fn dep_encode_or_handle_err<O, H>(
+        value: &u32,
+        dest: &mut O,
+        h: H,
+    ) -> Result<(), H::HandledErr>
+    where
+        O: NestedEncodeOutput,
+        H: EncodeErrorHandler,
+    {
+        (*value as u8).dep_encode_or_handle_err(dest, h)
+    }
+
+    pub fn dep_decode_or_handle_err<I, H>(input: &mut I, h: H) -> Result<u32, H::HandledErr>
+    where
+        I: NestedDecodeInput,
+        H: DecodeErrorHandler,
+    {
+        Ok(u8::dep_decode_or_handle_err(input, h)? as u32)
+    }
+}
+
+/// Tests that the ABI reflects the codec attributes.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
+pub enum AbiEnumWithCodecAttributes {
+    #[codec(discriminant = 1)]
+    One,
+    Two {
+        #[codec(skip)]
+        cache: u64,
+        #[codec(with = compact_u32)]
+        compact: u32,
+        #[codec(default)]
+        added_later: u16,
+    },
+    #[codec(discriminant = 0)]
+    Zero,
+}
+
+/// Tests that the ABI shows the version tag of versioned structs.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
+#[codec(version = 2)]
+pub struct AbiStructWithVersion {
+    pub value: u32,
+    pub flag: bool,
+}
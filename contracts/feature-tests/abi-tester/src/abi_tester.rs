@@ -3,10 +3,12 @@
 
 dharitri_wasm::imports!();
 
+mod abi_codec_attributes;
 mod abi_enum;
 mod abi_test_type;
 mod only_nested;
 
+use abi_codec_attributes::*;
 use abi_enum::*;
 use abi_test_type::*;
 use only_nested::*;
@@ -37,6 +39,19 @@ pub trait AbiTester {
         e
     }
 
+    #[endpoint]
+    fn echo_codec_attributes(
+        &self,
+        e: AbiEnumWithCodecAttributes,
+    ) -> AbiEnumWithCodecAttributes {
+        e
+    }
+
+    #[endpoint]
+    fn echo_versioned_struct(&self, s: AbiStructWithVersion) -> AbiStructWithVersion {
+        s
+    }
+
     #[endpoint]
     #[only_owner]
     fn take_managed_type(&self, _arg: AbiManagedType<Self::Api>) {}
@@ -0,0 +1,15 @@
+use dharitri_wasm_debug::*;
+
+fn world() -> BlockchainMock {
+    let mut blockchain = BlockchainMock::new();
+    blockchain.set_current_dir_from_workspace("contracts/feature-tests/abi-tester");
+
+    blockchain
+        .register_contract_builder("file:output/abi-tester.wasm", abi_tester::ContractBuilder);
+    blockchain
+}
+
+#[test]
+fn abi_tester_typed_values_rs() {
+    dharitri_wasm_debug::denali_rs("denali/abi_tester_typed_values.scen.json", world());
+}
@@ -9,7 +9,9 @@ dharitri_wasm_node::wasm_endpoints! {
     (
         address_vs_h256
         echo_abi_test_type
+        echo_codec_attributes
         echo_enum
+        echo_versioned_struct
         dct_local_role
         dct_token_data
         dct_token_payment
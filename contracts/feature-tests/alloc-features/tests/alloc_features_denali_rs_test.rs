@@ -16,40 +16,40 @@ fn boxed_bytes_zeros_rs() {
     dharitri_wasm_debug::denali_rs("denali/boxed_bytes_zeros.scen.json", world());
 }
 
-// #[test]
-// fn crypto_elliptic_curves_rs() {
-//     dharitri_wasm_debug::denali_rs("denali/crypto_elliptic_curves.scen.json", world());
-// }
+#[test]
+fn crypto_elliptic_curves_rs() {
+    dharitri_wasm_debug::denali_rs("denali/crypto_elliptic_curves.scen.json", world());
+}
 
 #[test]
 fn crypto_keccak256_legacy_alloc_rs() {
     dharitri_wasm_debug::denali_rs("denali/crypto_keccak256_legacy_alloc.scen.json", world());
 }
 
-// #[test]
-// fn crypto_ripemd160_rs() {
-//     dharitri_wasm_debug::denali_rs("denali/crypto_ripemd160.scen.json", world());
-// }
+#[test]
+fn crypto_ripemd160_rs() {
+    dharitri_wasm_debug::denali_rs("denali/crypto_ripemd160.scen.json", world());
+}
 
 #[test]
 fn crypto_sha256_legacy_alloc_rs() {
     dharitri_wasm_debug::denali_rs("denali/crypto_sha256_legacy_alloc.scen.json", world());
 }
 
-// #[test]
-// fn crypto_verify_bls_rs() {
-//     dharitri_wasm_debug::denali_rs("denali/crypto_verify_bls.scen.json", world());
-// }
+#[test]
+fn crypto_verify_bls_rs() {
+    dharitri_wasm_debug::denali_rs("denali/crypto_verify_bls.scen.json", world());
+}
 
 #[test]
 fn crypto_verify_ed25519_rs() {
     dharitri_wasm_debug::denali_rs("denali/crypto_verify_ed25519.scen.json", world());
 }
 
-// #[test]
-// fn crypto_verify_secp256k1_rs() {
-//     dharitri_wasm_debug::denali_rs("denali/crypto_verify_secp256k1.scen.json", world());
-// }
+#[test]
+fn crypto_verify_secp256k1_rs() {
+    dharitri_wasm_debug::denali_rs("denali/crypto_verify_secp256k1.scen.json", world());
+}
 
 #[test]
 fn echo_async_result_empty_rs() {
@@ -226,12 +226,6 @@ fn only_owner_rs() {
     dharitri_wasm_debug::denali_rs("denali/only_owner.scen.json", world());
 }
 
-// Will never run in denali-rs.
-// #[test]
-// fn out_of_gas_rs() {
-//     dharitri_wasm_debug::denali_rs("denali/out_of_gas.scen.json", world());
-// }
-
 #[test]
 fn panic_rs() {
     dharitri_wasm_debug::denali_rs("denali/panic.scen.json", world());
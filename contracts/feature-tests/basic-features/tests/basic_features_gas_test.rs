@@ -0,0 +1,24 @@
+use dharitri_wasm_debug::*;
+
+fn world() -> BlockchainMock {
+    let mut blockchain = BlockchainMock::new();
+    blockchain.set_current_dir_from_workspace("contracts/feature-tests/basic-features");
+    blockchain.load_gas_schedule("tests/gas_schedule.toml");
+
+    blockchain.register_contract_builder(
+        "file:output/basic-features.wasm",
+        basic_features::ContractBuilder,
+    );
+
+    blockchain
+}
+
+#[test]
+fn gas_metering_rs() {
+    dharitri_wasm_debug::denali_rs("tests/denali-rs/gas_metering.scen.json", world());
+}
+
+#[test]
+fn out_of_gas_rs() {
+    dharitri_wasm_debug::denali_rs("denali/out_of_gas.scen.json", world());
+}
@@ -13,6 +13,11 @@ fn forw_raw_async_echo_go() {
     dharitri_wasm_debug::denali_go("denali/forw_raw_async_echo.scen.json");
 }
 
+#[test]
+fn forw_raw_async_nested_go() {
+    dharitri_wasm_debug::denali_go("denali/forw_raw_async_nested.scen.json");
+}
+
 #[test]
 fn forw_raw_async_send_and_retrieve_multi_transfer_funds_go() {
     dharitri_wasm_debug::denali_go(
@@ -20,6 +25,16 @@ fn forw_raw_async_send_and_retrieve_multi_transfer_funds_go() {
     );
 }
 
+#[test]
+fn forw_raw_async_reject_dct_go() {
+    dharitri_wasm_debug::denali_go("denali/forw_raw_async_reject_dct.scen.json");
+}
+
+#[test]
+fn forw_raw_async_retrieve_dct_go() {
+    dharitri_wasm_debug::denali_go("denali/forw_raw_async_retrieve_dct.scen.json");
+}
+
 #[test]
 fn forw_raw_builtin_nft_local_mint_via_async_call_go() {
     dharitri_wasm_debug::denali_go("denali/forw_raw_builtin_nft_local_mint_via_async_call.scen.json");
@@ -47,6 +47,11 @@ fn forw_raw_async_echo_rs() {
     dharitri_wasm_debug::denali_rs("denali/forw_raw_async_echo.scen.json", world());
 }
 
+#[test]
+fn forw_raw_async_nested_rs() {
+    dharitri_wasm_debug::denali_rs("denali/forw_raw_async_nested.scen.json", world());
+}
+
 // #[test]
 // fn forw_raw_async_send_and_retrieve_multi_transfer_funds_rs() {
 //     dharitri_wasm_debug::denali_rs(
@@ -55,6 +60,16 @@ fn forw_raw_async_echo_rs() {
 //     );
 // }
 
+#[test]
+fn forw_raw_async_reject_dct_rs() {
+    dharitri_wasm_debug::denali_rs("denali/forw_raw_async_reject_dct.scen.json", world());
+}
+
+#[test]
+fn forw_raw_async_retrieve_dct_rs() {
+    dharitri_wasm_debug::denali_rs("denali/forw_raw_async_retrieve_dct.scen.json", world());
+}
+
 #[test]
 fn forw_raw_builtin_nft_local_mint_via_async_call_rs() {
     dharitri_wasm_debug::denali_rs(
@@ -469,15 +484,20 @@ fn forwarder_send_dct_multi_transfer_rs() {
     );
 }
 
-// #[test]
-// fn forwarder_send_twice_moax_rs() {
-//     dharitri_wasm_debug::denali_rs("denali/forwarder_send_twice_moax.scen.json", world());
-// }
+#[test]
+fn forwarder_send_twice_moax_rs() {
+    dharitri_wasm_debug::denali_rs("denali/forwarder_send_twice_moax.scen.json", world());
+}
 
-// #[test]
-// fn forwarder_send_twice_dct_rs() {
-//     dharitri_wasm_debug::denali_rs("denali/forwarder_send_twice_dct.scen.json", world());
-// }
+#[test]
+fn forwarder_send_twice_dct_rs() {
+    dharitri_wasm_debug::denali_rs("denali/forwarder_send_twice_dct.scen.json", world());
+}
+
+#[test]
+fn forwarder_system_sc_issue_rs() {
+    dharitri_wasm_debug::denali_rs("denali/forwarder_system_sc_issue.scen.json", world());
+}
 
 #[test]
 fn forwarder_sync_echo_rs() {
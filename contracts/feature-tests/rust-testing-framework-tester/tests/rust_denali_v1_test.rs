@@ -1,6 +1,7 @@
 use adder::*;
 use forwarder::call_sync::*;
 use num_traits::ToPrimitive;
+use std::{cell::Cell, rc::Rc};
 
 use dharitri_wasm::{
     dharitri_codec::Empty,
@@ -343,6 +344,115 @@ fn test_dct_payment_reverted() {
     wrapper.check_dct_balance(sc_wrapper.address_ref(), token_id, &rust_biguint!(2_000));
 }
 
+#[test]
+fn test_dct_payment_paused() {
+    let mut wrapper = BlockchainStateWrapper::new();
+    let rust_zero = rust_biguint!(0);
+
+    let caller_addr = wrapper.create_user_account(&rust_zero);
+    let sc_wrapper = wrapper.create_sc_account(
+        &rust_zero,
+        None,
+        rust_testing_framework_tester::contract_obj,
+        SC_WASM_PATH,
+    );
+    let token_id = &b"COOL-123456"[..];
+
+    wrapper.set_dct_balance(&caller_addr, token_id, &rust_biguint!(1_000));
+    wrapper.set_dct_token_paused(token_id, true);
+
+    wrapper
+        .execute_dct_transfer(
+            &caller_addr,
+            &sc_wrapper,
+            token_id,
+            0,
+            &rust_biguint!(1_000),
+            |sc| {
+                sc.receive_dct();
+            },
+        )
+        .assert_error(10, "dct token is paused");
+    wrapper.check_dct_balance(&caller_addr, token_id, &rust_biguint!(1_000));
+
+    wrapper.set_dct_token_paused(token_id, false);
+    wrapper
+        .execute_dct_transfer(
+            &caller_addr,
+            &sc_wrapper,
+            token_id,
+            0,
+            &rust_biguint!(1_000),
+            |sc| {
+                sc.receive_dct();
+            },
+        )
+        .assert_ok();
+    wrapper.check_dct_balance(sc_wrapper.address_ref(), token_id, &rust_biguint!(1_000));
+}
+
+#[test]
+fn test_dct_payment_limited_transfer() {
+    let mut wrapper = BlockchainStateWrapper::new();
+    let rust_zero = rust_biguint!(0);
+
+    let caller_addr = wrapper.create_user_account(&rust_zero);
+    let role_holder_addr = wrapper.create_user_account(&rust_zero);
+    let sc_wrapper = wrapper.create_sc_account(
+        &rust_zero,
+        None,
+        rust_testing_framework_tester::contract_obj,
+        SC_WASM_PATH,
+    );
+    let token_id = &b"COOL-123456"[..];
+
+    wrapper.set_dct_balance(&caller_addr, token_id, &rust_biguint!(1_000));
+    wrapper.set_dct_balance(&role_holder_addr, token_id, &rust_biguint!(1_000));
+    wrapper.set_dct_local_roles(&role_holder_addr, token_id, &[DctLocalRole::Transfer]);
+
+    // only the role holder can send the token
+    wrapper
+        .execute_dct_transfer(
+            &caller_addr,
+            &sc_wrapper,
+            token_id,
+            0,
+            &rust_biguint!(500),
+            |sc| {
+                sc.receive_dct();
+            },
+        )
+        .assert_error(10, "action is not allowed");
+    wrapper
+        .execute_dct_transfer(
+            &role_holder_addr,
+            &sc_wrapper,
+            token_id,
+            0,
+            &rust_biguint!(500),
+            |sc| {
+                sc.receive_dct();
+            },
+        )
+        .assert_ok();
+
+    // removing the role lifts the restriction
+    wrapper.set_dct_local_roles(&role_holder_addr, token_id, &[]);
+    wrapper
+        .execute_dct_transfer(
+            &caller_addr,
+            &sc_wrapper,
+            token_id,
+            0,
+            &rust_biguint!(500),
+            |sc| {
+                sc.receive_dct();
+            },
+        )
+        .assert_ok();
+    wrapper.check_dct_balance(sc_wrapper.address_ref(), token_id, &rust_biguint!(1_000));
+}
+
 #[test]
 fn test_nft_balance() {
     let mut wrapper = BlockchainStateWrapper::new();
@@ -840,6 +950,90 @@ fn storage_set_test() {
         .assert_ok();
 }
 
+#[test]
+fn snapshot_restore_test() {
+    let rust_zero = rust_biguint!(0);
+    let mut wrapper = BlockchainStateWrapper::new();
+    let user_addr = wrapper.create_user_account(&rust_biguint!(100));
+    let sc_wrapper = wrapper.create_sc_account(
+        &rust_zero,
+        None,
+        rust_testing_framework_tester::contract_obj,
+        SC_WASM_PATH,
+    );
+    wrapper
+        .execute_tx(&user_addr, &sc_wrapper, &rust_zero, |sc| {
+            sc.init();
+        })
+        .assert_ok();
+
+    let initial = wrapper.snapshot();
+    wrapper
+        .execute_tx(&user_addr, &sc_wrapper, &rust_biguint!(30), |sc| {
+            sc.total_value().set(&managed_biguint!(50));
+        })
+        .assert_ok();
+    wrapper.produce_block();
+
+    let after_tx = wrapper.snapshot();
+    wrapper.restore(&initial);
+    wrapper.check_moax_balance(&user_addr, &rust_biguint!(100));
+    wrapper
+        .execute_query(&sc_wrapper, |sc| {
+            assert_eq!(sc.total_value().get(), managed_biguint!(1));
+            assert_eq!(sc.get_block_nonce(), 0);
+        })
+        .assert_ok();
+
+    wrapper.restore(&after_tx);
+    wrapper.check_moax_balance(&user_addr, &rust_biguint!(70));
+    wrapper
+        .execute_query(&sc_wrapper, |sc| {
+            assert_eq!(sc.total_value().get(), managed_biguint!(50));
+            assert_eq!(sc.get_block_nonce(), 1);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn fork_test() {
+    let rust_zero = rust_biguint!(0);
+    let mut wrapper = BlockchainStateWrapper::new();
+    let user_addr = wrapper.create_user_account(&rust_biguint!(100));
+    let sc_wrapper = wrapper.create_sc_account(
+        &rust_zero,
+        None,
+        rust_testing_framework_tester::contract_obj,
+        SC_WASM_PATH,
+    );
+    wrapper
+        .execute_tx(&user_addr, &sc_wrapper, &rust_zero, |sc| {
+            sc.init();
+        })
+        .assert_ok();
+
+    let mut fork = wrapper.fork();
+    fork.execute_tx(&user_addr, &sc_wrapper, &rust_biguint!(30), |sc| {
+        sc.total_value().set(&managed_biguint!(50));
+    })
+    .assert_ok();
+    fork.check_moax_balance(&user_addr, &rust_biguint!(70));
+
+    // the original is not affected
+    wrapper.check_moax_balance(&user_addr, &rust_biguint!(100));
+    wrapper
+        .execute_query(&sc_wrapper, |sc| {
+            assert_eq!(sc.total_value().get(), managed_biguint!(1));
+        })
+        .assert_ok();
+
+    // both continue generating addresses from where the original was at the time of the fork
+    let fork_user_addr = fork.create_user_account(&rust_zero);
+    let original_user_addr = wrapper.create_user_account(&rust_zero);
+    assert_eq!(fork_user_addr, original_user_addr);
+    assert_ne!(fork_user_addr, user_addr);
+}
+
 #[test]
 fn blockchain_state_test() {
     let rust_zero = rust_biguint!(0);
@@ -872,6 +1066,64 @@ fn blockchain_state_test() {
         .assert_ok();
 }
 
+#[test]
+fn block_production_test() {
+    let rust_zero = rust_biguint!(0);
+    let mut wrapper = BlockchainStateWrapper::new();
+    let sc_wrapper = wrapper.create_sc_account(
+        &rust_zero,
+        None,
+        rust_testing_framework_tester::contract_obj,
+        SC_WASM_PATH,
+    );
+
+    wrapper.set_round_duration_seconds(6);
+    wrapper.set_rounds_per_epoch(10);
+
+    let hook_ran = Rc::new(Cell::new(false));
+    let hook_ran_clone = hook_ran.clone();
+    wrapper.schedule_at_block(3, move |wrapper| {
+        wrapper
+            .execute_query(&sc_wrapper, |sc| {
+                assert_eq!(sc.get_block_nonce(), 3);
+                assert_eq!(sc.get_block_timestamp(), 18);
+            })
+            .assert_ok();
+        hook_ran_clone.set(true);
+    });
+
+    wrapper.advance_blocks(5);
+    assert!(hook_ran.get());
+
+    let sc_wrapper = wrapper.create_sc_account(
+        &rust_zero,
+        None,
+        rust_testing_framework_tester::contract_obj,
+        SC_WASM_PATH,
+    );
+    let check_block = |wrapper: &mut BlockchainStateWrapper, nonce, timestamp, epoch| {
+        wrapper
+            .execute_query(&sc_wrapper, |sc| {
+                assert_eq!(sc.get_block_nonce(), nonce);
+                assert_eq!(sc.get_block_timestamp(), timestamp);
+                assert_eq!(sc.get_block_epoch(), epoch);
+            })
+            .assert_ok();
+    };
+    check_block(&mut wrapper, 5, 30, 0);
+
+    // missed rounds produce no blocks, but still count for the timestamp
+    wrapper.advance_rounds(7);
+    check_block(&mut wrapper, 6, 72, 1);
+
+    // lands on the first round of epoch 3
+    wrapper.advance_epochs(2);
+    check_block(&mut wrapper, 7, 180, 3);
+
+    wrapper.produce_block();
+    check_block(&mut wrapper, 8, 186, 3);
+}
+
 #[test]
 fn execute_on_dest_context_query_test() {
     let rust_zero = rust_biguint!(0);
@@ -0,0 +1,139 @@
+use dharitri_wasm::{contract_base::ContractAbiProvider, types::Address};
+use dharitri_wasm_debug::{managed_biguint, rust_biguint, testing_framework::*, DebugApi};
+use rust_testing_framework_tester::*;
+
+const SC_WASM_PATH: &str = "output/rust-testing-framework-tester.wasm";
+const TOKEN_ID: &[u8] = b"FUZZ-123456";
+const NUM_CALLERS: u64 = 3;
+const USER_BALANCE: u64 = 10_000;
+const FUZZ_OUTPUT_PATH: &str = "fuzz_total_value.scen.json";
+
+fn setup<CB, ContractObjBuilder>(
+    obj_builder: ContractObjBuilder,
+) -> FuzzWorld<CB, ContractObjBuilder>
+where
+    CB: dharitri_wasm::contract_base::ContractBase<Api = DebugApi>
+        + dharitri_wasm::contract_base::CallableContract
+        + RustTestingFrameworkTester
+        + 'static,
+    ContractObjBuilder: 'static + Copy + Fn() -> CB,
+{
+    let rust_zero = rust_biguint!(0);
+    let mut wrapper = BlockchainStateWrapper::new();
+    let callers: Vec<Address> = (0..NUM_CALLERS)
+        .map(|_| wrapper.create_user_account(&rust_biguint!(USER_BALANCE)))
+        .collect();
+    for caller in &callers {
+        wrapper.set_dct_balance(caller, TOKEN_ID, &rust_biguint!(USER_BALANCE));
+    }
+    let sc_wrapper = wrapper.create_sc_account(&rust_zero, None, obj_builder, SC_WASM_PATH);
+    wrapper
+        .execute_tx(&callers[0], &sc_wrapper, &rust_zero, |sc| {
+            sc.init();
+        })
+        .assert_ok();
+
+    FuzzWorld {
+        wrapper,
+        sc_wrapper,
+        callers,
+    }
+}
+
+fn total_balances<CB, ContractObjBuilder>(
+    world: &FuzzWorld<CB, ContractObjBuilder>,
+    token_id: Option<&[u8]>,
+) -> num_bigint::BigUint
+where
+    CB: dharitri_wasm::contract_base::ContractBase<Api = DebugApi>
+        + dharitri_wasm::contract_base::CallableContract
+        + 'static,
+    ContractObjBuilder: 'static + Copy + Fn() -> CB,
+{
+    let mut addresses = world.callers.clone();
+    addresses.push(world.sc_wrapper.address_ref().clone());
+    addresses
+        .iter()
+        .map(|address| match token_id {
+            Some(token_id) => world.wrapper.get_dct_balance(address, token_id, 0),
+            None => world.wrapper.get_moax_balance(address),
+        })
+        .sum()
+}
+
+#[test]
+fn fuzz_balances_conserved_test() {
+    let expected_total = rust_biguint!(NUM_CALLERS * USER_BALANCE);
+    let expected_dct_total = expected_total.clone();
+
+    ContractFuzzer::new(rust_testing_framework_tester::AbiProvider::abi(), || {
+        setup(rust_testing_framework_tester::contract_obj)
+    })
+    .include_endpoint("receive_moax")
+    .include_endpoint("recieve_moax_half")
+    .include_endpoint("receive_dct")
+    .include_endpoint("receive_dct_half")
+    .include_endpoint("reject_payment")
+    .include_endpoint("addValue")
+    .include_endpoint("panic")
+    .token_id(TOKEN_ID)
+    .seed(1)
+    .invariant("MOAX conserved", move |world| {
+        total_balances(world, None) == expected_total
+    })
+    .invariant("DCT conserved", move |world| {
+        total_balances(world, Some(TOKEN_ID)) == expected_dct_total
+    })
+    .run();
+}
+
+fn total_value_fuzzer<CB, ContractObjBuilder>(
+    obj_builder: ContractObjBuilder,
+) -> ContractFuzzer<CB, ContractObjBuilder>
+where
+    CB: dharitri_wasm::contract_base::ContractBase<Api = DebugApi>
+        + dharitri_wasm::contract_base::CallableContract
+        + RustTestingFrameworkTester
+        + 'static,
+    ContractObjBuilder: 'static + Copy + Fn() -> CB,
+{
+    ContractFuzzer::new(
+        rust_testing_framework_tester::AbiProvider::abi(),
+        move || setup(obj_builder),
+    )
+    .include_endpoint("sum")
+    .include_endpoint("receive_moax")
+    .include_endpoint("addValue")
+    .seed(2)
+    .invariant("total value below 1000", |world| {
+        let mut below = false;
+        world
+            .wrapper
+            .execute_query(&world.sc_wrapper, |sc| {
+                below = sc.total_value().get() < managed_biguint!(1000);
+            })
+            .assert_ok();
+        below
+    })
+}
+
+#[test]
+fn fuzz_shrink_test() {
+    let failure = total_value_fuzzer(rust_testing_framework_tester::contract_obj)
+        .find_failure()
+        .expect("invariant should break");
+
+    assert_eq!(failure.invariant_name, "total value below 1000");
+    assert!(failure.original_len >= failure.calls.len());
+    assert_eq!(failure.calls.len(), 1);
+    assert_eq!(failure.calls[0].endpoint_name, "addValue");
+    assert!(failure.calls[0].moax_value == rust_biguint!(0));
+}
+
+#[test]
+#[should_panic(expected = "invariant `total value below 1000` broken")]
+fn fuzz_denali_output_test() {
+    total_value_fuzzer(rust_testing_framework_tester::contract_obj)
+        .denali_output(FUZZ_OUTPUT_PATH)
+        .run();
+}
@@ -12,11 +12,13 @@ dharitri_wasm::imports!();
 
 /// Contract that tests that using modules works correctly.
 /// Also provides testing for the most common modules:
+/// - AccessControlModule
 /// - DnsModule
 /// - FeaturesModule
 /// - DctModule
 /// - GovernanceModule
 /// - PauseModule
+/// - TimelockModule
 #[dharitri_wasm::contract]
 pub trait UseModule:
     internal_mod_a::InternalModuleA
@@ -25,12 +27,14 @@ pub trait UseModule:
     + internal_mod_init::InternalModuleInit
     + only_owner_mod::OnlyOwnerModule
     + only_owner_derived_mod::OnlyOwnerDerivedModule
+    + dharitri_wasm_modules::access_control::AccessControlModule
     + dharitri_wasm_modules::dns::DnsModule
     + dharitri_wasm_modules::dct::DctModule
     + dharitri_wasm_modules::features::FeaturesModule
     + dharitri_wasm_modules::governance::GovernanceModule
     + dharitri_wasm_modules::governance::governance_configurable::GovernanceConfigurablePropertiesModule
     + dharitri_wasm_modules::pause::PauseModule
+    + dharitri_wasm_modules::timelock::TimelockModule
     + dharitri_wasm_modules::default_issue_callbacks::DefaultIssueCallbacksModule
 {
     /// Validates that the "featureName" feature is on.
@@ -40,8 +44,19 @@ pub trait UseModule:
         self.check_feature_on(b"featureName", true);
     }
 
+    /// Can only be called by addresses that have been granted the "MINTER" role.
+    #[only_role("MINTER")]
+    #[endpoint(checkMinterRole)]
+    fn check_minter_role(&self) {}
+
     #[endpoint(checkPause)]
     fn check_pause(&self) -> SCResult<bool> {
         Ok(self.is_paused())
     }
+
+    /// Fails if either the whole contract or this endpoint is paused.
+    #[endpoint(checkEndpointPause)]
+    fn check_endpoint_pause(&self) {
+        self.require_endpoint_not_paused(b"checkEndpointPause");
+    }
 }
@@ -1,3 +1,8 @@
+#[test]
+fn use_module_access_control_go() {
+    dharitri_wasm_debug::denali_go("denali/use_module_access_control.scen.json");
+}
+
 #[test]
 fn use_module_dns_register_go() {
     dharitri_wasm_debug::denali_go("denali/use_module_dns_register.scen.json");
@@ -28,6 +33,16 @@ fn use_module_pause_go() {
     dharitri_wasm_debug::denali_go("denali/use_module_pause.scen.json");
 }
 
+#[test]
+fn use_module_pause_endpoint_go() {
+    dharitri_wasm_debug::denali_go("denali/use_module_pause_endpoint.scen.json");
+}
+
+#[test]
+fn use_module_timelock_go() {
+    dharitri_wasm_debug::denali_go("denali/use_module_timelock.scen.json");
+}
+
 // Governance module tests
 
 #[test]
@@ -50,6 +65,16 @@ fn invalid_proposals_go() {
     dharitri_wasm_debug::denali_go("denali/use_module_governance/invalid_proposals.scen.json");
 }
 
+#[test]
+fn quorum_percentage_go() {
+    dharitri_wasm_debug::denali_go("denali/use_module_governance/quorum_percentage.scen.json");
+}
+
+#[test]
+fn voting_strategies_go() {
+    dharitri_wasm_debug::denali_go("denali/use_module_governance/voting_strategies.scen.json");
+}
+
 #[test]
 fn withdraw_governance_tokens_go() {
     dharitri_wasm_debug::denali_go(
@@ -43,6 +43,11 @@ fn world() -> BlockchainMock {
     blockchain
 }
 
+#[test]
+fn use_module_access_control_rs() {
+    dharitri_wasm_debug::denali_rs("denali/use_module_access_control.scen.json", world());
+}
+
 #[test]
 fn use_module_dns_register_rs() {
     dharitri_wasm_debug::denali_rs("denali/use_module_dns_register.scen.json", world());
@@ -68,6 +73,16 @@ fn use_module_pause_rs() {
     dharitri_wasm_debug::denali_rs("denali/use_module_pause.scen.json", world());
 }
 
+#[test]
+fn use_module_pause_endpoint_rs() {
+    dharitri_wasm_debug::denali_rs("denali/use_module_pause_endpoint.scen.json", world());
+}
+
+#[test]
+fn use_module_timelock_rs() {
+    dharitri_wasm_debug::denali_rs("denali/use_module_timelock.scen.json", world());
+}
+
 // Governance module tests
 
 #[test]
@@ -99,6 +114,22 @@ fn invalid_proposals_rs() {
     );
 }
 
+#[test]
+fn quorum_percentage_rs() {
+    dharitri_wasm_debug::denali_rs(
+        "denali/use_module_governance/quorum_percentage.scen.json",
+        world(),
+    );
+}
+
+#[test]
+fn voting_strategies_rs() {
+    dharitri_wasm_debug::denali_rs(
+        "denali/use_module_governance/voting_strategies.scen.json",
+        world(),
+    );
+}
+
 #[test]
 fn withdraw_governance_tokens_rs() {
     dharitri_wasm_debug::denali_rs(
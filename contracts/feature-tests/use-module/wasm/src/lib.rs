@@ -7,44 +7,76 @@
 dharitri_wasm_node::wasm_endpoints! {
     use_module
     (
+        abstain
         callBack
         call_derived_not_owner_only
         call_mod_a
         call_mod_b
         call_mod_c
         cancel
+        cancelAction
         changeLockTimeAfterVotingEndsInBlocks
         changeMinTokenBalanceForProposing
         changeQuorum
+        changeQuorumRule
+        changeTimelockMinDelay
         changeVotingDelayInBlocks
         changeVotingPeriodInBlocks
+        changeVotingStrategy
+        checkEndpointPause
         checkFeatureGuard
+        checkMinterRole
         checkPause
+        delegateVote
         depositTokensForAction
         dnsRegister
         downvote
         execute
+        executeAction
+        getActionReadyTimestamp
+        getActionStatus
+        getEndpointUnpauseTimestamp
         getGovernanceTokenId
         getLockTimeAfterVotingEndsInBlocks
         getMinTokenBalanceForProposing
+        getPauseGuardian
         getProposalActions
         getProposalDescription
         getProposalStatus
         getProposer
         getQuorum
+        getQuorumRule
+        getRoleAdmin
+        getRoleMembers
+        getScheduledAction
+        getTimelockMinDelay
+        getTotalAbstainVotes
         getTotalDownvotes
         getTotalVotes
+        getUnpauseTimestamp
         getVotingDelayInBlocks
         getVotingPeriodInBlocks
+        getVotingStrategy
+        grantRole
+        hasRole
         initGovernanceModule
+        initTimelockModule
+        isEndpointPaused
         isPaused
         issueToken
         only_owner_mod_endpoint
         pause
+        pauseEndpoint
         propose
         queue
+        renounceRole
+        revokeRole
+        scheduleAction
         setFeatureFlag
+        setPauseGuardian
+        setRoleAdmin
         unpause
+        unpauseEndpoint
         vote
         withdrawGovernanceTokens
     )
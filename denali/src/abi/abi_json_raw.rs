@@ -0,0 +1,68 @@
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+/// The parts of a contract ABI JSON that are relevant when interpreting scenarios.
+/// Everything else in the file is ignored.
+#[derive(Deserialize)]
+pub struct AbiJsonRaw {
+    #[serde(default)]
+    pub constructor: Option<AbiEndpointJsonRaw>,
+
+    #[serde(default)]
+    pub endpoints: Vec<AbiEndpointJsonRaw>,
+
+    #[serde(default)]
+    pub types: BTreeMap<String, AbiTypeJsonRaw>,
+}
+
+#[derive(Deserialize)]
+pub struct AbiEndpointJsonRaw {
+    /// Missing for the constructor.
+    #[serde(default)]
+    pub name: String,
+
+    #[serde(default)]
+    pub inputs: Vec<AbiArgJsonRaw>,
+
+    #[serde(default)]
+    pub outputs: Vec<AbiArgJsonRaw>,
+}
+
+#[derive(Deserialize)]
+pub struct AbiArgJsonRaw {
+    #[serde(rename = "type")]
+    pub type_name: String,
+}
+
+#[derive(Deserialize)]
+pub struct AbiTypeJsonRaw {
+    #[serde(rename = "type")]
+    pub content_type: String,
+
+    #[serde(default)]
+    pub version: Option<u8>,
+
+    #[serde(default)]
+    pub fields: Vec<AbiFieldJsonRaw>,
+
+    #[serde(default)]
+    pub variants: Vec<AbiVariantJsonRaw>,
+}
+
+#[derive(Deserialize)]
+pub struct AbiFieldJsonRaw {
+    pub name: String,
+
+    #[serde(rename = "type")]
+    pub field_type: String,
+}
+
+#[derive(Deserialize)]
+pub struct AbiVariantJsonRaw {
+    pub name: String,
+
+    pub discriminant: usize,
+
+    #[serde(default)]
+    pub fields: Vec<AbiFieldJsonRaw>,
+}
@@ -0,0 +1,10 @@
+mod abi_json_raw;
+mod scenario_abi;
+pub mod type_name_util;
+mod typed_interpret;
+mod typed_value_decode;
+mod typed_value_diff;
+mod typed_value_encode;
+
+pub use scenario_abi::*;
+pub use typed_interpret::*;
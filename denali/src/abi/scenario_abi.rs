@@ -0,0 +1,186 @@
+use std::collections::BTreeMap;
+
+use super::{
+    abi_json_raw::{AbiEndpointJsonRaw, AbiFieldJsonRaw, AbiJsonRaw},
+    type_name_util::split_generic_type_name,
+};
+
+/// Contract ABI, as loaded from the JSON produced by `meta_abi.rs`.
+///
+/// Only holds what is needed to encode and decode typed scenario values:
+/// the argument and result types of each endpoint and the descriptions of the custom types.
+#[derive(Debug, Default)]
+pub struct ScenarioAbi {
+    pub constructor: Option<AbiEndpointTypes>,
+    pub endpoints: BTreeMap<String, AbiEndpointTypes>,
+    pub type_descriptions: BTreeMap<String, AbiTypeDescription>,
+}
+
+/// A custom struct or enum type from the ABI.
+///
+/// Unlike the `TypeDescription` of the contract ABI, it owns the field and variant names,
+/// since they come from the JSON and not from the contract code.
+#[derive(Clone, Debug)]
+pub struct AbiTypeDescription {
+    pub contents: AbiTypeContents,
+    /// Version byte written in front of the fields, for versioned structs.
+    pub version: Option<u8>,
+}
+
+#[derive(Clone, Debug)]
+pub enum AbiTypeContents {
+    Enum(Vec<AbiEnumVariant>),
+    Struct(Vec<AbiStructField>),
+}
+
+#[derive(Clone, Debug)]
+pub struct AbiEnumVariant {
+    pub name: String,
+    pub discriminant: usize,
+    pub fields: Vec<AbiStructField>,
+}
+
+#[derive(Clone, Debug)]
+pub struct AbiStructField {
+    pub name: String,
+    pub field_type: String,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct AbiEndpointTypes {
+    pub inputs: AbiArgTypes,
+    pub outputs: AbiArgTypes,
+}
+
+/// The types of the individual arguments (or results) of an endpoint, with the multi-values flattened.
+///
+/// A variadic argument can only come last, its item types repeat indefinitely after the fixed ones.
+#[derive(Clone, Debug, Default)]
+pub struct AbiArgTypes {
+    pub fixed: Vec<String>,
+    pub repeated: Vec<String>,
+}
+
+impl AbiArgTypes {
+    fn from_type_names<'a, I: Iterator<Item = &'a str>>(type_names: I) -> Self {
+        let mut arg_types = AbiArgTypes::default();
+        for type_name in type_names {
+            if !arg_types.repeated.is_empty() {
+                break;
+            }
+            arg_types.add_multi_value_type(type_name);
+        }
+        arg_types
+    }
+
+    fn add_multi_value_type(&mut self, type_name: &str) {
+        match split_generic_type_name(type_name) {
+            Some(("variadic", args)) => {
+                flatten_multi_value_type(args[0], &mut self.repeated);
+            },
+            Some(("counted-variadic", args)) => {
+                self.fixed.push("u32".to_string());
+                flatten_multi_value_type(args[0], &mut self.repeated);
+            },
+            _ => flatten_multi_value_type(type_name, &mut self.fixed),
+        }
+    }
+
+    /// The type of the argument at the given position, `None` if there is no such argument.
+    pub fn type_at(&self, index: usize) -> Option<&str> {
+        if index < self.fixed.len() {
+            return Some(self.fixed[index].as_str());
+        }
+        if self.repeated.is_empty() {
+            return None;
+        }
+        let repeated_index = (index - self.fixed.len()) % self.repeated.len();
+        Some(self.repeated[repeated_index].as_str())
+    }
+}
+
+fn flatten_multi_value_type(type_name: &str, result: &mut Vec<String>) {
+    match split_generic_type_name(type_name) {
+        Some(("optional", args)) => flatten_multi_value_type(args[0], result),
+        Some(("multi", args)) => {
+            for arg in args {
+                flatten_multi_value_type(arg, result);
+            }
+        },
+        _ => result.push(type_name.to_string()),
+    }
+}
+
+impl ScenarioAbi {
+    pub fn from_json_str(s: &str) -> Self {
+        let raw: AbiJsonRaw =
+            serde_json::from_str(s).unwrap_or_else(|e| panic!("invalid ABI JSON: {}", e));
+        Self::from_raw(raw)
+    }
+
+    fn from_raw(raw: AbiJsonRaw) -> Self {
+        let mut type_descriptions = BTreeMap::new();
+        for (type_name, type_raw) in raw.types {
+            let contents = match type_raw.content_type.as_str() {
+                "struct" => AbiTypeContents::Struct(convert_fields(type_raw.fields)),
+                "enum" => AbiTypeContents::Enum(
+                    type_raw
+                        .variants
+                        .into_iter()
+                        .map(|variant| AbiEnumVariant {
+                            name: variant.name,
+                            discriminant: variant.discriminant,
+                            fields: convert_fields(variant.fields),
+                        })
+                        .collect(),
+                ),
+                _ => continue,
+            };
+            type_descriptions.insert(
+                type_name,
+                AbiTypeDescription {
+                    contents,
+                    version: type_raw.version,
+                },
+            );
+        }
+
+        ScenarioAbi {
+            constructor: raw.constructor.map(convert_endpoint),
+            endpoints: raw
+                .endpoints
+                .into_iter()
+                .map(|endpoint| (endpoint.name.clone(), convert_endpoint(endpoint)))
+                .collect(),
+            type_descriptions,
+        }
+    }
+
+    pub fn type_description(&self, type_name: &str) -> Option<&AbiTypeDescription> {
+        self.type_descriptions.get(type_name)
+    }
+}
+
+fn convert_endpoint(endpoint: AbiEndpointJsonRaw) -> AbiEndpointTypes {
+    AbiEndpointTypes {
+        inputs: AbiArgTypes::from_type_names(
+            endpoint.inputs.iter().map(|input| input.type_name.as_str()),
+        ),
+        outputs: AbiArgTypes::from_type_names(
+            endpoint
+                .outputs
+                .iter()
+                .map(|output| output.type_name.as_str()),
+        ),
+    }
+}
+
+fn convert_fields(fields: Vec<AbiFieldJsonRaw>) -> Vec<AbiStructField> {
+    fields
+        .into_iter()
+        .map(|field| AbiStructField {
+            name: field.name,
+            field_type: field.field_type,
+        })
+        .collect()
+}
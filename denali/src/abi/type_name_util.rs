@@ -0,0 +1,60 @@
+/// Byte width and signedness of the fixed-width numeric types.
+pub fn fixed_width_num_type(type_name: &str) -> Option<(usize, bool)> {
+    match type_name {
+        "u8" => Some((1, false)),
+        "u16" => Some((2, false)),
+        "u32" | "usize" | "NonZeroUsize" => Some((4, false)),
+        "u64" => Some((8, false)),
+        "u128" => Some((16, false)),
+        "i8" => Some((1, true)),
+        "i16" => Some((2, true)),
+        "i32" | "isize" => Some((4, true)),
+        "i64" => Some((8, true)),
+        "i128" => Some((16, true)),
+        _ => None,
+    }
+}
+
+/// Types encoded as a length-prefixed byte slice when nested.
+pub fn is_bytes_type(type_name: &str) -> bool {
+    matches!(
+        type_name,
+        "bytes" | "TokenIdentifier" | "utf-8 string" | "BoxedBytes"
+    )
+}
+
+/// Types encoded as exactly 32 raw bytes.
+pub fn is_hash_type(type_name: &str) -> bool {
+    matches!(type_name, "Address" | "H256")
+}
+
+/// `array32<u8>` has length 32.
+pub fn array_length(type_base_name: &str) -> Option<usize> {
+    type_base_name.strip_prefix("array")?.parse().ok()
+}
+
+/// Splits `Name<A, B<C, D>>` into `("Name", ["A", "B<C, D>"])`.
+///
+/// Returns `None` for types without generic arguments.
+pub fn split_generic_type_name(type_name: &str) -> Option<(&str, Vec<&str>)> {
+    let open_index = type_name.find('<')?;
+    let inner = type_name[open_index + 1..].strip_suffix('>')?;
+
+    let mut args = Vec::new();
+    let mut depth = 0usize;
+    let mut arg_start = 0;
+    for (i, c) in inner.char_indices() {
+        match c {
+            '<' => depth += 1,
+            '>' => depth -= 1,
+            ',' if depth == 0 => {
+                args.push(inner[arg_start..i].trim());
+                arg_start = i + 1;
+            },
+            _ => {},
+        }
+    }
+    args.push(inner[arg_start..].trim());
+
+    Some((&type_name[..open_index], args))
+}
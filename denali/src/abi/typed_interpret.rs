@@ -0,0 +1,99 @@
+use crate::{
+    interpret_trait::{InterpretableFrom, InterpreterContext},
+    model::{BytesValue, CheckValue, CheckValueList},
+    serde_raw::{CheckBytesValueRaw, CheckValueListRaw, ValueSubTree},
+};
+
+use super::AbiArgTypes;
+
+/// Encodes the value according to its ABI type, if it is a typed value and the type is known.
+/// Falls back to the untyped interpretation otherwise.
+pub fn interpret_typed_value(
+    value: ValueSubTree,
+    type_name: Option<&str>,
+    context: &InterpreterContext,
+) -> BytesValue {
+    if let (Some(abi), Some(type_name)) = (&context.abi, type_name) {
+        if abi.is_typed_value(&value, type_name) {
+            return BytesValue {
+                value: abi.encode_top(&value, type_name, context),
+                original: value,
+            };
+        }
+    }
+    BytesValue::interpret_from(value, context)
+}
+
+pub fn interpret_typed_args(
+    args: Vec<ValueSubTree>,
+    arg_types: Option<&AbiArgTypes>,
+    context: &InterpreterContext,
+) -> Vec<BytesValue> {
+    args.into_iter()
+        .enumerate()
+        .map(|(i, arg)| {
+            let type_name = arg_types.and_then(|arg_types| arg_types.type_at(i));
+            interpret_typed_value(arg, type_name, context)
+        })
+        .collect()
+}
+
+pub fn interpret_typed_check_list(
+    from: CheckValueListRaw,
+    result_types: Option<&AbiArgTypes>,
+    context: &InterpreterContext,
+) -> CheckValueList {
+    match from {
+        CheckValueListRaw::CheckList(list_raw) => CheckValue::Equal(
+            list_raw
+                .into_iter()
+                .enumerate()
+                .map(|(i, check_raw)| match check_raw {
+                    CheckBytesValueRaw::Equal(value) => {
+                        let type_name = result_types.and_then(|types| types.type_at(i));
+                        CheckValue::Equal(interpret_typed_value(value, type_name, context))
+                    },
+                    _ => CheckValue::Star,
+                })
+                .collect(),
+        ),
+        _ => CheckValue::Star,
+    }
+}
+
+/// Keeps the ABI result types of a transaction, to explain `out` mismatches field by field.
+#[derive(Clone, Debug)]
+pub struct TypedResults {
+    pub context: InterpreterContext,
+    pub types: AbiArgTypes,
+}
+
+impl TypedResults {
+    /// Field-level differences between the typed expected results and the actual ones.
+    /// Untyped expected values are not analyzed.
+    pub fn diff(&self, expected: &CheckValueList, actual: &[Vec<u8>]) -> Vec<String> {
+        let (abi, expected_list) = match (&self.context.abi, expected) {
+            (Some(abi), CheckValue::Equal(list)) => (abi, list),
+            _ => return Vec::new(),
+        };
+
+        let mut diffs = Vec::new();
+        for (i, (check_value, actual_value)) in expected_list.iter().zip(actual.iter()).enumerate()
+        {
+            if let (CheckValue::Equal(expected_value), Some(type_name)) =
+                (check_value, self.types.type_at(i))
+            {
+                if abi.is_typed_value(&expected_value.original, type_name) {
+                    diffs.extend(abi.diff_top(
+                        format!("out[{}]", i).as_str(),
+                        &expected_value.original,
+                        actual_value.as_slice(),
+                        type_name,
+                        &self.context,
+                    ));
+                }
+            }
+        }
+        diffs
+    }
+}
@@ -0,0 +1,266 @@
+use num_bigint::{BigInt, BigUint};
+use std::collections::BTreeMap;
+
+use crate::serde_raw::ValueSubTree;
+
+use super::{type_name_util::*, AbiStructField, AbiTypeContents, ScenarioAbi};
+
+/// Decoding produces values in the same format as the typed scenario values,
+/// so they can be compared field by field and displayed in the same way.
+impl ScenarioAbi {
+    pub fn decode_top(&self, bytes: &[u8], type_name: &str) -> Result<ValueSubTree, String> {
+        if let Some((width, signed)) = fixed_width_num_type(type_name) {
+            if bytes.len() > width {
+                return Err(format!(
+                    "{} bytes is too long for type {}",
+                    bytes.len(),
+                    type_name
+                ));
+            }
+            return Ok(decode_num(bytes, signed));
+        }
+        match type_name {
+            "BigUint" => return Ok(decode_num(bytes, false)),
+            "BigInt" => return Ok(decode_num(bytes, true)),
+            "bool" => {
+                return match bytes {
+                    [] => Ok(ValueSubTree::Str("false".to_string())),
+                    [1] => Ok(ValueSubTree::Str("true".to_string())),
+                    _ => Err("invalid bool value".to_string()),
+                }
+            },
+            _ => {},
+        }
+        if is_bytes_type(type_name) {
+            return Ok(decode_bytes(bytes));
+        }
+
+        match split_generic_type_name(type_name) {
+            Some(("Option", args)) => {
+                if bytes.is_empty() {
+                    return Ok(ValueSubTree::default());
+                }
+                if bytes[0] != 1 {
+                    return Err("invalid Option tag".to_string());
+                }
+                decode_all(&bytes[1..], |input| self.decode_nested(input, args[0]))
+            },
+            Some(("List", args)) => {
+                let mut input = bytes;
+                let mut items = Vec::new();
+                while !input.is_empty() {
+                    items.push(self.decode_nested(&mut input, args[0])?);
+                }
+                Ok(ValueSubTree::List(items))
+            },
+            Some(_) => decode_all(bytes, |input| self.decode_nested(input, type_name)),
+            None => match self.type_description(type_name).map(|td| &td.contents) {
+                Some(AbiTypeContents::Enum(variants)) if bytes.len() <= 1 => {
+                    let discriminant = bytes.first().cloned().unwrap_or_default() as usize;
+                    match variants
+                        .iter()
+                        .find(|variant| variant.discriminant == discriminant)
+                    {
+                        Some(variant) if variant.fields.is_empty() => {
+                            Ok(ValueSubTree::Str(variant.name.to_string()))
+                        },
+                        _ => decode_all(bytes, |input| self.decode_nested(input, type_name)),
+                    }
+                },
+                Some(_) => decode_all(bytes, |input| self.decode_nested(input, type_name)),
+                None if is_hash_type(type_name) || type_name == "()" => {
+                    decode_all(bytes, |input| self.decode_nested(input, type_name))
+                },
+                None => Ok(decode_raw(bytes)),
+            },
+        }
+    }
+
+    pub fn decode_nested(
+        &self,
+        input: &mut &[u8],
+        type_name: &str,
+    ) -> Result<ValueSubTree, String> {
+        if let Some((width, signed)) = fixed_width_num_type(type_name) {
+            return Ok(decode_num(take(input, width)?, signed));
+        }
+        match type_name {
+            "BigUint" => return Ok(decode_num(take_length_prefixed(input)?, false)),
+            "BigInt" => return Ok(decode_num(take_length_prefixed(input)?, true)),
+            "bool" => {
+                return match take(input, 1)? {
+                    [0] => Ok(ValueSubTree::Str("false".to_string())),
+                    [1] => Ok(ValueSubTree::Str("true".to_string())),
+                    _ => Err("invalid bool value".to_string()),
+                }
+            },
+            "()" => return Ok(ValueSubTree::default()),
+            _ => {},
+        }
+        if is_bytes_type(type_name) {
+            return Ok(decode_bytes(take_length_prefixed(input)?));
+        }
+        if is_hash_type(type_name) {
+            return Ok(decode_raw(take(input, 32)?));
+        }
+
+        if let Some((base_name, args)) = split_generic_type_name(type_name) {
+            return self.decode_nested_generic(input, base_name, args.as_slice());
+        }
+
+        let type_description = self.type_description(type_name);
+        match type_description.map(|td| &td.contents) {
+            Some(AbiTypeContents::Struct(fields)) => {
+                if let Some(version) = type_description.and_then(|td| td.version) {
+                    let found = take(input, 1)?[0];
+                    if found != version {
+                        return Err(format!(
+                            "unsupported version {} for struct {}, expected {}",
+                            found, type_name, version
+                        ));
+                    }
+                }
+                self.decode_nested_fields(input, fields)
+            },
+            Some(AbiTypeContents::Enum(variants)) => {
+                let discriminant = take(input, 1)?[0] as usize;
+                let variant = variants
+                    .iter()
+                    .find(|variant| variant.discriminant == discriminant)
+                    .ok_or_else(|| {
+                        format!(
+                            "invalid discriminant {} for enum {}",
+                            discriminant, type_name
+                        )
+                    })?;
+                if variant.fields.is_empty() {
+                    return Ok(ValueSubTree::Str(variant.name.to_string()));
+                }
+                let fields = self.decode_nested_fields(input, variant.fields.as_slice())?;
+                let mut variant_map = BTreeMap::new();
+                variant_map.insert(variant.name.to_string(), fields);
+                Ok(ValueSubTree::Map(variant_map))
+            },
+            _ => Err(format!(
+                "cannot decode nested value of unknown type {}",
+                type_name
+            )),
+        }
+    }
+
+    fn decode_nested_generic(
+        &self,
+        input: &mut &[u8],
+        base_name: &str,
+        args: &[&str],
+    ) -> Result<ValueSubTree, String> {
+        let num_items = if base_name == "Option" {
+            return match take(input, 1)?[0] {
+                0 => Ok(ValueSubTree::default()),
+                1 => self.decode_nested(input, args[0]),
+                _ => Err("invalid Option tag".to_string()),
+            };
+        } else if base_name == "tuple" {
+            let items = args
+                .iter()
+                .map(|item_type| self.decode_nested(input, item_type))
+                .collect::<Result<Vec<_>, _>>()?;
+            return Ok(ValueSubTree::List(items));
+        } else if base_name == "List" {
+            let length_bytes = take(input, 4)?;
+            u32::from_be_bytes([
+                length_bytes[0],
+                length_bytes[1],
+                length_bytes[2],
+                length_bytes[3],
+            ]) as usize
+        } else if let Some(length) = array_length(base_name) {
+            length
+        } else {
+            return Err(format!(
+                "cannot decode nested value of unknown type {}",
+                base_name
+            ));
+        };
+
+        let items = (0..num_items)
+            .map(|_| self.decode_nested(input, args[0]))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(ValueSubTree::List(items))
+    }
+
+    fn decode_nested_fields(
+        &self,
+        input: &mut &[u8],
+        fields: &[AbiStructField],
+    ) -> Result<ValueSubTree, String> {
+        let mut field_values = BTreeMap::new();
+        for field in fields {
+            let field_value = self.decode_nested(input, &field.field_type)?;
+            field_values.insert(field.name.to_string(), field_value);
+        }
+        Ok(ValueSubTree::Map(field_values))
+    }
+}
+
+fn decode_all<F>(bytes: &[u8], decode: F) -> Result<ValueSubTree, String>
+where
+    F: FnOnce(&mut &[u8]) -> Result<ValueSubTree, String>,
+{
+    let mut input = bytes;
+    let result = decode(&mut input)?;
+    if !input.is_empty() {
+        return Err(format!("{} bytes left over after decoding", input.len()));
+    }
+    Ok(result)
+}
+
+fn take<'a>(input: &mut &'a [u8], length: usize) -> Result<&'a [u8], String> {
+    if input.len() < length {
+        return Err("input too short".to_string());
+    }
+    let (taken, rest) = input.split_at(length);
+    *input = rest;
+    Ok(taken)
+}
+
+fn take_length_prefixed<'a>(input: &mut &'a [u8]) -> Result<&'a [u8], String> {
+    let length_bytes = take(input, 4)?;
+    let length = u32::from_be_bytes([
+        length_bytes[0],
+        length_bytes[1],
+        length_bytes[2],
+        length_bytes[3],
+    ]);
+    take(input, length as usize)
+}
+
+fn decode_num(bytes: &[u8], signed: bool) -> ValueSubTree {
+    let num_str = if signed {
+        BigInt::from_signed_bytes_be(bytes).to_string()
+    } else {
+        BigUint::from_bytes_be(bytes).to_string()
+    };
+    ValueSubTree::Str(num_str)
+}
+
+/// Readable text is shown as `str:...`, everything else as hex.
+/// The `|` separator is avoided, since it would be interpreted as a concatenation.
+fn decode_bytes(bytes: &[u8]) -> ValueSubTree {
+    let is_readable = bytes
+        .iter()
+        .all(|b| (b.is_ascii_graphic() && *b != b'|') || *b == b' ');
+    if !bytes.is_empty() && is_readable {
+        ValueSubTree::Str(format!("str:{}", String::from_utf8_lossy(bytes)))
+    } else {
+        decode_raw(bytes)
+    }
+}
+
+fn decode_raw(bytes: &[u8]) -> ValueSubTree {
+    if bytes.is_empty() {
+        ValueSubTree::default()
+    } else {
+        ValueSubTree::Str(format!("0x{}", hex::encode(bytes)))
+    }
+}
@@ -0,0 +1,209 @@
+use std::collections::BTreeMap;
+
+use crate::{interpret_trait::InterpreterContext, serde_raw::ValueSubTree};
+
+use super::{type_name_util::*, AbiStructField, AbiTypeContents, ScenarioAbi};
+
+impl ScenarioAbi {
+    /// Compares an expected typed value with the actual encoded bytes.
+    ///
+    /// Returns one line for each differing field, of the form `path: want ..., have ...`,
+    /// so no lines means the values are equal.
+    pub fn diff_top(
+        &self,
+        path: &str,
+        expected: &ValueSubTree,
+        actual: &[u8],
+        type_name: &str,
+        context: &InterpreterContext,
+    ) -> Vec<String> {
+        let mut diffs = Vec::new();
+        match self.decode_top(actual, type_name) {
+            Ok(actual_value) => self.diff_values(
+                path,
+                expected,
+                &actual_value,
+                type_name,
+                context,
+                &mut diffs,
+            ),
+            Err(err) => diffs.push(format!(
+                "{}: cannot decode 0x{} as {}: {}",
+                path,
+                hex::encode(actual),
+                type_name,
+                err
+            )),
+        }
+        diffs
+    }
+
+    fn diff_values(
+        &self,
+        path: &str,
+        expected: &ValueSubTree,
+        actual: &ValueSubTree,
+        type_name: &str,
+        context: &InterpreterContext,
+        diffs: &mut Vec<String>,
+    ) {
+        match (expected, actual) {
+            (ValueSubTree::List(expected_items), ValueSubTree::List(actual_items)) => {
+                if let Some(item_types) = self.item_types(type_name, expected_items.len()) {
+                    if expected_items.len() != actual_items.len() {
+                        diffs.push(format!(
+                            "{}: want {} items, have {}",
+                            path,
+                            expected_items.len(),
+                            actual_items.len()
+                        ));
+                    }
+                    for (i, (expected_item, actual_item)) in
+                        expected_items.iter().zip(actual_items.iter()).enumerate()
+                    {
+                        let item_path = format!("{}[{}]", path, i);
+                        self.diff_values(
+                            &item_path,
+                            expected_item,
+                            actual_item,
+                            item_types[i],
+                            context,
+                            diffs,
+                        );
+                    }
+                    return;
+                }
+            },
+            (_, ValueSubTree::Map(actual_map)) => {
+                match self.type_description(type_name).map(|td| &td.contents) {
+                    Some(AbiTypeContents::Struct(fields)) => {
+                        if let Some(expected_map) = fields_by_name(expected, fields) {
+                            self.diff_fields(
+                                path,
+                                &expected_map,
+                                actual_map,
+                                fields,
+                                context,
+                                diffs,
+                            );
+                            return;
+                        }
+                    },
+                    Some(AbiTypeContents::Enum(_)) => {
+                        if let Some((variant_name, expected_fields, actual_fields)) =
+                            same_variant(expected, actual_map)
+                        {
+                            let (_, fields) =
+                                self.enum_variant_by_name(type_name, variant_name).unwrap();
+                            if let Some(expected_fields) = fields_by_name(expected_fields, fields) {
+                                let variant_path = format!("{}.{}", path, variant_name);
+                                self.diff_fields(
+                                    &variant_path,
+                                    &expected_fields,
+                                    actual_fields,
+                                    fields,
+                                    context,
+                                    diffs,
+                                );
+                                return;
+                            }
+                        }
+                    },
+                    _ => {},
+                }
+            },
+            _ => {},
+        }
+
+        if let Some(("Option", args)) = split_generic_type_name(type_name) {
+            if !expected.is_empty_string() && !actual.is_empty_string() {
+                self.diff_values(path, expected, actual, args[0], context, diffs);
+                return;
+            }
+        }
+
+        let mut expected_bytes = Vec::new();
+        self.encode_nested(expected, type_name, context, &mut expected_bytes);
+        let mut actual_bytes = Vec::new();
+        self.encode_nested(actual, type_name, context, &mut actual_bytes);
+        if expected_bytes != actual_bytes {
+            diffs.push(format!("{}: want {}, have {}", path, expected, actual));
+        }
+    }
+
+    fn diff_fields(
+        &self,
+        path: &str,
+        expected_map: &BTreeMap<String, ValueSubTree>,
+        actual_map: &BTreeMap<String, ValueSubTree>,
+        fields: &[AbiStructField],
+        context: &InterpreterContext,
+        diffs: &mut Vec<String>,
+    ) {
+        for field in fields {
+            if let (Some(expected_field), Some(actual_field)) =
+                (expected_map.get(&field.name), actual_map.get(&field.name))
+            {
+                let field_path = format!("{}.{}", path, field.name);
+                self.diff_values(
+                    &field_path,
+                    expected_field,
+                    actual_field,
+                    &field.field_type,
+                    context,
+                    diffs,
+                );
+            }
+        }
+    }
+
+    /// The types of the items of a list-like type, `None` if the type is not list-like.
+    fn item_types<'a>(&self, type_name: &'a str, num_items: usize) -> Option<Vec<&'a str>> {
+        let (base_name, args) = split_generic_type_name(type_name)?;
+        if base_name == "tuple" {
+            Some(args)
+        } else if base_name == "List" || array_length(base_name).is_some() {
+            Some(vec![args[0]; num_items])
+        } else {
+            None
+        }
+    }
+}
+
+/// Fields can be given either as an object, by name, or as a list, in order.
+fn fields_by_name(
+    field_values: &ValueSubTree,
+    fields: &[AbiStructField],
+) -> Option<BTreeMap<String, ValueSubTree>> {
+    match field_values {
+        ValueSubTree::Map(field_map) => Some(field_map.clone()),
+        ValueSubTree::List(field_list) => Some(
+            fields
+                .iter()
+                .zip(field_list.iter())
+                .map(|(field, value)| (field.name.to_string(), value.clone()))
+                .collect(),
+        ),
+        ValueSubTree::Str(_) => None,
+    }
+}
+
+/// The variant name, expected and actual fields, if both enum values are the same variant with fields.
+fn same_variant<'a>(
+    expected: &'a ValueSubTree,
+    actual_map: &'a BTreeMap<String, ValueSubTree>,
+) -> Option<(
+    &'a str,
+    &'a ValueSubTree,
+    &'a BTreeMap<String, ValueSubTree>,
+)> {
+    if let ValueSubTree::Map(expected_map) = expected {
+        if expected_map.keys().eq(actual_map.keys()) {
+            let (variant_name, expected_fields) = expected_map.iter().next()?;
+            if let ValueSubTree::Map(actual_fields) = &actual_map[variant_name] {
+                return Some((variant_name.as_str(), expected_fields, actual_fields));
+            }
+        }
+    }
+    None
+}
@@ -0,0 +1,419 @@
+use num_bigint::{BigInt, Sign};
+use std::collections::BTreeMap;
+
+use crate::{
+    interpret_trait::InterpreterContext,
+    serde_raw::ValueSubTree,
+    value_interpreter::{interpret_string, interpret_subtree},
+};
+
+use super::{type_name_util::*, AbiStructField, AbiTypeContents, ScenarioAbi};
+
+impl ScenarioAbi {
+    /// Typed values are JSON lists and objects, enum variant names and `Option`s (`""` meaning `None`).
+    /// Anything else is interpreted as before, as raw bytes.
+    pub fn is_typed_value(&self, value: &ValueSubTree, type_name: &str) -> bool {
+        match value {
+            ValueSubTree::Str(s) => {
+                self.enum_variant_by_name(type_name, s).is_some()
+                    || matches!(split_generic_type_name(type_name), Some(("Option", _)))
+            },
+            ValueSubTree::List(_) | ValueSubTree::Map(_) => true,
+        }
+    }
+
+    /// Encodes a value as a standalone argument or result.
+    pub fn encode_top(
+        &self,
+        value: &ValueSubTree,
+        type_name: &str,
+        context: &InterpreterContext,
+    ) -> Vec<u8> {
+        if let Some((_, signed)) = fixed_width_num_type(type_name) {
+            return encode_top_num(leaf_str(value, type_name), signed, context);
+        }
+        match type_name {
+            "BigUint" => return encode_top_num(leaf_str(value, type_name), false, context),
+            "BigInt" => return encode_top_num(leaf_str(value, type_name), true, context),
+            "bool" => {
+                return if interpret_bool(value, type_name, context) {
+                    vec![1]
+                } else {
+                    Vec::new()
+                }
+            },
+            _ => {},
+        }
+        if is_bytes_type(type_name) {
+            return interpret_string(leaf_str(value, type_name), context);
+        }
+
+        match split_generic_type_name(type_name) {
+            Some(("Option", args)) => {
+                if value.is_empty_string() {
+                    return Vec::new();
+                }
+                let mut result = vec![1u8];
+                self.encode_nested(value, args[0], context, &mut result);
+                result
+            },
+            Some(("List", args)) => {
+                if let ValueSubTree::List(items) = value {
+                    let mut result = Vec::new();
+                    for item in items {
+                        self.encode_nested(item, args[0], context, &mut result);
+                    }
+                    result
+                } else {
+                    interpret_subtree(value, context)
+                }
+            },
+            _ => {
+                if let ValueSubTree::Str(s) = value {
+                    if let Some((discriminant, fields)) = self.enum_variant_by_name(type_name, s) {
+                        if fields.is_empty() {
+                            return encode_top_discriminant(discriminant);
+                        }
+                    }
+                }
+                let mut result = Vec::new();
+                self.encode_nested(value, type_name, context, &mut result);
+                result
+            },
+        }
+    }
+
+    /// Encodes a value as part of a larger structure, the way the codec's `NestedEncode` does it.
+    pub fn encode_nested(
+        &self,
+        value: &ValueSubTree,
+        type_name: &str,
+        context: &InterpreterContext,
+        dest: &mut Vec<u8>,
+    ) {
+        if let Some((width, signed)) = fixed_width_num_type(type_name) {
+            let s = leaf_str(value, type_name);
+            dest.extend_from_slice(encode_fixed_width_num(s, width, signed, context).as_slice());
+            return;
+        }
+        match type_name {
+            "BigUint" => {
+                let bytes = encode_top_num(leaf_str(value, type_name), false, context);
+                push_length_prefixed(dest, bytes.as_slice());
+                return;
+            },
+            "BigInt" => {
+                let bytes = encode_top_num(leaf_str(value, type_name), true, context);
+                push_length_prefixed(dest, bytes.as_slice());
+                return;
+            },
+            "bool" => {
+                dest.push(interpret_bool(value, type_name, context) as u8);
+                return;
+            },
+            "()" => return,
+            _ => {},
+        }
+        if is_bytes_type(type_name) {
+            let bytes = interpret_string(leaf_str(value, type_name), context);
+            push_length_prefixed(dest, bytes.as_slice());
+            return;
+        }
+        if is_hash_type(type_name) {
+            let bytes = interpret_string(leaf_str(value, type_name), context);
+            assert!(
+                bytes.len() == 32,
+                "{} value {} must be 32 bytes long",
+                type_name,
+                value
+            );
+            dest.extend_from_slice(bytes.as_slice());
+            return;
+        }
+
+        if let Some((base_name, args)) = split_generic_type_name(type_name) {
+            self.encode_nested_generic(value, base_name, args.as_slice(), context, dest);
+            return;
+        }
+
+        let type_description = self.type_description(type_name);
+        match type_description.map(|td| &td.contents) {
+            Some(AbiTypeContents::Struct(fields)) => {
+                if let Some(version) = type_description.and_then(|td| td.version) {
+                    dest.push(version);
+                }
+                self.encode_nested_fields(value, type_name, fields.as_slice(), context, dest)
+            },
+            Some(AbiTypeContents::Enum(_)) => {
+                self.encode_nested_enum(value, type_name, context, dest)
+            },
+            _ => dest.extend_from_slice(interpret_subtree(value, context).as_slice()),
+        }
+    }
+
+    fn encode_nested_generic(
+        &self,
+        value: &ValueSubTree,
+        base_name: &str,
+        args: &[&str],
+        context: &InterpreterContext,
+        dest: &mut Vec<u8>,
+    ) {
+        let items = match value {
+            ValueSubTree::List(items) => items,
+            _ if base_name == "Option" => {
+                if value.is_empty_string() {
+                    dest.push(0);
+                } else {
+                    dest.push(1);
+                    self.encode_nested(value, args[0], context, dest);
+                }
+                return;
+            },
+            _ => {
+                dest.extend_from_slice(interpret_subtree(value, context).as_slice());
+                return;
+            },
+        };
+
+        if base_name == "List" {
+            dest.extend_from_slice(&(items.len() as u32).to_be_bytes()[..]);
+        } else if let Some(length) = array_length(base_name) {
+            assert!(
+                items.len() == length,
+                "{}<{}> value must have exactly {} items, found {}",
+                base_name,
+                args[0],
+                length,
+                items.len()
+            );
+        } else if base_name == "tuple" {
+            assert!(
+                items.len() == args.len(),
+                "tuple value must have exactly {} items, found {}",
+                args.len(),
+                items.len()
+            );
+            for (item, item_type) in items.iter().zip(args.iter()) {
+                self.encode_nested(item, item_type, context, dest);
+            }
+            return;
+        } else {
+            panic!("list values not supported for type {}", base_name);
+        }
+
+        for item in items {
+            self.encode_nested(item, args[0], context, dest);
+        }
+    }
+
+    /// Struct fields, as well as enum variant fields, can be given either as an object, by name, or as a list, in order.
+    fn encode_nested_fields(
+        &self,
+        value: &ValueSubTree,
+        type_name: &str,
+        fields: &[AbiStructField],
+        context: &InterpreterContext,
+        dest: &mut Vec<u8>,
+    ) {
+        match value {
+            ValueSubTree::Map(field_values) => {
+                check_field_names(field_values, type_name, fields);
+                for field in fields {
+                    self.encode_nested(
+                        &field_values[&field.name],
+                        &field.field_type,
+                        context,
+                        dest,
+                    );
+                }
+            },
+            ValueSubTree::List(field_values) => {
+                assert!(
+                    field_values.len() == fields.len(),
+                    "{} value must have exactly {} fields, found {}",
+                    type_name,
+                    fields.len(),
+                    field_values.len()
+                );
+                for (field_value, field) in field_values.iter().zip(fields.iter()) {
+                    self.encode_nested(field_value, &field.field_type, context, dest);
+                }
+            },
+            ValueSubTree::Str(_) => {
+                dest.extend_from_slice(interpret_subtree(value, context).as_slice())
+            },
+        }
+    }
+
+    /// Enum values are either the name of the variant, or an object with the variant name as single key,
+    /// and the variant fields as value.
+    fn encode_nested_enum(
+        &self,
+        value: &ValueSubTree,
+        type_name: &str,
+        context: &InterpreterContext,
+        dest: &mut Vec<u8>,
+    ) {
+        match value {
+            ValueSubTree::Str(s) => {
+                if let Some((discriminant, fields)) = self.enum_variant_by_name(type_name, s) {
+                    assert!(
+                        fields.is_empty(),
+                        "{}::{} has fields, they must be provided",
+                        type_name,
+                        s
+                    );
+                    dest.push(discriminant as u8);
+                } else {
+                    dest.extend_from_slice(interpret_string(s, context).as_slice());
+                }
+            },
+            ValueSubTree::Map(variant_map) if variant_map.len() == 1 => {
+                let (variant_name, field_values) = variant_map.iter().next().unwrap();
+                let (discriminant, fields) = self
+                    .enum_variant_by_name(type_name, variant_name)
+                    .unwrap_or_else(|| {
+                        panic!("unknown variant {} of enum {}", variant_name, type_name)
+                    });
+                dest.push(discriminant as u8);
+                let variant_type_name = format!("{}::{}", type_name, variant_name);
+                self.encode_nested_fields(
+                    field_values,
+                    variant_type_name.as_str(),
+                    fields,
+                    context,
+                    dest,
+                );
+            },
+            _ => panic!(
+                "{} value must be a variant name, or an object with the variant name as single key, found {}",
+                type_name, value
+            ),
+        }
+    }
+
+    pub(crate) fn enum_variant_by_name(
+        &self,
+        type_name: &str,
+        variant_name: &str,
+    ) -> Option<(usize, &[AbiStructField])> {
+        if let Some(AbiTypeContents::Enum(variants)) =
+            self.type_description(type_name).map(|td| &td.contents)
+        {
+            variants
+                .iter()
+                .find(|variant| variant.name == variant_name)
+                .map(|variant| (variant.discriminant, variant.fields.as_slice()))
+        } else {
+            None
+        }
+    }
+}
+
+fn check_field_names(
+    field_values: &BTreeMap<String, ValueSubTree>,
+    type_name: &str,
+    fields: &[AbiStructField],
+) {
+    for field in fields {
+        assert!(
+            field_values.contains_key(&field.name),
+            "missing field {} of {}",
+            field.name,
+            type_name
+        );
+    }
+    for field_name in field_values.keys() {
+        assert!(
+            fields.iter().any(|field| &field.name == field_name),
+            "unknown field {} of {}",
+            field_name,
+            type_name
+        );
+    }
+}
+
+fn leaf_str<'a>(value: &'a ValueSubTree, type_name: &str) -> &'a str {
+    if let ValueSubTree::Str(s) = value {
+        s.as_str()
+    } else {
+        panic!(
+            "{} value must be a single string, found {}",
+            type_name, value
+        )
+    }
+}
+
+fn interpret_bool(value: &ValueSubTree, type_name: &str, context: &InterpreterContext) -> bool {
+    let bytes = interpret_string(leaf_str(value, type_name), context);
+    bytes.iter().any(|b| *b != 0)
+}
+
+fn push_length_prefixed(dest: &mut Vec<u8>, bytes: &[u8]) {
+    dest.extend_from_slice(&(bytes.len() as u32).to_be_bytes()[..]);
+    dest.extend_from_slice(bytes);
+}
+
+/// Explicitly signed expressions (`-1`, `+1`, `i32:1`) are interpreted as two's complement,
+/// all others as unsigned.
+fn interpret_num(s: &str, context: &InterpreterContext) -> BigInt {
+    let bytes = interpret_string(s, context);
+    let explicitly_signed = s.starts_with('-')
+        || s.starts_with('+')
+        || ["i8:", "i16:", "i32:", "i64:"]
+            .iter()
+            .any(|prefix| s.starts_with(prefix));
+    if explicitly_signed {
+        BigInt::from_signed_bytes_be(bytes.as_slice())
+    } else {
+        BigInt::from_bytes_be(Sign::Plus, bytes.as_slice())
+    }
+}
+
+fn encode_top_num(s: &str, signed: bool, context: &InterpreterContext) -> Vec<u8> {
+    let num = interpret_num(s, context);
+    if num.sign() == Sign::NoSign {
+        return Vec::new();
+    }
+    if signed {
+        num.to_signed_bytes_be()
+    } else {
+        num.to_biguint()
+            .unwrap_or_else(|| panic!("negative value {} for unsigned type", s))
+            .to_bytes_be()
+    }
+}
+
+fn encode_fixed_width_num(
+    s: &str,
+    width: usize,
+    signed: bool,
+    context: &InterpreterContext,
+) -> Vec<u8> {
+    let num = interpret_num(s, context);
+    let bytes = encode_top_num(s, signed, context);
+    assert!(
+        bytes.len() <= width,
+        "value {} does not fit in {} bytes",
+        s,
+        width
+    );
+    let padding = if num.sign() == Sign::Minus {
+        0xffu8
+    } else {
+        0u8
+    };
+    let mut result = vec![padding; width - bytes.len()];
+    result.extend_from_slice(bytes.as_slice());
+    result
+}
+
+/// Fieldless enums are top-encoded like a `u8`, with no bytes for the first variant.
+fn encode_top_discriminant(discriminant: usize) -> Vec<u8> {
+    if discriminant == 0 {
+        Vec::new()
+    } else {
+        vec![discriminant as u8]
+    }
+}
@@ -0,0 +1,24 @@
+use bech32::{FromBase32, ToBase32, Variant};
+use dharitri_wasm::types::heap::Address;
+
+/// Human-readable part of Dharitri addresses.
+pub const ADDRESS_HRP: &str = "moa";
+
+/// Accepts any human-readable part, only the data part is kept.
+pub fn decode(bech32_address: &str) -> Address {
+    let (_, data_u5, _) = bech32::decode(bech32_address)
+        .unwrap_or_else(|err| panic!("bech32 decode error for {}: {}", bech32_address, err));
+    let data = Vec::<u8>::from_base32(&data_u5)
+        .unwrap_or_else(|err| panic!("bech32 decode error for {}: {}", bech32_address, err));
+    assert!(
+        data.len() == 32,
+        "bech32 address {} is not 32 bytes in length",
+        bech32_address
+    );
+    Address::from_slice(&data)
+}
+
+pub fn encode(address: &Address) -> String {
+    bech32::encode(ADDRESS_HRP, address.as_bytes().to_base32(), Variant::Bech32)
+        .expect("bech32 encode error")
+}
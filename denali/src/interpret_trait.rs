@@ -1,13 +1,36 @@
-use std::path::PathBuf;
+use std::{path::PathBuf, rc::Rc};
 
-#[derive(Default)]
+use crate::abi::{AbiEndpointTypes, ScenarioAbi};
+
+#[derive(Clone, Debug, Default)]
 pub struct InterpreterContext {
     pub context_path: PathBuf,
+
+    /// The contract ABI referenced by the scenario, if any. Enables typed arguments and results.
+    pub abi: Option<Rc<ScenarioAbi>>,
 }
 
 impl InterpreterContext {
     pub fn new(context_path: PathBuf) -> Self {
-        InterpreterContext { context_path }
+        InterpreterContext {
+            context_path,
+            abi: None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_abi(mut self, abi: ScenarioAbi) -> Self {
+        self.abi = Some(Rc::new(abi));
+        self
+    }
+
+    /// The ABI types of an endpoint, `None` for the constructor.
+    pub fn endpoint_abi_types(&self, endpoint_name: Option<&str>) -> Option<&AbiEndpointTypes> {
+        let abi = self.abi.as_ref()?;
+        match endpoint_name {
+            Some(name) => abi.endpoints.get(name),
+            None => abi.constructor.as_ref(),
+        }
     }
 }
 
@@ -1,3 +1,5 @@
+pub mod abi;
+pub mod bech32;
 pub mod interpret_trait;
 pub mod model;
 mod parse_util;
@@ -0,0 +1,89 @@
+use crate::{
+    interpret_trait::{InterpretableFrom, InterpreterContext, IntoRaw},
+    model::{AddressValue, U64Value},
+    serde_raw::DctTokenRaw,
+};
+
+/// Token-level settings, normally kept by the DCT system SC.
+/// Settings left out keep their current value.
+#[derive(Debug, Default)]
+pub struct DctToken {
+    pub owner: Option<AddressValue>,
+    pub paused: Option<U64Value>,
+    pub can_freeze: Option<U64Value>,
+    pub can_wipe: Option<U64Value>,
+}
+
+impl DctToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn owner<A>(mut self, owner_expr: A) -> Self
+    where
+        AddressValue: InterpretableFrom<A>,
+    {
+        self.owner = Some(AddressValue::interpret_from(
+            owner_expr,
+            &InterpreterContext::default(),
+        ));
+        self
+    }
+
+    pub fn paused<V>(mut self, paused_expr: V) -> Self
+    where
+        U64Value: InterpretableFrom<V>,
+    {
+        self.paused = Some(U64Value::interpret_from(
+            paused_expr,
+            &InterpreterContext::default(),
+        ));
+        self
+    }
+
+    pub fn can_freeze<V>(mut self, can_freeze_expr: V) -> Self
+    where
+        U64Value: InterpretableFrom<V>,
+    {
+        self.can_freeze = Some(U64Value::interpret_from(
+            can_freeze_expr,
+            &InterpreterContext::default(),
+        ));
+        self
+    }
+
+    pub fn can_wipe<V>(mut self, can_wipe_expr: V) -> Self
+    where
+        U64Value: InterpretableFrom<V>,
+    {
+        self.can_wipe = Some(U64Value::interpret_from(
+            can_wipe_expr,
+            &InterpreterContext::default(),
+        ));
+        self
+    }
+}
+
+impl InterpretableFrom<DctTokenRaw> for DctToken {
+    fn interpret_from(from: DctTokenRaw, context: &InterpreterContext) -> Self {
+        DctToken {
+            owner: from.owner.map(|v| AddressValue::interpret_from(v, context)),
+            paused: from.paused.map(|v| U64Value::interpret_from(v, context)),
+            can_freeze: from
+                .can_freeze
+                .map(|v| U64Value::interpret_from(v, context)),
+            can_wipe: from.can_wipe.map(|v| U64Value::interpret_from(v, context)),
+        }
+    }
+}
+
+impl IntoRaw<DctTokenRaw> for DctToken {
+    fn into_raw(self) -> DctTokenRaw {
+        DctTokenRaw {
+            owner: self.owner.map(|value| value.original),
+            paused: self.paused.map(|value| value.original),
+            can_freeze: self.can_freeze.map(|value| value.original),
+            can_wipe: self.can_wipe.map(|value| value.original),
+        }
+    }
+}
@@ -1,6 +1,8 @@
 use crate::{
+    abi::ScenarioAbi,
     interpret_trait::{InterpretableFrom, InterpreterContext, IntoRaw},
     serde_raw::ScenarioRaw,
+    value_interpreter::interpret_string,
 };
 
 use super::Step;
@@ -10,15 +12,33 @@ pub struct Scenario {
     pub name: Option<String>,
     pub comment: Option<String>,
     pub check_gas: Option<bool>,
+
+    /// Path to the contract ABI JSON, usually as `file:...`.
+    /// Allows writing typed arguments and expected results.
+    pub abi: Option<String>,
+
+    /// Free-form labels, used by test runners to select scenarios.
+    pub tags: Vec<String>,
+
     pub steps: Vec<Step>,
 }
 
 impl InterpretableFrom<ScenarioRaw> for Scenario {
     fn interpret_from(from: ScenarioRaw, context: &InterpreterContext) -> Self {
+        let context = &match &from.abi {
+            Some(abi_path) => {
+                let abi_json = interpret_string(abi_path, context);
+                let abi = ScenarioAbi::from_json_str(&String::from_utf8_lossy(&abi_json));
+                context.clone().with_abi(abi)
+            },
+            None => context.clone(),
+        };
         Scenario {
             name: from.name,
             comment: from.comment,
             check_gas: from.check_gas,
+            abi: from.abi,
+            tags: from.tags,
             steps: from
                 .steps
                 .into_iter()
@@ -35,6 +55,8 @@ impl IntoRaw<ScenarioRaw> for Scenario {
             comment: self.comment,
             check_gas: self.check_gas,
             gas_schedule: None,
+            abi: self.abi,
+            tags: self.tags,
             steps: self.steps.into_iter().map(Step::into_raw).collect(),
         }
     }
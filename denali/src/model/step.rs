@@ -6,8 +6,8 @@ use crate::{
 use std::collections::BTreeMap;
 
 use super::{
-    Account, AddressKey, BlockInfo, BytesValue, CheckAccounts, NewAddress, TxCall, TxDeploy,
-    TxExpect, TxQuery, TxTransfer, TxValidatorReward,
+    Account, AddressKey, BlockInfo, BytesKey, BytesValue, CheckAccounts, DctToken, NewAddress,
+    TxCall, TxDeploy, TxExpect, TxQuery, TxTransfer, TxValidatorReward,
 };
 
 #[derive(Debug)]
@@ -20,6 +20,8 @@ pub struct ExternalStepsStep {
 pub struct SetStateStep {
     pub comment: Option<String>,
     pub accounts: BTreeMap<AddressKey, Account>,
+    /// Token-level settings, e.g. paused. Only the Rust runner (denali-rs) supports them.
+    pub dct_tokens: BTreeMap<BytesKey, DctToken>,
     pub new_addresses: Vec<NewAddress>,
     pub block_hashes: Vec<BytesValue>,
     pub previous_block_info: Box<Option<BlockInfo>>,
@@ -75,6 +77,26 @@ pub struct DumpStateStep {
     pub comment: Option<String>,
 }
 
+/// Saves the current blockchain state under the given id.
+///
+/// Only the Rust runner (denali-rs) supports it, the Go VM does not.
+/// Keep it out of scenarios that are also run with denali-go.
+#[derive(Debug, Default)]
+pub struct SnapshotStep {
+    pub comment: Option<String>,
+    pub id: String,
+}
+
+/// Brings the blockchain back to the state saved under the given id.
+/// The snapshot is kept, so it can be restored again later.
+///
+/// Only supported by the Rust runner (denali-rs), same as `SnapshotStep`.
+#[derive(Debug, Default)]
+pub struct RestoreSnapshotStep {
+    pub comment: Option<String>,
+    pub id: String,
+}
+
 #[derive(Debug)]
 pub enum Step {
     ExternalSteps(ExternalStepsStep),
@@ -86,6 +108,8 @@ pub enum Step {
     ValidatorReward(ValidatorRewardStep),
     CheckState(CheckStateStep),
     DumpState(DumpStateStep),
+    Snapshot(SnapshotStep),
+    RestoreSnapshot(RestoreSnapshotStep),
 }
 
 impl InterpretableFrom<StepRaw> for Step {
@@ -97,6 +121,7 @@ impl InterpretableFrom<StepRaw> for Step {
             StepRaw::SetState {
                 comment,
                 accounts,
+                dct_tokens,
                 new_addresses,
                 block_hashes,
                 previous_block_info,
@@ -112,6 +137,15 @@ impl InterpretableFrom<StepRaw> for Step {
                         )
                     })
                     .collect(),
+                dct_tokens: dct_tokens
+                    .into_iter()
+                    .map(|(k, v)| {
+                        (
+                            BytesKey::interpret_from(k, context),
+                            DctToken::interpret_from(v, context),
+                        )
+                    })
+                    .collect(),
                 new_addresses: new_addresses
                     .into_iter()
                     .map(|t| NewAddress::interpret_from(t, context))
@@ -133,36 +167,57 @@ impl InterpretableFrom<StepRaw> for Step {
                 display_logs: _,
                 tx,
                 expect,
-            } => Step::ScCall(ScCallStep {
-                tx_id,
-                comment,
-                tx: Box::new(TxCall::interpret_from(tx, context)),
-                expect: expect.map(|v| TxExpect::interpret_from(v, context)),
-            }),
+            } => {
+                let tx = TxCall::interpret_from(tx, context);
+                let result_types = context
+                    .endpoint_abi_types(Some(tx.function.as_str()))
+                    .map(|endpoint| &endpoint.outputs);
+                Step::ScCall(ScCallStep {
+                    tx_id,
+                    comment,
+                    expect: expect
+                        .map(|v| TxExpect::interpret_with_result_types(v, result_types, context)),
+                    tx: Box::new(tx),
+                })
+            },
             StepRaw::ScQuery {
                 tx_id,
                 comment,
                 display_logs: _,
                 tx,
                 expect,
-            } => Step::ScQuery(ScQueryStep {
-                tx_id,
-                comment,
-                tx: Box::new(TxQuery::interpret_from(tx, context)),
-                expect: expect.map(|v| TxExpect::interpret_from(v, context)),
-            }),
+            } => {
+                let tx = TxQuery::interpret_from(tx, context);
+                let result_types = context
+                    .endpoint_abi_types(Some(tx.function.as_str()))
+                    .map(|endpoint| &endpoint.outputs);
+                Step::ScQuery(ScQueryStep {
+                    tx_id,
+                    comment,
+                    expect: expect
+                        .map(|v| TxExpect::interpret_with_result_types(v, result_types, context)),
+                    tx: Box::new(tx),
+                })
+            },
             StepRaw::ScDeploy {
                 tx_id,
                 comment,
                 display_logs: _,
                 tx,
                 expect,
-            } => Step::ScDeploy(ScDeployStep {
-                tx_id,
-                comment,
-                tx: Box::new(TxDeploy::interpret_from(tx, context)),
-                expect: expect.map(|v| TxExpect::interpret_from(v, context)),
-            }),
+            } => {
+                let tx = TxDeploy::interpret_from(tx, context);
+                let result_types = context
+                    .endpoint_abi_types(None)
+                    .map(|endpoint| &endpoint.outputs);
+                Step::ScDeploy(ScDeployStep {
+                    tx_id,
+                    comment,
+                    expect: expect
+                        .map(|v| TxExpect::interpret_with_result_types(v, result_types, context)),
+                    tx: Box::new(tx),
+                })
+            },
             StepRaw::Transfer { tx_id, comment, tx } => Step::Transfer(TransferStep {
                 tx_id,
                 comment,
@@ -180,6 +235,10 @@ impl InterpretableFrom<StepRaw> for Step {
                 accounts: CheckAccounts::interpret_from(accounts, context),
             }),
             StepRaw::DumpState { comment } => Step::DumpState(DumpStateStep { comment }),
+            StepRaw::Snapshot { comment, id } => Step::Snapshot(SnapshotStep { comment, id }),
+            StepRaw::RestoreSnapshot { comment, id } => {
+                Step::RestoreSnapshot(RestoreSnapshotStep { comment, id })
+            },
         }
     }
 }
@@ -198,6 +257,11 @@ impl IntoRaw<StepRaw> for Step {
                     .into_iter()
                     .map(|(address, account)| (address.into_raw(), account.into_raw()))
                     .collect(),
+                dct_tokens: s
+                    .dct_tokens
+                    .into_iter()
+                    .map(|(token, dct_token)| (token.into_raw(), dct_token.into_raw()))
+                    .collect(),
                 new_addresses: s
                     .new_addresses
                     .into_iter()
@@ -243,6 +307,14 @@ impl IntoRaw<StepRaw> for Step {
                 accounts: s.accounts.into_raw(),
             },
             Step::DumpState(s) => StepRaw::DumpState { comment: s.comment },
+            Step::Snapshot(s) => StepRaw::Snapshot {
+                comment: s.comment,
+                id: s.id,
+            },
+            Step::RestoreSnapshot(s) => StepRaw::RestoreSnapshot {
+                comment: s.comment,
+                id: s.id,
+            },
         }
     }
 }
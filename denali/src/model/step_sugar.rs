@@ -1,9 +1,9 @@
 use crate::interpret_trait::{InterpretableFrom, InterpreterContext};
 
 use super::{
-    Account, AddressKey, AddressValue, BigUintValue, BlockInfo, BytesValue, CheckAccount,
-    CheckStateStep, NewAddress, ScCallStep, ScDeployStep, ScQueryStep, SetStateStep, TxDCT,
-    TxExpect, U64Value,
+    Account, AddressKey, AddressValue, BigUintValue, BlockInfo, BytesKey, BytesValue, CheckAccount,
+    CheckStateStep, DctToken, NewAddress, RestoreSnapshotStep, ScCallStep, ScDeployStep,
+    ScQueryStep, SetStateStep, SnapshotStep, TxDCT, TxExpect, U64Value,
 };
 
 impl SetStateStep {
@@ -20,6 +20,16 @@ impl SetStateStep {
         self
     }
 
+    pub fn put_dct_token<K>(mut self, token_identifier_expr: K, dct_token: DctToken) -> Self
+    where
+        BytesKey: InterpretableFrom<K>,
+    {
+        let token_identifier =
+            BytesKey::interpret_from(token_identifier_expr, &InterpreterContext::default());
+        self.dct_tokens.insert(token_identifier, dct_token);
+        self
+    }
+
     pub fn new_address<CA, NA>(
         mut self,
         creator_address_expr: CA,
@@ -317,3 +327,21 @@ impl CheckStateStep {
         self
     }
 }
+
+impl SnapshotStep {
+    pub fn new(id: &str) -> Self {
+        SnapshotStep {
+            comment: None,
+            id: id.to_string(),
+        }
+    }
+}
+
+impl RestoreSnapshotStep {
+    pub fn new(id: &str) -> Self {
+        RestoreSnapshotStep {
+            comment: None,
+            id: id.to_string(),
+        }
+    }
+}
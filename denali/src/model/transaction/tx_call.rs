@@ -1,4 +1,5 @@
 use crate::{
+    abi::interpret_typed_args,
     interpret_trait::{InterpretableFrom, InterpreterContext, IntoRaw},
     model::{AddressValue, BigUintValue, BytesValue, U64Value},
     serde_raw::TxCallRaw,
@@ -20,6 +21,13 @@ pub struct TxCall {
 
 impl InterpretableFrom<TxCallRaw> for TxCall {
     fn interpret_from(from: TxCallRaw, context: &InterpreterContext) -> Self {
+        let arguments = interpret_typed_args(
+            from.arguments,
+            context
+                .endpoint_abi_types(Some(from.function.as_str()))
+                .map(|endpoint| &endpoint.inputs),
+            context,
+        );
         TxCall {
             from: AddressValue::interpret_from(from.from, context),
             to: AddressValue::interpret_from(from.to, context),
@@ -30,11 +38,7 @@ impl InterpretableFrom<TxCallRaw> for TxCall {
                 .map(|dct_value| TxDCT::interpret_from(dct_value, context))
                 .collect(),
             function: from.function,
-            arguments: from
-                .arguments
-                .into_iter()
-                .map(|t| BytesValue::interpret_from(t, context))
-                .collect(),
+            arguments,
             gas_limit: U64Value::interpret_from(from.gas_limit, context),
             gas_price: U64Value::interpret_from(from.gas_price, context),
         }
@@ -1,4 +1,5 @@
 use crate::{
+    abi::interpret_typed_args,
     interpret_trait::{InterpretableFrom, InterpreterContext, IntoRaw},
     model::{AddressValue, BigUintValue, BytesValue, U64Value},
     serde_raw::TxDeployRaw,
@@ -18,15 +19,18 @@ pub struct TxDeploy {
 
 impl InterpretableFrom<TxDeployRaw> for TxDeploy {
     fn interpret_from(from: TxDeployRaw, context: &InterpreterContext) -> Self {
+        let arguments = interpret_typed_args(
+            from.arguments,
+            context
+                .endpoint_abi_types(None)
+                .map(|endpoint| &endpoint.inputs),
+            context,
+        );
         TxDeploy {
             from: AddressValue::interpret_from(from.from, context),
             moax_value: interpret_moax_value(from.value, from.moax_value, context),
             contract_code: BytesValue::interpret_from(from.contract_code, context),
-            arguments: from
-                .arguments
-                .into_iter()
-                .map(|t| BytesValue::interpret_from(t, context))
-                .collect(),
+            arguments,
             gas_limit: U64Value::interpret_from(from.gas_limit, context),
             gas_price: U64Value::interpret_from(from.gas_price, context),
         }
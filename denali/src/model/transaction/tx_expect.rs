@@ -1,4 +1,5 @@
 use crate::{
+    abi::{interpret_typed_check_list, AbiArgTypes, TypedResults},
     interpret_trait::{InterpretableFrom, InterpreterContext, IntoRaw},
     model::{BytesValue, CheckLogs, CheckValue, CheckValueList, U64Value},
     serde_raw::TxExpectRaw,
@@ -12,6 +13,9 @@ pub struct TxExpect {
     pub logs: CheckLogs,
     pub gas: CheckValue<U64Value>,
     pub refund: CheckValue<U64Value>,
+
+    /// Only set when the scenario has an ABI, used for explaining `out` mismatches.
+    pub typed_results: Option<TypedResults>,
 }
 
 impl TxExpect {
@@ -23,6 +27,7 @@ impl TxExpect {
             logs: CheckLogs::Star,
             gas: CheckValue::Star,
             refund: CheckValue::Star,
+            typed_results: None,
         }
     }
 
@@ -42,6 +47,7 @@ impl TxExpect {
             logs: CheckLogs::Star,
             gas: CheckValue::Star,
             refund: CheckValue::Star,
+            typed_results: None,
         }
     }
 
@@ -66,13 +72,28 @@ impl TxExpect {
 
 impl InterpretableFrom<TxExpectRaw> for TxExpect {
     fn interpret_from(from: TxExpectRaw, context: &InterpreterContext) -> Self {
+        TxExpect::interpret_with_result_types(from, None, context)
+    }
+}
+
+impl TxExpect {
+    /// With the ABI result types of the called endpoint, the expected `out` values can be typed.
+    pub fn interpret_with_result_types(
+        from: TxExpectRaw,
+        result_types: Option<&AbiArgTypes>,
+        context: &InterpreterContext,
+    ) -> Self {
         TxExpect {
-            out: CheckValueList::interpret_from(from.out, context),
+            out: interpret_typed_check_list(from.out, result_types, context),
             status: CheckValue::<U64Value>::interpret_from(from.status, context),
             logs: CheckLogs::interpret_from(from.logs, context),
             message: CheckValue::<BytesValue>::interpret_from(from.message, context),
             gas: CheckValue::<U64Value>::interpret_from(from.gas, context),
             refund: CheckValue::<U64Value>::interpret_from(from.refund, context),
+            typed_results: result_types.map(|types| TypedResults {
+                context: context.clone(),
+                types: types.clone(),
+            }),
         }
     }
 }
@@ -99,4 +120,14 @@ impl TxExpect {
             },
         }
     }
+
+    /// Field-level differences between the expected and the actual results, one per line.
+    /// Empty if the scenario has no ABI or the expected results are not typed.
+    pub fn out_typed_diff(&self, actual: &[Vec<u8>]) -> String {
+        let diffs = match &self.typed_results {
+            Some(typed_results) => typed_results.diff(&self.out, actual),
+            None => Vec::new(),
+        };
+        itertools::join(diffs.iter().map(|diff| format!("\n    {}", diff)), "")
+    }
 }
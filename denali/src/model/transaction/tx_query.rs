@@ -1,4 +1,5 @@
 use crate::{
+    abi::interpret_typed_args,
     interpret_trait::{InterpretableFrom, InterpreterContext, IntoRaw},
     model::{AddressValue, BytesValue},
     serde_raw::TxQueryRaw,
@@ -13,14 +14,17 @@ pub struct TxQuery {
 
 impl InterpretableFrom<TxQueryRaw> for TxQuery {
     fn interpret_from(from: TxQueryRaw, context: &InterpreterContext) -> Self {
+        let arguments = interpret_typed_args(
+            from.arguments,
+            context
+                .endpoint_abi_types(Some(from.function.as_str()))
+                .map(|endpoint| &endpoint.inputs),
+            context,
+        );
         TxQuery {
             to: AddressValue::interpret_from(from.to, context),
             function: from.function,
-            arguments: from
-                .arguments
-                .into_iter()
-                .map(|t| BytesValue::interpret_from(t, context))
-                .collect(),
+            arguments,
         }
     }
 }
@@ -1,5 +1,6 @@
 use super::{value_from_slice, AddressValue};
 use crate::{
+    bech32,
     interpret_trait::{InterpretableFrom, InterpreterContext, IntoRaw},
     value_interpreter::interpret_string,
 };
@@ -86,7 +87,7 @@ impl InterpretableFrom<&Address> for AddressKey {
     fn interpret_from(from: &Address, _context: &InterpreterContext) -> Self {
         AddressKey {
             value: from.clone(),
-            original: format!("0x{}", hex::encode(from)),
+            original: format!("bech32:{}", bech32::encode(from)),
         }
     }
 }
@@ -3,6 +3,7 @@ use std::fmt;
 use dharitri_wasm::types::Address;
 
 use crate::{
+    bech32,
     interpret_trait::{InterpretableFrom, InterpreterContext, IntoRaw},
     serde_raw::ValueSubTree,
     value_interpreter::{interpret_string, interpret_subtree},
@@ -86,7 +87,7 @@ impl InterpretableFrom<&Address> for AddressValue {
     fn interpret_from(from: &Address, _context: &InterpreterContext) -> Self {
         AddressValue {
             value: from.clone(),
-            original: ValueSubTree::Str(format!("0x{}", hex::encode(from))),
+            original: ValueSubTree::Str(format!("bech32:{}", bech32::encode(from))),
         }
     }
 }
@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+
+use crate::serde_raw::ValueSubTree;
+
+/// Token-level settings, normally kept by the DCT system SC.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DctTokenRaw {
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner: Option<ValueSubTree>,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paused: Option<ValueSubTree>,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_freeze: Option<ValueSubTree>,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_wipe: Option<ValueSubTree>,
+}
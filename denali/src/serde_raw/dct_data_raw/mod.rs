@@ -6,6 +6,7 @@ mod dct_map_contents_raw_check;
 mod dct_map_raw_check;
 mod dct_raw;
 mod dct_raw_check;
+mod dct_token_raw;
 mod instance_raw;
 
 pub use dct_data_raw_check::*;
@@ -16,4 +17,5 @@ pub use dct_map_contents_raw_check::*;
 pub use dct_map_raw_check::*;
 pub use dct_raw::*;
 pub use dct_raw_check::*;
+pub use dct_token_raw::*;
 pub use instance_raw::*;
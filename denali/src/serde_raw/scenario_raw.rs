@@ -25,6 +25,14 @@ pub struct ScenarioRaw {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub gas_schedule: Option<String>,
 
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub abi: Option<String>,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+
     pub steps: Vec<StepRaw>,
 }
 
@@ -3,8 +3,8 @@ use std::collections::BTreeMap;
 use serde::{Deserialize, Serialize};
 
 use crate::serde_raw::{
-    AccountRaw, BlockInfoRaw, CheckAccountsRaw, NewAddressRaw, TxCallRaw, TxDeployRaw, TxExpectRaw,
-    TxQueryRaw, TxTransferRaw, TxValidatorRewardRaw, ValueSubTree,
+    AccountRaw, BlockInfoRaw, CheckAccountsRaw, DctTokenRaw, NewAddressRaw, TxCallRaw, TxDeployRaw,
+    TxExpectRaw, TxQueryRaw, TxTransferRaw, TxValidatorRewardRaw, ValueSubTree,
 };
 
 #[derive(Serialize, Deserialize)]
@@ -29,6 +29,11 @@ pub enum StepRaw {
         #[serde(skip_serializing_if = "BTreeMap::is_empty")]
         accounts: BTreeMap<String, AccountRaw>,
 
+        /// Only supported by the Rust runner (denali-rs).
+        #[serde(default)]
+        #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+        dct_tokens: BTreeMap<String, DctTokenRaw>,
+
         #[serde(default)]
         #[serde(skip_serializing_if = "Vec::is_empty")]
         new_addresses: Vec<NewAddressRaw>,
@@ -143,4 +148,21 @@ pub enum StepRaw {
         #[serde(skip_serializing_if = "Option::is_none")]
         comment: Option<String>,
     },
+
+    Snapshot {
+        #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        comment: Option<String>,
+
+        id: String,
+    },
+
+    #[serde(rename_all = "camelCase")]
+    RestoreSnapshot {
+        #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        comment: Option<String>,
+
+        id: String,
+    },
 }
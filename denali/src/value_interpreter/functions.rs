@@ -1,3 +1,5 @@
+use ripemd160::Ripemd160;
+use sha2::Sha256;
 use sha3::{Digest, Keccak256};
 
 const SC_ADDRESS_NUM_LEADING_ZEROS: usize = 8;
@@ -9,6 +11,20 @@ pub(crate) fn keccak256(data: &[u8]) -> Vec<u8> {
     hash.into()
 }
 
+pub(crate) fn sha256(data: &[u8]) -> Vec<u8> {
+    let mut hasher = Sha256::new();
+    hasher.update(data);
+    let hash: [u8; 32] = hasher.finalize().into();
+    hash.into()
+}
+
+pub(crate) fn ripemd160(data: &[u8]) -> Vec<u8> {
+    let mut hasher = Ripemd160::new();
+    hasher.update(data);
+    let hash: [u8; 20] = hasher.finalize().into();
+    hash.into()
+}
+
 fn decode_shard_id(shard_id_raw: &str) -> u8 {
     let shard_id = hex::decode(shard_id_raw).unwrap();
     assert!(
@@ -1,4 +1,4 @@
-use crate::{interpret_trait::InterpreterContext, serde_raw::ValueSubTree};
+use crate::{bech32, interpret_trait::InterpreterContext, serde_raw::ValueSubTree};
 
 use super::{file_loader::load_file, functions::*, parse_num::*, prefixes::*};
 
@@ -28,7 +28,7 @@ pub fn interpret_string(s: &str, context: &InterpreterContext) -> Vec<u8> {
     }
 
     // concatenate values of different formats
-    let split_parts: Vec<_> = s.split('|').collect();
+    let split_parts = split_outside_composites(s, '|');
     if split_parts.len() > 1 {
         let mut result = Vec::<u8>::new();
         for part in split_parts.iter() {
@@ -59,6 +59,10 @@ pub fn interpret_string(s: &str, context: &InterpreterContext) -> Vec<u8> {
         return sc_address_expression(stripped);
     }
 
+    if let Some(stripped) = s.strip_prefix(BECH32_PREFIX) {
+        return bech32::decode(stripped).to_vec();
+    }
+
     if let Some(stripped) = s.strip_prefix(FILE_PREFIX) {
         return load_file(stripped, context);
     }
@@ -68,10 +72,28 @@ pub fn interpret_string(s: &str, context: &InterpreterContext) -> Vec<u8> {
         return keccak256(arg.as_slice());
     }
 
+    if let Some(stripped) = s.strip_prefix(SHA256_PREFIX) {
+        let arg = interpret_string(stripped, context);
+        return sha256(arg.as_slice());
+    }
+
+    if let Some(stripped) = s.strip_prefix(RIPEMD160_PREFIX) {
+        let arg = interpret_string(stripped, context);
+        return ripemd160(arg.as_slice());
+    }
+
     if let Some(stripped) = s.strip_prefix(NESTED_PREFIX) {
         return parse_nested(stripped, context);
     }
 
+    if let Some(stripped) = s.strip_prefix(LIST_PREFIX) {
+        return parse_list(stripped, context);
+    }
+
+    if let Some(stripped) = s.strip_prefix(STRUCT_PREFIX) {
+        return parse_struct(stripped, context);
+    }
+
     if let Some(fixed_width) = try_parse_fixed_width(s) {
         return fixed_width;
     }
@@ -84,3 +106,79 @@ fn parse_nested(s: &str, context: &InterpreterContext) -> Vec<u8> {
     let encoded_length = (parsed.len() as u32).to_be_bytes();
     [&encoded_length[..], &parsed[..]].concat()
 }
+
+/// `list:(item1,item2,...)` is the nested encoding of a list:
+/// the number of items as u32, followed by the items.
+///
+/// Items are not encoded any further, so they should be nested-encoded values themselves,
+/// e.g. `list:(u32:1,biguint:5,nested:str:abc)`.
+fn parse_list(s: &str, context: &InterpreterContext) -> Vec<u8> {
+    let items = composite_items(s);
+    let mut result = (items.len() as u32).to_be_bytes().to_vec();
+    for item in items {
+        result.extend_from_slice(interpret_string(item, context).as_slice());
+    }
+    result
+}
+
+/// `struct:(field1,field2,...)` concatenates its fields, which should be nested-encoded,
+/// same as the items of a list.
+fn parse_struct(s: &str, context: &InterpreterContext) -> Vec<u8> {
+    let mut result = Vec::new();
+    for field in composite_items(s) {
+        result.extend_from_slice(interpret_string(field, context).as_slice());
+    }
+    result
+}
+
+/// Splits the contents of the parentheses following a composite prefix.
+///
+/// Commas always separate items here, numbers inside composites can only use `_` as digit separator.
+fn composite_items(s: &str) -> Vec<&str> {
+    let inner = s
+        .strip_prefix('(')
+        .and_then(|rest| rest.strip_suffix(')'))
+        .unwrap_or_else(|| {
+            panic!(
+                "composite value contents must be enclosed in parentheses, got: `{}`",
+                s
+            )
+        });
+    if inner.trim().is_empty() {
+        return Vec::new();
+    }
+    split_outside_composites(inner, ',')
+        .into_iter()
+        .map(str::trim)
+        .collect()
+}
+
+/// Splits by the separator, except inside the parentheses of `list:` and `struct:` values,
+/// so these can contain separators and be nested in one another.
+///
+/// Parentheses anywhere else have no special meaning, to not affect existing values.
+fn split_outside_composites(s: &str, separator: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut part_start = 0;
+    for (index, c) in s.char_indices() {
+        match c {
+            '(' if depth > 0
+                || s[..index].ends_with(LIST_PREFIX)
+                || s[..index].ends_with(STRUCT_PREFIX) =>
+            {
+                depth += 1;
+            },
+            ')' if depth > 0 => {
+                depth -= 1;
+            },
+            _ if c == separator && depth == 0 => {
+                parts.push(&s[part_start..index]);
+                part_start = index + c.len_utf8();
+            },
+            _ => {},
+        }
+    }
+    parts.push(&s[part_start..]);
+    parts
+}
@@ -2,8 +2,11 @@ pub(super) const STR_PREFIXES: &[&str] = &["str:", "``", "''"];
 
 pub(super) const ADDR_PREFIX: &str = "address:";
 pub(super) const SC_ADDR_PREFIX: &str = "sc:";
+pub(super) const BECH32_PREFIX: &str = "bech32:";
 pub(super) const FILE_PREFIX: &str = "file:";
 pub(super) const KECCAK256_PREFIX: &str = "keccak256:";
+pub(super) const SHA256_PREFIX: &str = "sha256:";
+pub(super) const RIPEMD160_PREFIX: &str = "ripemd160:";
 
 pub(super) const U64_PREFIX: &str = "u64:";
 pub(super) const U32_PREFIX: &str = "u32:";
@@ -16,3 +19,5 @@ pub(super) const I8_PREFIX: &str = "i8:";
 
 pub(super) const BIGUINT_PREFIX: &str = "biguint:";
 pub(super) const NESTED_PREFIX: &str = "nested:";
+pub(super) const LIST_PREFIX: &str = "list:";
+pub(super) const STRUCT_PREFIX: &str = "struct:";
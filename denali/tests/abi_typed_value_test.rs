@@ -0,0 +1,209 @@
+use denali::{
+    abi::{interpret_typed_args, ScenarioAbi},
+    interpret_trait::InterpreterContext,
+    serde_raw::ValueSubTree,
+};
+
+const ABI_JSON: &str = r#"{
+    "constructor": {
+        "inputs": [{ "name": "config", "type": "Config" }],
+        "outputs": []
+    },
+    "endpoints": [
+        {
+            "name": "setItems",
+            "inputs": [
+                { "name": "id", "type": "u32" },
+                { "name": "items", "type": "variadic<multi<Color,Option<i16>>>", "multi_arg": true }
+            ],
+            "outputs": []
+        }
+    ],
+    "types": {
+        "Config": {
+            "type": "struct",
+            "fields": [
+                { "name": "owner", "type": "Address" },
+                { "name": "fee", "type": "BigUint" },
+                { "name": "name", "type": "bytes" },
+                { "name": "colors", "type": "List<Color>" },
+                { "name": "pair", "type": "tuple<u8,bool>" }
+            ]
+        },
+        "Color": {
+            "type": "enum",
+            "variants": [
+                { "name": "Red", "discriminant": 0 },
+                { "name": "Green", "discriminant": 1 },
+                {
+                    "name": "Custom",
+                    "discriminant": 2,
+                    "fields": [
+                        { "name": "rgb", "type": "array3<u8>" },
+                        { "name": "alpha", "type": "i8" }
+                    ]
+                }
+            ]
+        }
+    }
+}"#;
+
+fn context() -> InterpreterContext {
+    InterpreterContext::default().with_abi(ScenarioAbi::from_json_str(ABI_JSON))
+}
+
+fn value(json: &str) -> ValueSubTree {
+    serde_json::from_str(json).unwrap()
+}
+
+const CONFIG_JSON: &str = r#"{
+    "owner": "address:owner",
+    "fee": "1000",
+    "name": "str:abc",
+    "colors": ["Green", { "Custom": { "rgb": ["1", "2", "3"], "alpha": "-1" } }],
+    "pair": ["7", "true"]
+}"#;
+
+fn config_encoded() -> Vec<u8> {
+    let mut expected = b"owner___________________________".to_vec();
+    expected.extend_from_slice(&[0, 0, 0, 2, 0x03, 0xe8]);
+    expected.extend_from_slice(&[0, 0, 0, 3, b'a', b'b', b'c']);
+    expected.extend_from_slice(&[0, 0, 0, 2, 1, 2, 1, 2, 3, 0xff]);
+    expected.extend_from_slice(&[7, 1]);
+    expected
+}
+
+#[test]
+fn test_encode_struct() {
+    let context = &context();
+    let abi = context.abi.as_ref().unwrap();
+    assert_eq!(
+        config_encoded(),
+        abi.encode_top(&value(CONFIG_JSON), "Config", context)
+    );
+}
+
+#[test]
+fn test_encode_enum_top() {
+    let context = &context();
+    let abi = context.abi.as_ref().unwrap();
+    assert_eq!(
+        Vec::<u8>::new(),
+        abi.encode_top(&value(r#""Red""#), "Color", context)
+    );
+    assert_eq!(
+        vec![1],
+        abi.encode_top(&value(r#""Green""#), "Color", context)
+    );
+    assert_eq!(
+        vec![2, 4, 5, 6, 0x80],
+        abi.encode_top(
+            &value(r#"{ "Custom": [["4", "5", "6"], "-128"] }"#),
+            "Color",
+            context
+        )
+    );
+}
+
+#[test]
+fn test_typed_args_multi_value() {
+    let context = &context();
+    let arg_types = &context.endpoint_abi_types(Some("setItems")).unwrap().inputs;
+    let args = interpret_typed_args(
+        vec![
+            value(r#""5""#),
+            value(r#""Green""#),
+            value(r#""-2""#),
+            value(r#""Red""#),
+            value(r#""""#),
+        ],
+        Some(arg_types),
+        context,
+    );
+    let arg_values: Vec<Vec<u8>> = args.into_iter().map(|arg| arg.value).collect();
+    assert_eq!(
+        vec![vec![5], vec![1], vec![1, 0xff, 0xfe], vec![], vec![]],
+        arg_values
+    );
+}
+
+#[test]
+fn test_decode_struct() {
+    let context = &context();
+    let abi = context.abi.as_ref().unwrap();
+    let decoded = abi
+        .decode_top(config_encoded().as_slice(), "Config")
+        .unwrap();
+    assert_eq!(
+        config_encoded(),
+        abi.encode_top(&decoded, "Config", context)
+    );
+    assert_eq!(
+        value(r#"{ "Custom": { "rgb": ["1", "2", "3"], "alpha": "-1" } }"#),
+        abi.decode_top(&[2, 1, 2, 3, 0xff], "Color").unwrap()
+    );
+    assert!(abi.decode_top(&[5], "Color").is_err());
+}
+
+#[test]
+fn test_diff() {
+    let context = &context();
+    let abi = context.abi.as_ref().unwrap();
+    let actual = config_encoded();
+    assert!(abi
+        .diff_top(
+            "out[0]",
+            &value(CONFIG_JSON),
+            actual.as_slice(),
+            "Config",
+            context
+        )
+        .is_empty());
+
+    let expected = value(
+        r#"{
+            "owner": "address:owner",
+            "fee": "1001",
+            "name": "str:abc",
+            "colors": ["Green", { "Custom": { "rgb": ["1", "2", "4"], "alpha": "-1" } }],
+            "pair": ["7", "false"]
+        }"#,
+    );
+    assert_eq!(
+        vec![
+            r#"out[0].fee: want "1001", have "1000""#.to_string(),
+            r#"out[0].colors[1].Custom.rgb[2]: want "4", have "3""#.to_string(),
+            r#"out[0].pair[1]: want "false", have "true""#.to_string(),
+        ],
+        abi.diff_top("out[0]", &expected, actual.as_slice(), "Config", context)
+    );
+
+    let expected = value(
+        r#"{
+            "owner": "address:owner",
+            "fee": "1000",
+            "name": "str:abc",
+            "colors": ["Red"],
+            "pair": ["7", "true"]
+        }"#,
+    );
+    assert_eq!(
+        vec![
+            "out[0].colors: want 1 items, have 2".to_string(),
+            r#"out[0].colors[0]: want "Red", have "Green""#.to_string(),
+        ],
+        abi.diff_top("out[0]", &expected, actual.as_slice(), "Config", context)
+    );
+}
+
+#[test]
+#[should_panic(expected = "missing field pair of Config")]
+fn test_missing_field() {
+    let context = &context();
+    let abi = context.abi.as_ref().unwrap();
+    abi.encode_top(
+        &value(r#"{ "owner": "address:owner", "fee": "1", "name": "", "colors": [] }"#),
+        "Config",
+        context,
+    );
+}
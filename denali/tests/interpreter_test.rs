@@ -1,4 +1,5 @@
 use denali::{interpret_trait::InterpreterContext, value_interpreter::interpret_string};
+use dharitri_wasm::types::heap::Address;
 
 extern crate denali;
 
@@ -280,3 +281,76 @@ fn test_biguint_nested_neg() {
     let context = &InterpreterContext::default();
     interpret_string("biguint:-1", context);
 }
+
+#[test]
+fn test_bech32() {
+    let context = &InterpreterContext::default();
+    let system_sc_bech32 = "moa1qqqqqqqqqqqqqqqpqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqzllls29jpxv";
+    let system_sc_address =
+        hex::decode("000000000000000000010000000000000000000000000000000000000002ffff").unwrap();
+
+    assert_eq!(
+        system_sc_address,
+        interpret_string(&format!("bech32:{}", system_sc_bech32), context)
+    );
+    assert_eq!(
+        system_sc_bech32,
+        denali::bech32::encode(&Address::from_slice(&system_sc_address))
+    );
+}
+
+#[test]
+fn test_hashes() {
+    let context = &InterpreterContext::default();
+
+    assert_eq!(
+        hex::decode("e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855").unwrap(),
+        interpret_string("sha256:", context)
+    );
+    assert_eq!(
+        hex::decode("ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad").unwrap(),
+        interpret_string("sha256:str:abc", context)
+    );
+    assert_eq!(
+        hex::decode("8eb208f7e05d987a9b044a8e98c6b087f15a0bfc").unwrap(),
+        interpret_string("ripemd160:str:abc", context)
+    );
+}
+
+#[test]
+fn test_list_and_struct() {
+    let context = &InterpreterContext::default();
+
+    assert_eq!(
+        vec![0x00, 0x00, 0x00, 0x00],
+        interpret_string("list:()", context)
+    );
+    assert_eq!(
+        vec![
+            0x00, 0x00, 0x00, 0x02, // number of items
+            0x00, 0x00, 0x00, 0x01, 0x05, // biguint:5
+            0x00, 0x00, 0x00, 0x02, b'a', b'b', // nested:str:ab
+        ],
+        interpret_string("list:(biguint:5, nested:str:ab)", context)
+    );
+    assert_eq!(
+        vec![0x00, 0x00, 0x00, 0x01, 0x05, 0x01, 0x02, 0x03],
+        interpret_string("struct:(biguint:5,u8:1|u16:0x0203)", context)
+    );
+    assert_eq!(
+        vec![
+            0x00, 0x00, 0x03, 0xe8, // u32:1_000
+            0x00, 0x00, 0x00, 0x01, // inner list, number of items
+            0x00, 0x00, 0x00, 0x01, 0x07, // biguint:7
+            0xff, // concatenated after the struct
+        ],
+        interpret_string("struct:(u32:1_000,list:(biguint:7))|0xff", context)
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_list_no_parentheses() {
+    let context = &InterpreterContext::default();
+    interpret_string("list:u32:1", context);
+}
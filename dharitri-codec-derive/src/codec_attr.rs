@@ -0,0 +1,201 @@
+use quote::quote;
+use syn::{parse::ParseStream, punctuated::Punctuated, Token};
+
+pub static ATTR_CODEC: &str = "codec";
+
+/// Settings extracted from the `#[codec(...)]` attributes of a struct or variant field.
+#[derive(Default)]
+pub struct CodecFieldAttributes {
+    /// `#[codec(skip)]`: field is not serialized, it gets its default value when decoding.
+    pub skip: bool,
+
+    /// `#[codec(default)]` or `#[codec(default = expr)]`:
+    /// value used when the input runs out before reaching this field (e.g. older data).
+    /// `Some(None)` means `Default::default()`.
+    pub default: Option<Option<syn::Expr>>,
+
+    /// `#[codec(with = module)]`: the module provides custom
+    /// `dep_encode_or_handle_err` and `dep_decode_or_handle_err` functions for the field.
+    /// When `TypeAbi` is also derived, the module must declare an `AbiType` type alias as well.
+    pub with: Option<syn::Path>,
+}
+
+impl CodecFieldAttributes {
+    pub fn parse(field: &syn::Field) -> Self {
+        let mut result = CodecFieldAttributes::default();
+        for arg in codec_args(field.attrs.as_slice()) {
+            match arg {
+                CodecArg::Skip => result.skip = true,
+                CodecArg::Default(expr) => result.default = Some(expr.map(|expr| *expr)),
+                CodecArg::With(path) => result.with = Some(path),
+                CodecArg::Discriminant(_) => {
+                    panic!("`#[codec(discriminant = ...)]` is only allowed on enum variants")
+                },
+                _ => panic!("`#[codec(versioned)]`, `#[codec(version = ...)]` and `#[codec(migrate(...))]` are only allowed on structs"),
+            }
+        }
+        assert!(
+            !(result.skip && result.with.is_some()),
+            "`#[codec(skip)]` and `#[codec(with = ...)]` cannot be used on the same field"
+        );
+        result
+    }
+
+    /// Expression for the field value, used when it is skipped or missing from the input.
+    pub fn default_value_expr(&self) -> proc_macro2::TokenStream {
+        match &self.default {
+            Some(Some(expr)) => quote! { #expr },
+            _ => quote! { core::default::Default::default() },
+        }
+    }
+}
+
+/// Settings extracted from the `#[codec(...)]` attributes of the type itself.
+/// Only allowed on structs, they allow storage layouts to evolve across contract upgrades.
+#[derive(Default)]
+pub struct CodecContainerAttributes {
+    /// `#[codec(versioned)]`: trailing fields missing from the input get their default values,
+    /// as if they were all annotated with `#[codec(default)]`.
+    pub versioned: bool,
+
+    /// `#[codec(version = N)]`: the encoding starts with a version tag byte.
+    pub version: Option<u8>,
+
+    /// `#[codec(migrate(V = function, ...))]`: decodes data tagged with an older version `V`.
+    /// The function receives the old type, which gets decoded first, and converts it to the current one.
+    pub migrations: Vec<(u8, syn::Path)>,
+}
+
+impl CodecContainerAttributes {
+    pub fn parse(ast: &syn::DeriveInput) -> Self {
+        let mut result = CodecContainerAttributes::default();
+        for arg in codec_args(ast.attrs.as_slice()) {
+            match arg {
+                CodecArg::Versioned => result.versioned = true,
+                CodecArg::Version(version) => result.version = Some(version),
+                CodecArg::Migrate(migrations) => result.migrations.extend(migrations),
+                _ => panic!("only `#[codec(versioned)]`, `#[codec(version = ...)]` and `#[codec(migrate(...))]` are allowed on types"),
+            }
+        }
+        if !matches!(ast.data, syn::Data::Struct(_)) {
+            assert!(
+                !result.versioned && result.version.is_none(),
+                "versioned encoding is only supported for structs"
+            );
+        }
+        if let Some(version) = result.version {
+            for (old_version, _) in result.migrations.iter() {
+                assert!(
+                    *old_version != version,
+                    "cannot migrate from the current version {}",
+                    version
+                );
+            }
+        } else {
+            assert!(
+                result.migrations.is_empty(),
+                "`#[codec(migrate(...))]` requires `#[codec(version = ...)]`"
+            );
+        }
+        result
+    }
+}
+
+/// Computes the discriminants of all enum variants.
+/// Variants without an explicit `#[codec(discriminant = N)]` continue from the previous one,
+/// just like regular Rust enum discriminants.
+pub fn variant_discriminants(data_enum: &syn::DataEnum) -> Vec<u8> {
+    let mut discriminants: Vec<u8> = Vec::with_capacity(data_enum.variants.len());
+    let mut next_discriminant = 0usize;
+    for variant in data_enum.variants.iter() {
+        for arg in codec_args(variant.attrs.as_slice()) {
+            match arg {
+                CodecArg::Discriminant(discriminant) => next_discriminant = discriminant as usize,
+                _ => panic!("only `#[codec(discriminant = ...)]` is allowed on enum variants"),
+            }
+        }
+        assert!(
+            next_discriminant < 256,
+            "enum discriminants must fit in a u8"
+        );
+        let discriminant = next_discriminant as u8;
+        assert!(
+            !discriminants.contains(&discriminant),
+            "duplicate enum discriminant {} for variant `{}`",
+            discriminant,
+            variant.ident
+        );
+        discriminants.push(discriminant);
+        next_discriminant += 1;
+    }
+    discriminants
+}
+
+enum CodecArg {
+    Skip,
+    Default(Option<Box<syn::Expr>>),
+    Discriminant(u8),
+    With(syn::Path),
+    Versioned,
+    Version(u8),
+    Migrate(Vec<(u8, syn::Path)>),
+}
+
+fn parse_migration(input: ParseStream) -> syn::Result<(u8, syn::Path)> {
+    let lit: syn::LitInt = input.parse()?;
+    input.parse::<Token![=]>()?;
+    Ok((lit.base10_parse()?, input.parse()?))
+}
+
+impl syn::parse::Parse for CodecArg {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ident: syn::Ident = input.parse()?;
+        if ident == "skip" {
+            Ok(CodecArg::Skip)
+        } else if ident == "default" {
+            if input.peek(Token![=]) {
+                input.parse::<Token![=]>()?;
+                Ok(CodecArg::Default(Some(input.parse()?)))
+            } else {
+                Ok(CodecArg::Default(None))
+            }
+        } else if ident == "discriminant" {
+            input.parse::<Token![=]>()?;
+            let lit: syn::LitInt = input.parse()?;
+            Ok(CodecArg::Discriminant(lit.base10_parse()?))
+        } else if ident == "with" {
+            input.parse::<Token![=]>()?;
+            Ok(CodecArg::With(input.parse()?))
+        } else if ident == "versioned" {
+            Ok(CodecArg::Versioned)
+        } else if ident == "version" {
+            input.parse::<Token![=]>()?;
+            let lit: syn::LitInt = input.parse()?;
+            Ok(CodecArg::Version(lit.base10_parse()?))
+        } else if ident == "migrate" {
+            let content;
+            syn::parenthesized!(content in input);
+            let migrations = Punctuated::<(u8, syn::Path), Token![,]>::parse_terminated_with(
+                &content,
+                parse_migration,
+            )?;
+            Ok(CodecArg::Migrate(migrations.into_iter().collect()))
+        } else {
+            Err(syn::Error::new(
+                ident.span(),
+                "unknown codec attribute, expected one of: skip, default, discriminant, with, versioned, version, migrate",
+            ))
+        }
+    }
+}
+
+fn codec_args(attrs: &[syn::Attribute]) -> Vec<CodecArg> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident(ATTR_CODEC))
+        .flat_map(|attr| {
+            attr.parse_args_with(Punctuated::<CodecArg, Token![,]>::parse_terminated)
+                .unwrap_or_else(|err| panic!("invalid `#[codec(...)]` attribute: {}", err))
+        })
+        .collect()
+}
@@ -1,22 +1,79 @@
 use proc_macro::TokenStream;
 use quote::quote;
 
-use crate::util::*;
+use crate::{
+    codec_attr::{variant_discriminants, CodecContainerAttributes, CodecFieldAttributes},
+    util::*,
+};
 
-pub fn dep_decode_snippet(
-    _index: usize,
+fn dep_decode_value_snippet(
+    index: usize,
     field: &syn::Field,
     input_value: &proc_macro2::TokenStream,
+    context_type_name: &str,
+    default_if_depleted: bool,
 ) -> proc_macro2::TokenStream {
+    let field_attributes = CodecFieldAttributes::parse(field);
+    if field_attributes.skip {
+        return field_attributes.default_value_expr();
+    }
+
     let ty = &field.ty;
-    if let Some(ident) = &field.ident {
+    let decode_fn = if let Some(with_module) = &field_attributes.with {
+        quote! { #with_module::dep_decode_or_handle_err }
+    } else {
+        quote! { <#ty as dharitri_codec::NestedDecode>::dep_decode_or_handle_err }
+    };
+    // the field name only ends up in error messages, if decode error context is enabled
+    let field_name_str = if let Some(ident) = &field.ident {
+        ident.to_string()
+    } else {
+        index.to_string()
+    };
+    let decode_value = quote! {
+        dharitri_codec::decode_context::field(
+            #context_type_name,
+            #field_name_str,
+            #input_value,
+            |input| #decode_fn(input, h),
+        )?
+    };
+
+    if field_attributes.default.is_some() || default_if_depleted {
+        // older data might not contain the field at all
+        let default_value = field_attributes.default_value_expr();
         quote! {
-            #ident: <#ty as dharitri_codec::NestedDecode>::dep_decode_or_handle_err(#input_value, h)?
+            if dharitri_codec::NestedDecodeInput::is_depleted(&*#input_value) {
+                #default_value
+            } else {
+                #decode_value
+            }
         }
     } else {
+        decode_value
+    }
+}
+
+pub fn dep_decode_snippet(
+    index: usize,
+    field: &syn::Field,
+    input_value: &proc_macro2::TokenStream,
+    context_type_name: &str,
+    default_if_depleted: bool,
+) -> proc_macro2::TokenStream {
+    let value = dep_decode_value_snippet(
+        index,
+        field,
+        input_value,
+        context_type_name,
+        default_if_depleted,
+    );
+    if let Some(ident) = &field.ident {
         quote! {
-            <#ty as dharitri_codec::NestedDecode>::dep_decode_or_handle_err(#input_value, h)?
+            #ident: #value
         }
+    } else {
+        value
     }
 }
 
@@ -28,29 +85,85 @@ pub fn variant_dep_decode_snippets(
     data_enum
 		.variants
 		.iter()
-		.enumerate()
-		.map(|(variant_index, variant)| {
-			let variant_index_u8 = variant_index as u8;
+		.zip(variant_discriminants(data_enum))
+		.map(|(variant, discriminant)| {
 			let variant_ident = &variant.ident;
+			let context_type_name = format!("{}::{}", name, variant_ident);
 			let variant_field_snippets = fields_decl_syntax(&variant.fields, |index, field| {
-				dep_decode_snippet(index, field, input_value)
+				dep_decode_snippet(index, field, input_value, &context_type_name, false)
 			});
 			quote! {
-				#variant_index_u8 => core::result::Result::Ok( #name::#variant_ident #variant_field_snippets ),
+				#discriminant => core::result::Result::Ok( #name::#variant_ident #variant_field_snippets ),
 			}
 		})
 		.collect()
 }
 
+/// Wraps the decoding of a whole struct or enum, so that decode error byte offsets start where the value starts.
+/// The decode expression must refer to the input as `input`.
+pub fn decode_context_value_expr(
+    input_value: &proc_macro2::TokenStream,
+    decode_expr: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    quote! {
+        dharitri_codec::decode_context::value(#input_value, |input| { #decode_expr })
+    }
+}
+
+/// Decodes all struct fields, taking the versioning attributes into account.
+/// Evaluates to a `Result<Self, H::HandledErr>`.
+pub fn struct_dep_decode_expr(
+    ast: &syn::DeriveInput,
+    data_struct: &syn::DataStruct,
+    input_value: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let name = &ast.ident;
+    let outer_input_value = input_value;
+    let input_value = &quote! {input};
+    let container_attributes = CodecContainerAttributes::parse(ast);
+    let context_type_name = name.to_string();
+    let field_dep_decode_snippets = fields_decl_syntax(&data_struct.fields, |index, field| {
+        dep_decode_snippet(
+            index,
+            field,
+            input_value,
+            &context_type_name,
+            container_attributes.versioned,
+        )
+    });
+
+    let decode_expr = if let Some(version) = container_attributes.version {
+        let migration_arms = container_attributes
+            .migrations
+            .iter()
+            .map(|(old_version, migrate_fn)| {
+                quote! {
+                    #old_version => core::result::Result::Ok(
+                        #migrate_fn(dharitri_codec::NestedDecode::dep_decode_or_handle_err(#input_value, h)?)
+                    ),
+                }
+            });
+        quote! {
+            match <u8 as dharitri_codec::NestedDecode>::dep_decode_or_handle_err(#input_value, h)? {
+                #version => core::result::Result::Ok(#name #field_dep_decode_snippets),
+                #(#migration_arms)*
+                _ => core::result::Result::Err(h.handle_error(dharitri_codec::DecodeError::UNSUPPORTED_VERSION)),
+            }
+        }
+    } else {
+        quote! {
+            core::result::Result::Ok(#name #field_dep_decode_snippets)
+        }
+    };
+    decode_context_value_expr(outer_input_value, decode_expr)
+}
+
 pub fn nested_decode_impl(ast: &syn::DeriveInput) -> TokenStream {
     let name = &ast.ident;
     let (impl_generics, ty_generics, where_clause) = &ast.generics.split_for_impl();
     let gen = match &ast.data {
         syn::Data::Struct(data_struct) => {
-            let field_dep_decode_snippets =
-                fields_decl_syntax(&data_struct.fields, |index, field| {
-                    dep_decode_snippet(index, field, &quote! {input})
-                });
+            let struct_dep_decode_expr = struct_dep_decode_expr(ast, data_struct, &quote! {input});
             quote! {
                 impl #impl_generics dharitri_codec::NestedDecode for #name #ty_generics #where_clause {
                     fn dep_decode_or_handle_err<I, H>(input: &mut I, h: H) -> core::result::Result<Self, H::HandledErr>
@@ -58,20 +171,29 @@ pub fn nested_decode_impl(ast: &syn::DeriveInput) -> TokenStream {
                         I: dharitri_codec::NestedDecodeInput,
                         H: dharitri_codec::DecodeErrorHandler,
                     {
-                        core::result::Result::Ok(
-                            #name #field_dep_decode_snippets
-                        )
+                        #struct_dep_decode_expr
                     }
                 }
             }
         },
         syn::Data::Enum(data_enum) => {
+            // enums have no container attributes, parsing only checks that none were given
+            CodecContainerAttributes::parse(ast);
             assert!(
                 data_enum.variants.len() < 256,
                 "enums with more than 256 variants not supported"
             );
             let variant_dep_decode_snippets =
                 variant_dep_decode_snippets(name, data_enum, &quote! {input});
+            let enum_dep_decode_expr = decode_context_value_expr(
+                &quote! {input},
+                quote! {
+                    match <u8 as dharitri_codec::NestedDecode>::dep_decode_or_handle_err(input, h)? {
+                        #(#variant_dep_decode_snippets)*
+                        _ => core::result::Result::Err(h.handle_error(dharitri_codec::DecodeError::INVALID_VALUE)),
+                    }
+                },
+            );
 
             quote! {
                 impl #impl_generics dharitri_codec::NestedDecode for #name #ty_generics #where_clause {
@@ -80,10 +202,7 @@ pub fn nested_decode_impl(ast: &syn::DeriveInput) -> TokenStream {
                         I: dharitri_codec::NestedDecodeInput,
                         H: dharitri_codec::DecodeErrorHandler,
                     {
-                        match <u8 as dharitri_codec::NestedDecode>::dep_decode_or_handle_err(input, h)? {
-                            #(#variant_dep_decode_snippets)*
-                            _ => core::result::Result::Err(h.handle_error(dharitri_codec::DecodeError::INVALID_VALUE)),
-                        }
+                        #enum_dep_decode_expr
                     }
                 }
             }
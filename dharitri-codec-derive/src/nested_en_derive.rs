@@ -1,10 +1,36 @@
-use crate::util::*;
+use crate::{
+    codec_attr::{variant_discriminants, CodecContainerAttributes, CodecFieldAttributes},
+    util::*,
+};
 use proc_macro::TokenStream;
 use quote::quote;
 
-pub fn dep_encode_snippet(value: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
-    quote! {
-        dharitri_codec::NestedEncode::dep_encode_or_handle_err(&#value, dest, h)?;
+pub fn dep_encode_snippet(
+    field: &syn::Field,
+    value: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let field_attributes = CodecFieldAttributes::parse(field);
+    if field_attributes.skip {
+        quote! {}
+    } else if let Some(with_module) = &field_attributes.with {
+        quote! {
+            #with_module::dep_encode_or_handle_err(&#value, dest, h)?;
+        }
+    } else {
+        quote! {
+            dharitri_codec::NestedEncode::dep_encode_or_handle_err(&#value, dest, h)?;
+        }
+    }
+}
+
+/// Encodes the version tag that precedes the fields of structs with `#[codec(version = N)]`.
+pub fn version_tag_encode_snippet(ast: &syn::DeriveInput) -> proc_macro2::TokenStream {
+    if let Some(version) = CodecContainerAttributes::parse(ast).version {
+        quote! {
+            dharitri_codec::NestedEncode::dep_encode_or_handle_err(&#version, dest, h)?;
+        }
+    } else {
+        quote! {}
     }
 }
 
@@ -15,18 +41,16 @@ fn variant_dep_encode_snippets(
     data_enum
         .variants
         .iter()
-        .enumerate()
-        .map(|(variant_index, variant)| {
-            let variant_index_u8 = variant_index as u8;
+        .zip(variant_discriminants(data_enum))
+        .map(|(variant, discriminant)| {
             let variant_ident = &variant.ident;
-            let local_var_declarations =
-                fields_decl_syntax(&variant.fields, local_variable_for_field);
+            let local_var_declarations = fields_decl_syntax(&variant.fields, pattern_for_field);
             let variant_field_snippets = fields_snippets(&variant.fields, |index, field| {
-                dep_encode_snippet(&local_variable_for_field(index, field))
+                dep_encode_snippet(field, &local_variable_for_field(index, field))
             });
             quote! {
                 #name::#variant_ident #local_var_declarations => {
-                    dharitri_codec::NestedEncode::dep_encode_or_handle_err(&#variant_index_u8, dest, h)?;
+                    dharitri_codec::NestedEncode::dep_encode_or_handle_err(&#discriminant, dest, h)?;
                     #(#variant_field_snippets)*
                 },
             }
@@ -40,8 +64,9 @@ pub fn nested_encode_impl(ast: &syn::DeriveInput) -> TokenStream {
     let gen = match &ast.data {
         syn::Data::Struct(data_struct) => {
             let field_dep_encode_snippets = fields_snippets(&data_struct.fields, |index, field| {
-                dep_encode_snippet(&self_field_expr(index, field))
+                dep_encode_snippet(field, &self_field_expr(index, field))
             });
+            let version_tag_encode_snippet = version_tag_encode_snippet(ast);
             quote! {
                 impl #impl_generics dharitri_codec::NestedEncode for #name #ty_generics #where_clause {
                     fn dep_encode_or_handle_err<O, H>(&self, dest: &mut O, h: H) -> core::result::Result<(), H::HandledErr>
@@ -49,6 +74,7 @@ pub fn nested_encode_impl(ast: &syn::DeriveInput) -> TokenStream {
                         O: dharitri_codec::NestedEncodeOutput,
                         H: dharitri_codec::EncodeErrorHandler,
                     {
+                        #version_tag_encode_snippet
                         #(#field_dep_encode_snippets)*
                         core::result::Result::Ok(())
                     }
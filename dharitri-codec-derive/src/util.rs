@@ -1,3 +1,4 @@
+use crate::codec_attr::CodecFieldAttributes;
 use quote::quote;
 
 pub fn is_fieldless_enum(data_enum: &syn::DataEnum) -> bool {
@@ -34,6 +35,25 @@ pub fn local_variable_for_field(index: usize, field: &syn::Field) -> proc_macro2
     }
 }
 
+/// Pattern binding a field to its local variable.
+/// Fields that are not serialized are ignored, to avoid unused variable warnings.
+pub fn pattern_for_field(index: usize, field: &syn::Field) -> proc_macro2::TokenStream {
+    let local_var = local_variable_for_field(index, field);
+    if !CodecFieldAttributes::parse(field).skip {
+        return local_var;
+    }
+
+    if field.ident.is_some() {
+        quote! {
+            #local_var: _
+        }
+    } else {
+        quote! {
+            _
+        }
+    }
+}
+
 pub fn fields_snippets<F>(fields: &syn::Fields, field_mapper: F) -> Vec<proc_macro2::TokenStream>
 where
     F: Fn(usize, &syn::Field) -> proc_macro2::TokenStream,
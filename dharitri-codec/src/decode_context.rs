@@ -0,0 +1,240 @@
+//! Optional context for decode errors, telling which field or item failed to decode,
+//! e.g. `Order.items[3].price: input too short at byte 57`.
+//!
+//! Only tracked with the `decode-error-context` feature, which requires std and is meant for debugging.
+//! Without it, all functions here compile down to the plain decode calls, at zero cost in wasm.
+
+use crate::NestedDecodeInput;
+
+/// Decodes a whole struct or enum.
+///
+/// Does not show up in the error path, it only marks where the value starts,
+/// so that byte offsets also account for version tags and enum discriminants.
+#[inline(always)]
+pub fn value<I, F, T, E>(input: &mut I, decode: F) -> Result<T, E>
+where
+    I: NestedDecodeInput,
+    F: FnOnce(&mut I) -> Result<T, E>,
+{
+    with_segment(ContextSegment::Value, input, decode)
+}
+
+/// Decodes a struct or enum variant field, recording its name in the error context.
+#[inline(always)]
+pub fn field<I, F, T, E>(
+    type_name: &'static str,
+    field_name: &'static str,
+    input: &mut I,
+    decode: F,
+) -> Result<T, E>
+where
+    I: NestedDecodeInput,
+    F: FnOnce(&mut I) -> Result<T, E>,
+{
+    with_segment(
+        ContextSegment::Field {
+            type_name,
+            field_name,
+        },
+        input,
+        decode,
+    )
+}
+
+/// Decodes a collection item, recording its index in the error context.
+#[inline(always)]
+pub fn item<I, F, T, E>(index: usize, input: &mut I, decode: F) -> Result<T, E>
+where
+    I: NestedDecodeInput,
+    F: FnOnce(&mut I) -> Result<T, E>,
+{
+    with_segment(ContextSegment::Item(index), input, decode)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ContextSegment {
+    Value,
+    Field {
+        type_name: &'static str,
+        field_name: &'static str,
+    },
+    Item(usize),
+}
+
+#[cfg(not(feature = "decode-error-context"))]
+#[inline(always)]
+fn with_segment<I, F, T, E>(_segment: ContextSegment, input: &mut I, decode: F) -> Result<T, E>
+where
+    I: NestedDecodeInput,
+    F: FnOnce(&mut I) -> Result<T, E>,
+{
+    decode(input)
+}
+
+#[cfg(feature = "decode-error-context")]
+pub use tracking::*;
+
+#[cfg(feature = "decode-error-context")]
+mod tracking {
+    use super::ContextSegment;
+    use crate::{DecodeError, NestedDecodeInput};
+    use alloc::{format, string::String, vec::Vec};
+    use core::{cell::RefCell, fmt};
+    use std::thread_local;
+
+    /// Where a decode error occurred.
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct DecodeErrorContext {
+        /// Path to the value that failed, e.g. `Order.items[3].price`.
+        pub path: String,
+
+        /// Where the value starts, relative to the beginning of the decoded input.
+        pub byte_offset: usize,
+    }
+
+    impl DecodeErrorContext {
+        /// Full error description, e.g. `Order.items[3].price: input too short at byte 57`.
+        pub fn describe(&self, err: &DecodeError) -> String {
+            format!(
+                "{}: {} at byte {}",
+                self.path,
+                err.message_str(),
+                self.byte_offset
+            )
+        }
+    }
+
+    impl fmt::Display for DecodeErrorContext {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{}, byte {}", self.path, self.byte_offset)
+        }
+    }
+
+    struct ContextFrame {
+        segment: ContextSegment,
+        remaining_len: usize,
+    }
+
+    #[derive(Default)]
+    struct ContextState {
+        frames: Vec<ContextFrame>,
+        last_error: Option<DecodeErrorContext>,
+    }
+
+    impl ContextState {
+        fn error_context(&self) -> Option<DecodeErrorContext> {
+            let first = self.frames.first()?;
+            let last = self.frames.last()?;
+            let mut path = String::new();
+            for frame in self.frames.iter() {
+                match frame.segment {
+                    ContextSegment::Value => {},
+                    ContextSegment::Field {
+                        type_name,
+                        field_name,
+                    } => {
+                        if path.is_empty() {
+                            path.push_str(type_name);
+                        }
+                        path.push('.');
+                        path.push_str(field_name);
+                    },
+                    ContextSegment::Item(index) => {
+                        path.push_str(&format!("[{}]", index));
+                    },
+                }
+            }
+            if path.is_empty() {
+                // failed before reaching any field or item, nothing to add to the error
+                return None;
+            }
+            Some(DecodeErrorContext {
+                path,
+                byte_offset: first.remaining_len - last.remaining_len,
+            })
+        }
+
+        /// Only the innermost failing frame gets recorded, the outer ones see it already set.
+        fn record_error(&mut self) {
+            if self.last_error.is_none() {
+                self.last_error = self.error_context();
+            }
+        }
+    }
+
+    thread_local!(
+        static CONTEXT_STATE: RefCell<ContextState> = RefCell::new(ContextState::default())
+    );
+
+    /// Pops the frame even when a panicking error handler unwinds the stack,
+    /// recording the context of the panic on the way.
+    struct FrameGuard;
+
+    impl Drop for FrameGuard {
+        fn drop(&mut self) {
+            CONTEXT_STATE.with(|state| {
+                let mut state = state.borrow_mut();
+                if std::thread::panicking() {
+                    state.record_error();
+                }
+                state.frames.pop();
+            });
+        }
+    }
+
+    pub(super) fn with_segment<I, F, T, E>(
+        segment: ContextSegment,
+        input: &mut I,
+        decode: F,
+    ) -> Result<T, E>
+    where
+        I: NestedDecodeInput,
+        F: FnOnce(&mut I) -> Result<T, E>,
+    {
+        CONTEXT_STATE.with(|state| {
+            let mut state = state.borrow_mut();
+            if state.frames.is_empty() {
+                // a new decode starts
+                state.last_error = None;
+            }
+            state.frames.push(ContextFrame {
+                segment,
+                remaining_len: input.remaining_len(),
+            });
+        });
+        let guard = FrameGuard;
+        let result = decode(input);
+        if result.is_err() {
+            CONTEXT_STATE.with(|state| state.borrow_mut().record_error());
+        }
+        drop(guard);
+        if result.is_ok() {
+            CONTEXT_STATE.with(|state| {
+                let mut state = state.borrow_mut();
+                if state.frames.is_empty() {
+                    // the whole decode succeeded
+                    state.last_error = None;
+                }
+            });
+        }
+        result
+    }
+
+    /// The context of the decode currently in progress, if any.
+    ///
+    /// Useful in error handlers that stop execution immediately.
+    pub fn current_error_context() -> Option<DecodeErrorContext> {
+        CONTEXT_STATE.with(|state| state.borrow().error_context())
+    }
+
+    /// The context of the last failed decode on this thread.
+    /// Cleared when a new decode starts.
+    pub fn last_error_context() -> Option<DecodeErrorContext> {
+        CONTEXT_STATE.with(|state| state.borrow().last_error.clone())
+    }
+
+    /// Same as `last_error_context`, but also clears it, so it does not get reported twice.
+    pub fn take_last_error_context() -> Option<DecodeErrorContext> {
+        CONTEXT_STATE.with(|state| state.borrow_mut().last_error.take())
+    }
+}
@@ -0,0 +1,134 @@
+use crate::{
+    decode_context, DecodeError, DecodeErrorHandler, EncodeErrorHandler, NestedDecode,
+    NestedDecodeInput, NestedEncode, NestedEncodeOutput, TopDecode, TopDecodeInput, TopEncode,
+    TopEncodeOutput,
+};
+use alloc::collections::BTreeMap;
+
+/// Same encoding as a `Vec<(K, V)>` of the entries, in ascending key order.
+impl<K: NestedEncode, V: NestedEncode> TopEncode for BTreeMap<K, V> {
+    fn top_encode_or_handle_err<O, H>(&self, output: O, h: H) -> Result<(), H::HandledErr>
+    where
+        O: TopEncodeOutput,
+        H: EncodeErrorHandler,
+    {
+        let mut buffer = output.start_nested_encode();
+        for (key, value) in self.iter() {
+            key.dep_encode_or_handle_err(&mut buffer, h)?;
+            value.dep_encode_or_handle_err(&mut buffer, h)?;
+        }
+        output.finalize_nested_encode(buffer);
+        Ok(())
+    }
+}
+
+/// Decodes the next entry and adds it to the map.
+/// Only the canonical encoding is accepted: keys must be strictly ascending.
+fn dep_decode_next_entry<K, V, I, H>(
+    map: &mut BTreeMap<K, V>,
+    input: &mut I,
+    h: H,
+) -> Result<(), H::HandledErr>
+where
+    K: NestedDecode + Ord,
+    V: NestedDecode,
+    I: NestedDecodeInput,
+    H: DecodeErrorHandler,
+{
+    let key = K::dep_decode_or_handle_err(input, h)?;
+    if let Some((last_key, _)) = map.last_key_value() {
+        if key <= *last_key {
+            return Err(h.handle_error(DecodeError::INVALID_VALUE));
+        }
+    }
+    let value = V::dep_decode_or_handle_err(input, h)?;
+    map.insert(key, value);
+    Ok(())
+}
+
+impl<K: NestedDecode + Ord, V: NestedDecode> TopDecode for BTreeMap<K, V> {
+    fn top_decode_or_handle_err<I, H>(input: I, h: H) -> Result<Self, H::HandledErr>
+    where
+        I: TopDecodeInput,
+        H: DecodeErrorHandler,
+    {
+        let mut nested_buffer = input.into_nested_buffer();
+        decode_context::value(&mut nested_buffer, |input| {
+            let mut result = BTreeMap::new();
+            while !input.is_depleted() {
+                let index = result.len();
+                decode_context::item(index, input, |input| {
+                    dep_decode_next_entry(&mut result, input, h)
+                })?;
+            }
+            Ok(result)
+        })
+    }
+}
+
+impl<K: NestedEncode, V: NestedEncode> NestedEncode for BTreeMap<K, V> {
+    fn dep_encode_or_handle_err<O, H>(&self, dest: &mut O, h: H) -> Result<(), H::HandledErr>
+    where
+        O: NestedEncodeOutput,
+        H: EncodeErrorHandler,
+    {
+        self.len().dep_encode_or_handle_err(dest, h)?;
+        for (key, value) in self.iter() {
+            key.dep_encode_or_handle_err(dest, h)?;
+            value.dep_encode_or_handle_err(dest, h)?;
+        }
+        Ok(())
+    }
+}
+
+impl<K: NestedDecode + Ord, V: NestedDecode> NestedDecode for BTreeMap<K, V> {
+    fn dep_decode_or_handle_err<I, H>(input: &mut I, h: H) -> Result<Self, H::HandledErr>
+    where
+        I: NestedDecodeInput,
+        H: DecodeErrorHandler,
+    {
+        decode_context::value(input, |input| {
+            let size = usize::dep_decode_or_handle_err(input, h)?;
+            let mut result = BTreeMap::new();
+            for index in 0..size {
+                decode_context::item(index, input, |input| {
+                    dep_decode_next_entry(&mut result, input, h)
+                })?;
+            }
+            Ok(result)
+        })
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::{
+        test_util::{check_dep_encode_decode, check_top_encode_decode},
+        DecodeError, TopDecode,
+    };
+    use alloc::collections::BTreeMap;
+
+    #[test]
+    fn test_btree_map() {
+        let mut map = BTreeMap::new();
+        map.insert(2u8, 20u16);
+        map.insert(1u8, 10u16);
+        check_top_encode_decode(map.clone(), &[1, 0, 10, 2, 0, 20]);
+        check_dep_encode_decode(map, &[0, 0, 0, 2, 1, 0, 10, 2, 0, 20]);
+        check_top_encode_decode(BTreeMap::<u8, u16>::new(), &[]);
+    }
+
+    #[test]
+    fn test_btree_map_not_canonical() {
+        // unordered keys
+        assert_eq!(
+            BTreeMap::<u8, u8>::top_decode(&[2, 20, 1, 10][..]),
+            Err(DecodeError::INVALID_VALUE)
+        );
+        // duplicate keys
+        assert_eq!(
+            BTreeMap::<u8, u8>::top_decode(&[1, 10, 1, 20][..]),
+            Err(DecodeError::INVALID_VALUE)
+        );
+    }
+}
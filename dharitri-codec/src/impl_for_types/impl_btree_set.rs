@@ -0,0 +1,123 @@
+use crate::{
+    decode_context, DecodeError, DecodeErrorHandler, EncodeErrorHandler, NestedDecode,
+    NestedDecodeInput, NestedEncode, NestedEncodeOutput, TopDecode, TopDecodeInput, TopEncode,
+    TopEncodeOutput,
+};
+use alloc::collections::BTreeSet;
+
+/// Same encoding as a `Vec<T>` of the items, in ascending order.
+impl<T: NestedEncode> TopEncode for BTreeSet<T> {
+    fn top_encode_or_handle_err<O, H>(&self, output: O, h: H) -> Result<(), H::HandledErr>
+    where
+        O: TopEncodeOutput,
+        H: EncodeErrorHandler,
+    {
+        let mut buffer = output.start_nested_encode();
+        for item in self.iter() {
+            item.dep_encode_or_handle_err(&mut buffer, h)?;
+        }
+        output.finalize_nested_encode(buffer);
+        Ok(())
+    }
+}
+
+/// Adds the next decoded item to the set.
+/// Only the canonical encoding is accepted: items must be strictly ascending.
+fn insert_next_item<T, H>(set: &mut BTreeSet<T>, item: T, h: H) -> Result<(), H::HandledErr>
+where
+    T: Ord,
+    H: DecodeErrorHandler,
+{
+    if let Some(last) = set.last() {
+        if item <= *last {
+            return Err(h.handle_error(DecodeError::INVALID_VALUE));
+        }
+    }
+    set.insert(item);
+    Ok(())
+}
+
+impl<T: NestedDecode + Ord> TopDecode for BTreeSet<T> {
+    fn top_decode_or_handle_err<I, H>(input: I, h: H) -> Result<Self, H::HandledErr>
+    where
+        I: TopDecodeInput,
+        H: DecodeErrorHandler,
+    {
+        let mut nested_buffer = input.into_nested_buffer();
+        decode_context::value(&mut nested_buffer, |input| {
+            let mut result = BTreeSet::new();
+            while !input.is_depleted() {
+                let item = decode_context::item(result.len(), input, |input| {
+                    T::dep_decode_or_handle_err(input, h)
+                })?;
+                insert_next_item(&mut result, item, h)?;
+            }
+            Ok(result)
+        })
+    }
+}
+
+impl<T: NestedEncode> NestedEncode for BTreeSet<T> {
+    fn dep_encode_or_handle_err<O, H>(&self, dest: &mut O, h: H) -> Result<(), H::HandledErr>
+    where
+        O: NestedEncodeOutput,
+        H: EncodeErrorHandler,
+    {
+        self.len().dep_encode_or_handle_err(dest, h)?;
+        for item in self.iter() {
+            item.dep_encode_or_handle_err(dest, h)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: NestedDecode + Ord> NestedDecode for BTreeSet<T> {
+    fn dep_decode_or_handle_err<I, H>(input: &mut I, h: H) -> Result<Self, H::HandledErr>
+    where
+        I: NestedDecodeInput,
+        H: DecodeErrorHandler,
+    {
+        decode_context::value(input, |input| {
+            let size = usize::dep_decode_or_handle_err(input, h)?;
+            let mut result = BTreeSet::new();
+            for index in 0..size {
+                let item = decode_context::item(index, input, |input| {
+                    T::dep_decode_or_handle_err(input, h)
+                })?;
+                insert_next_item(&mut result, item, h)?;
+            }
+            Ok(result)
+        })
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::{
+        test_util::{check_dep_encode_decode, check_top_encode_decode},
+        DecodeError, NestedDecode, TopDecode,
+    };
+    use alloc::collections::BTreeSet;
+
+    #[test]
+    fn test_btree_set() {
+        let set: BTreeSet<u16> = [3, 1, 2].iter().cloned().collect();
+        check_top_encode_decode(set.clone(), &[0, 1, 0, 2, 0, 3]);
+        check_dep_encode_decode(set, &[0, 0, 0, 3, 0, 1, 0, 2, 0, 3]);
+        check_top_encode_decode(BTreeSet::<u16>::new(), &[]);
+    }
+
+    #[test]
+    fn test_btree_set_not_canonical() {
+        // unordered
+        assert_eq!(
+            BTreeSet::<u8>::top_decode(&[2, 1][..]),
+            Err(DecodeError::INVALID_VALUE)
+        );
+        // duplicate
+        assert_eq!(
+            BTreeSet::<u8>::dep_decode(&mut &[0, 0, 0, 2, 1, 1][..]),
+            Err(DecodeError::INVALID_VALUE)
+        );
+    }
+}
@@ -1,5 +1,6 @@
 use crate::{
-    dep_encode_from_no_err, dep_encode_num_mimic, num_conv::universal_decode_number,
+    dep_encode_from_no_err, dep_encode_num_mimic,
+    num_conv::{top_encode_number_128, universal_decode_number, universal_decode_number_128},
     top_encode_from_no_err, DecodeError, DecodeErrorHandler, EncodeErrorHandler, NestedDecode,
     NestedDecodeInput, NestedEncode, NestedEncodeNoErr, NestedEncodeOutput, TopDecode,
     TopDecodeInput, TopEncode, TopEncodeNoErr, TopEncodeOutput, TypeInfo,
@@ -24,6 +25,16 @@ top_encode_num_signed! {isize, 32, TypeInfo::ISIZE}
 top_encode_num_signed! {i16, 16, TypeInfo::I16}
 top_encode_num_signed! {i8, 8, TypeInfo::I8}
 
+impl TopEncodeNoErr for i128 {
+    #[inline]
+    fn top_encode_no_err<O: TopEncodeOutput>(&self, output: O) {
+        let mut buffer = [0u8; 16];
+        output.set_slice_u8(top_encode_number_128(*self as u128, true, &mut buffer));
+    }
+}
+top_encode_from_no_err! {i128, TypeInfo::Unknown}
+
+dep_encode_num_mimic! {i128, u128, TypeInfo::Unknown}
 dep_encode_num_mimic! {i64, u64, TypeInfo::I64}
 dep_encode_num_mimic! {i32, u32, TypeInfo::I32}
 dep_encode_num_mimic! {isize, u32, TypeInfo::ISIZE}
@@ -55,6 +66,18 @@ dep_decode_num_signed!(i32, 4, TypeInfo::I32);
 dep_decode_num_signed!(isize, 4, TypeInfo::ISIZE);
 dep_decode_num_signed!(i64, 8, TypeInfo::I64);
 
+impl NestedDecode for i128 {
+    fn dep_decode_or_handle_err<I, H>(input: &mut I, h: H) -> Result<Self, H::HandledErr>
+    where
+        I: NestedDecodeInput,
+        H: DecodeErrorHandler,
+    {
+        let mut bytes = [0u8; 16];
+        input.read_into(&mut bytes[..], h)?;
+        Ok(i128::from_be_bytes(bytes))
+    }
+}
+
 macro_rules! top_decode_num_signed {
     ($ty:ty, $bounds_ty:ty, $type_info:expr) => {
         impl TopDecode for $ty {
@@ -84,6 +107,18 @@ top_decode_num_signed!(i32, i32, TypeInfo::I32);
 top_decode_num_signed!(isize, i32, TypeInfo::ISIZE); // even if isize can be 64 bits on some platforms, we always deserialize as max 32 bits
 top_decode_num_signed!(i64, i64, TypeInfo::I64);
 
+impl TopDecode for i128 {
+    fn top_decode_or_handle_err<I, H>(input: I, h: H) -> Result<Self, H::HandledErr>
+    where
+        I: TopDecodeInput,
+        H: DecodeErrorHandler,
+    {
+        let mut buffer = [0u8; 16];
+        let slice = input.into_max_size_buffer(&mut buffer, h)?;
+        Ok(universal_decode_number_128(slice, true) as i128)
+    }
+}
+
 #[cfg(test)]
 pub mod tests {
     use crate::test_util::{check_dep_encode_decode, check_top_encode_decode};
@@ -108,6 +143,17 @@ pub mod tests {
         check_top_encode_decode(-5i32, &[251]);
         check_top_encode_decode(-5i64, &[251]);
         check_top_encode_decode(-5isize, &[251]);
+        // 128 bits
+        check_top_encode_decode(0i128, &[]);
+        check_top_encode_decode(5i128, &[5]);
+        check_top_encode_decode(-5i128, &[251]);
+        check_top_encode_decode(-1i128, &[255]);
+        check_top_encode_decode(128i128, &[0, 128]);
+        check_top_encode_decode(-129i128, &[255, 127]);
+        check_top_encode_decode(
+            i128::MIN,
+            &[0x80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        );
     }
 
     #[test]
@@ -130,5 +176,13 @@ pub mod tests {
         check_dep_encode_decode(-5i32, &[255, 255, 255, 251]);
         check_dep_encode_decode(-5isize, &[255, 255, 255, 251]);
         check_dep_encode_decode(-5i64, &[255, 255, 255, 255, 255, 255, 255, 251]);
+        // 128 bits
+        check_dep_encode_decode(0i128, &[0; 16]);
+        check_dep_encode_decode(
+            -5i128,
+            &[
+                255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 251,
+            ],
+        );
     }
 }
@@ -1,5 +1,6 @@
 use crate::{
-    dep_encode_from_no_err, dep_encode_num_mimic, num_conv::universal_decode_number,
+    dep_encode_from_no_err, dep_encode_num_mimic,
+    num_conv::{top_encode_number_128, universal_decode_number, universal_decode_number_128},
     top_encode_from_no_err, DecodeError, DecodeErrorHandler, EncodeErrorHandler, NestedDecode,
     NestedDecodeInput, NestedEncode, NestedEncodeNoErr, NestedEncodeOutput, TopDecode,
     TopDecodeInput, TopEncode, TopEncodeNoErr, TopEncodeOutput, TypeInfo,
@@ -28,6 +29,7 @@ macro_rules! dep_encode_num_unsigned {
     };
 }
 
+dep_encode_num_unsigned! {u128, 128, TypeInfo::Unknown}
 dep_encode_num_unsigned! {u64, 64, TypeInfo::U64}
 dep_encode_num_unsigned! {u32, 32, TypeInfo::U32}
 dep_encode_num_unsigned! {u16, 16, TypeInfo::U16}
@@ -50,6 +52,15 @@ top_encode_num_unsigned! {usize, 32, TypeInfo::USIZE}
 top_encode_num_unsigned! {u16, 16, TypeInfo::U16}
 top_encode_num_unsigned! {u8, 8, TypeInfo::U8}
 
+impl TopEncodeNoErr for u128 {
+    #[inline]
+    fn top_encode_no_err<O: TopEncodeOutput>(&self, output: O) {
+        let mut buffer = [0u8; 16];
+        output.set_slice_u8(top_encode_number_128(*self, false, &mut buffer));
+    }
+}
+top_encode_from_no_err! {u128, TypeInfo::Unknown}
+
 impl NestedDecode for u8 {
     const TYPE_INFO: TypeInfo = TypeInfo::U8;
 
@@ -86,6 +97,18 @@ dep_decode_num_unsigned!(u32, 4, TypeInfo::U32);
 dep_decode_num_unsigned!(usize, 4, TypeInfo::USIZE);
 dep_decode_num_unsigned!(u64, 8, TypeInfo::U64);
 
+impl NestedDecode for u128 {
+    fn dep_decode_or_handle_err<I, H>(input: &mut I, h: H) -> Result<Self, H::HandledErr>
+    where
+        I: NestedDecodeInput,
+        H: DecodeErrorHandler,
+    {
+        let mut bytes = [0u8; 16];
+        input.read_into(&mut bytes[..], h)?;
+        Ok(u128::from_be_bytes(bytes))
+    }
+}
+
 macro_rules! top_decode_num_unsigned {
     ($ty:ty, $bounds_ty:ty, $type_info:expr) => {
         impl TopDecode for $ty {
@@ -114,6 +137,18 @@ top_decode_num_unsigned!(u32, u32, TypeInfo::U32);
 top_decode_num_unsigned!(usize, u32, TypeInfo::USIZE); // even if usize can be 64 bits on some platforms, we always deserialize as max 32 bits
 top_decode_num_unsigned!(u64, u64, TypeInfo::U64);
 
+impl TopDecode for u128 {
+    fn top_decode_or_handle_err<I, H>(input: I, h: H) -> Result<Self, H::HandledErr>
+    where
+        I: TopDecodeInput,
+        H: DecodeErrorHandler,
+    {
+        let mut buffer = [0u8; 16];
+        let slice = input.into_max_size_buffer(&mut buffer, h)?;
+        Ok(universal_decode_number_128(slice, false))
+    }
+}
+
 #[cfg(test)]
 pub mod tests {
     use crate::test_util::{check_dep_encode_decode, check_top_encode_decode};
@@ -132,6 +167,11 @@ pub mod tests {
         check_top_encode_decode(5u32, &[5]);
         check_top_encode_decode(5u64, &[5]);
         check_top_encode_decode(5usize, &[5]);
+        // 128 bits
+        check_top_encode_decode(0u128, &[]);
+        check_top_encode_decode(5u128, &[5]);
+        check_top_encode_decode(0x100u128, &[1, 0]);
+        check_top_encode_decode(u128::MAX, &[0xff; 16]);
     }
 
     #[test]
@@ -148,5 +188,17 @@ pub mod tests {
         check_dep_encode_decode(5u32, &[0, 0, 0, 5]);
         check_dep_encode_decode(5usize, &[0, 0, 0, 5]);
         check_dep_encode_decode(5u64, &[0, 0, 0, 0, 0, 0, 0, 5]);
+        // 128 bits
+        check_dep_encode_decode(0u128, &[0; 16]);
+        check_dep_encode_decode(5u128, &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5]);
+        check_dep_encode_decode(u128::MAX, &[0xff; 16]);
+    }
+
+    #[test]
+    fn test_top_u128_too_long() {
+        assert_eq!(
+            <u128 as crate::TopDecode>::top_decode(&[1u8; 17][..]),
+            Err(crate::DecodeError::INPUT_TOO_LONG)
+        );
     }
 }
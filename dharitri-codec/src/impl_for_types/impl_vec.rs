@@ -1,7 +1,7 @@
 use crate::{
-    boxed_slice_into_vec, DecodeError, DecodeErrorHandler, EncodeErrorHandler, NestedDecode,
-    NestedDecodeInput, NestedEncode, NestedEncodeOutput, TopDecode, TopDecodeInput, TopEncode,
-    TopEncodeOutput, TypeInfo,
+    boxed_slice_into_vec, decode_context, DecodeError, DecodeErrorHandler, EncodeErrorHandler,
+    NestedDecode, NestedDecodeInput, NestedEncode, NestedEncodeOutput, TopDecode, TopDecodeInput,
+    TopEncode, TopEncodeOutput, TypeInfo,
 };
 use alloc::vec::Vec;
 
@@ -29,15 +29,20 @@ impl<T: NestedDecode> TopDecode for Vec<T> {
             let cast_vec: Vec<T> = unsafe { core::mem::transmute(bytes_vec) };
             Ok(cast_vec)
         } else {
-            let mut result: Vec<T> = Vec::new();
             let mut nested_buffer = input.into_nested_buffer();
-            while !nested_buffer.is_depleted() {
-                result.push(T::dep_decode_or_handle_err(&mut nested_buffer, h)?);
-            }
-            if !nested_buffer.is_depleted() {
-                return Err(h.handle_error(DecodeError::INPUT_TOO_LONG));
-            }
-            Ok(result)
+            decode_context::value(&mut nested_buffer, |input| {
+                let mut result: Vec<T> = Vec::new();
+                while !input.is_depleted() {
+                    let item = decode_context::item(result.len(), input, |input| {
+                        T::dep_decode_or_handle_err(input, h)
+                    })?;
+                    result.push(item);
+                }
+                if !input.is_depleted() {
+                    return Err(h.handle_error(DecodeError::INPUT_TOO_LONG));
+                }
+                Ok(result)
+            })
         }
     }
 }
@@ -59,21 +64,23 @@ impl<T: NestedDecode> NestedDecode for Vec<T> {
         I: NestedDecodeInput,
         H: DecodeErrorHandler,
     {
-        let size = usize::dep_decode_or_handle_err(input, h)?;
-        match T::TYPE_INFO {
-            TypeInfo::U8 => {
-                let mut vec_u8: Vec<u8> = alloc::vec![0; size];
-                input.read_into(vec_u8.as_mut_slice(), h)?;
-                let cast_vec: Vec<T> = unsafe { core::mem::transmute(vec_u8) };
-                Ok(cast_vec)
-            },
-            _ => {
+        if let TypeInfo::U8 = T::TYPE_INFO {
+            let size = usize::dep_decode_or_handle_err(input, h)?;
+            let mut vec_u8: Vec<u8> = alloc::vec![0; size];
+            input.read_into(vec_u8.as_mut_slice(), h)?;
+            let cast_vec: Vec<T> = unsafe { core::mem::transmute(vec_u8) };
+            Ok(cast_vec)
+        } else {
+            decode_context::value(input, |input| {
+                let size = usize::dep_decode_or_handle_err(input, h)?;
                 let mut result: Vec<T> = Vec::with_capacity(size);
-                for _ in 0..size {
-                    result.push(T::dep_decode_or_handle_err(input, h)?);
+                for index in 0..size {
+                    result.push(decode_context::item(index, input, |input| {
+                        T::dep_decode_or_handle_err(input, h)
+                    })?);
                 }
                 Ok(result)
-            },
+            })
         }
     }
 }
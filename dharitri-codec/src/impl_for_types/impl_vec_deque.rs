@@ -0,0 +1,90 @@
+use crate::{
+    decode_context, DecodeErrorHandler, EncodeErrorHandler, NestedDecode, NestedDecodeInput,
+    NestedEncode, NestedEncodeOutput, TopDecode, TopDecodeInput, TopEncode, TopEncodeOutput,
+};
+use alloc::collections::VecDeque;
+
+/// Same encoding as `Vec<T>`.
+impl<T: NestedEncode> TopEncode for VecDeque<T> {
+    fn top_encode_or_handle_err<O, H>(&self, output: O, h: H) -> Result<(), H::HandledErr>
+    where
+        O: TopEncodeOutput,
+        H: EncodeErrorHandler,
+    {
+        let mut buffer = output.start_nested_encode();
+        for item in self.iter() {
+            item.dep_encode_or_handle_err(&mut buffer, h)?;
+        }
+        output.finalize_nested_encode(buffer);
+        Ok(())
+    }
+}
+
+impl<T: NestedDecode> TopDecode for VecDeque<T> {
+    fn top_decode_or_handle_err<I, H>(input: I, h: H) -> Result<Self, H::HandledErr>
+    where
+        I: TopDecodeInput,
+        H: DecodeErrorHandler,
+    {
+        let mut nested_buffer = input.into_nested_buffer();
+        decode_context::value(&mut nested_buffer, |input| {
+            let mut result = VecDeque::new();
+            while !input.is_depleted() {
+                let item = decode_context::item(result.len(), input, |input| {
+                    T::dep_decode_or_handle_err(input, h)
+                })?;
+                result.push_back(item);
+            }
+            Ok(result)
+        })
+    }
+}
+
+impl<T: NestedEncode> NestedEncode for VecDeque<T> {
+    fn dep_encode_or_handle_err<O, H>(&self, dest: &mut O, h: H) -> Result<(), H::HandledErr>
+    where
+        O: NestedEncodeOutput,
+        H: EncodeErrorHandler,
+    {
+        self.len().dep_encode_or_handle_err(dest, h)?;
+        for item in self.iter() {
+            item.dep_encode_or_handle_err(dest, h)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: NestedDecode> NestedDecode for VecDeque<T> {
+    fn dep_decode_or_handle_err<I, H>(input: &mut I, h: H) -> Result<Self, H::HandledErr>
+    where
+        I: NestedDecodeInput,
+        H: DecodeErrorHandler,
+    {
+        decode_context::value(input, |input| {
+            let size = usize::dep_decode_or_handle_err(input, h)?;
+            let mut result = VecDeque::with_capacity(size);
+            for index in 0..size {
+                result.push_back(decode_context::item(index, input, |input| {
+                    T::dep_decode_or_handle_err(input, h)
+                })?);
+            }
+            Ok(result)
+        })
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::test_util::{check_dep_encode_decode, check_top_encode_decode};
+    use alloc::collections::VecDeque;
+
+    #[test]
+    fn test_vec_deque() {
+        let mut deque = VecDeque::new();
+        deque.push_back(2u16);
+        deque.push_front(1u16);
+        check_top_encode_decode(deque.clone(), &[0, 1, 0, 2]);
+        check_dep_encode_decode(deque, &[0, 0, 0, 2, 0, 1, 0, 2]);
+        check_top_encode_decode(VecDeque::<u16>::new(), &[]);
+    }
+}
@@ -1,6 +1,8 @@
 mod impl_array;
 mod impl_array_vec;
 mod impl_bool;
+mod impl_btree_map;
+mod impl_btree_set;
 mod impl_bytes;
 pub mod impl_empty;
 mod impl_non_zero_usize;
@@ -13,6 +15,7 @@ mod impl_string;
 mod impl_tuple;
 mod impl_unit;
 mod impl_vec;
+mod impl_vec_deque;
 mod local_macro;
 
 #[cfg(feature = "num-bigint")]
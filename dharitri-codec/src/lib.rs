@@ -7,6 +7,9 @@
 
 extern crate alloc;
 
+#[cfg(feature = "decode-error-context")]
+extern crate std;
+
 #[cfg(feature = "dharitri-codec-derive")]
 pub use dharitri_codec_derive;
 
@@ -24,6 +27,7 @@ pub use num_bigint;
 
 mod codec_err;
 mod codec_err_handler;
+pub mod decode_context;
 mod default_traits;
 mod equivalent;
 mod impl_for_types;
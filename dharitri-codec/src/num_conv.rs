@@ -59,3 +59,46 @@ pub fn universal_decode_number(bytes: &[u8], signed: bool) -> u64 {
     }
     result
 }
+
+/// Same as `top_encode_number`, but for 128-bit numbers.
+///
+/// Kept separate, so contracts not using 128-bit numbers do not pay for the wider arithmetic.
+pub fn top_encode_number_128(x: u128, signed: bool, buffer: &mut [u8; 16]) -> &[u8] {
+    *buffer = x.to_be_bytes();
+    if x == 0 {
+        // 0 is a special case
+        return &[];
+    }
+
+    let negative = signed &&  // only possible when signed flag
+		buffer[0] > 0x7fu8; // most significant bit is 1
+
+    let irrelevant_byte = if negative { 0xffu8 } else { 0x00u8 };
+
+    // -1 stops at the last byte, returning a single 0xFF byte
+    let mut offset = 0usize;
+    while offset < 15 && buffer[offset] == irrelevant_byte {
+        offset += 1;
+    }
+
+    if signed && buffer[offset] >> 7 != negative as u8 {
+        debug_assert!(offset > 0);
+        offset -= 1;
+    }
+
+    &buffer[offset..]
+}
+
+/// Same as `universal_decode_number`, but for 128-bit numbers.
+pub fn universal_decode_number_128(bytes: &[u8], signed: bool) -> u128 {
+    if bytes.is_empty() {
+        return 0;
+    }
+    let negative = signed && bytes[0] >> 7 == 1;
+    let mut result = if negative { u128::MAX } else { 0u128 };
+    for byte in bytes.iter() {
+        result <<= 8;
+        result |= *byte as u128;
+    }
+    result
+}
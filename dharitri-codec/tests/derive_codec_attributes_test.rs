@@ -0,0 +1,181 @@
+extern crate dharitri_codec_derive;
+use dharitri_codec_derive::*;
+
+use dharitri_codec::test_util::{
+    check_dep_decode, check_dep_encode, check_dep_encode_decode, check_top_decode,
+    check_top_encode, check_top_encode_decode,
+};
+
+// to test, run the following command in dharitri-codec folder:
+// cargo expand --test derive_codec_attributes_test > expanded.rs
+
+/// Encodes a u32 on a single byte.
+mod compact_u32 {
+    use dharitri_codec::{
+        DecodeErrorHandler, EncodeErrorHandler, NestedDecode, NestedDecodeInput, NestedEncode,
+        NestedEncodeOutput,
+    };
+
+    pub fn dep_encode_or_handle_err<O, H>(
+        value: &u32,
+        dest: &mut O,
+        h: H,
+    ) -> Result<(), H::HandledErr>
+    where
+        O: NestedEncodeOutput,
+        H: EncodeErrorHandler,
+    {
+        (*value as u8).dep_encode_or_handle_err(dest, h)
+    }
+
+    pub fn dep_decode_or_handle_err<I, H>(input: &mut I, h: H) -> Result<u32, H::HandledErr>
+    where
+        I: NestedDecodeInput,
+        H: DecodeErrorHandler,
+    {
+        Ok(u8::dep_decode_or_handle_err(input, h)? as u32)
+    }
+}
+
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Clone, Debug)]
+pub struct StructWithSkip {
+    pub int: u16,
+    #[codec(skip)]
+    pub cache: u64,
+    #[codec(skip, default = 5)]
+    pub five: u8,
+    pub another_byte: u8,
+}
+
+#[test]
+fn struct_skip_test() {
+    let s = StructWithSkip {
+        int: 0x42,
+        cache: 0x1234,
+        five: 1,
+        another_byte: 0x6,
+    };
+    assert_eq!(check_top_encode(&s), [0, 0x42, 6].to_vec());
+    assert_eq!(check_dep_encode(&s), [0, 0x42, 6].to_vec());
+
+    let decoded = StructWithSkip {
+        int: 0x42,
+        cache: 0,
+        five: 5,
+        another_byte: 0x6,
+    };
+    check_top_encode_decode(decoded.clone(), &[0, 0x42, 6]);
+    check_dep_encode_decode(decoded, &[0, 0x42, 6]);
+}
+
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Clone, Debug)]
+pub struct StructWithDefaults {
+    pub int: u16,
+    #[codec(default = 7)]
+    pub added_later: u32,
+    #[codec(default)]
+    pub added_even_later: Vec<u8>,
+}
+
+#[test]
+fn struct_default_test() {
+    let s = StructWithDefaults {
+        int: 0x42,
+        added_later: 3,
+        added_even_later: vec![1, 2],
+    };
+    #[rustfmt::skip]
+    let bytes = &[
+        /* int */ 0, 0x42,
+        /* added_later */ 0, 0, 0, 3,
+        /* added_even_later */ 0, 0, 0, 2, 1, 2,
+    ];
+    check_top_encode_decode(s.clone(), bytes);
+    check_dep_encode_decode(s, bytes);
+
+    // older data, without the fields added later
+    let old = StructWithDefaults {
+        int: 0x42,
+        added_later: 7,
+        added_even_later: Vec::new(),
+    };
+    assert_eq!(old, check_top_decode(&[0, 0x42]));
+    assert_eq!(old, check_dep_decode(&[0, 0x42]));
+
+    let partial = StructWithDefaults {
+        int: 0x42,
+        added_later: 3,
+        added_even_later: Vec::new(),
+    };
+    assert_eq!(partial, check_top_decode(&[0, 0x42, 0, 0, 0, 3]));
+}
+
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Clone, Debug)]
+pub struct StructWithCustomField {
+    #[codec(with = compact_u32)]
+    pub compact: u32,
+    pub regular: u32,
+}
+
+#[test]
+fn struct_with_module_test() {
+    let s = StructWithCustomField {
+        compact: 5,
+        regular: 5,
+    };
+    check_top_encode_decode(s.clone(), &[5, 0, 0, 0, 5]);
+    check_dep_encode_decode(s, &[5, 0, 0, 0, 5]);
+}
+
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Clone, Debug)]
+enum FieldlessEnumWithDiscriminants {
+    #[codec(discriminant = 1)]
+    One,
+    Two,
+    #[codec(discriminant = 10)]
+    Ten,
+    #[codec(discriminant = 0)]
+    Zero,
+}
+
+#[test]
+fn fieldless_enum_discriminant_test() {
+    check_top_encode_decode(FieldlessEnumWithDiscriminants::Zero, &[]);
+    check_top_encode_decode(FieldlessEnumWithDiscriminants::One, &[1]);
+    check_top_encode_decode(FieldlessEnumWithDiscriminants::Two, &[2]);
+    check_top_encode_decode(FieldlessEnumWithDiscriminants::Ten, &[10]);
+
+    check_dep_encode_decode(FieldlessEnumWithDiscriminants::Zero, &[0]);
+    check_dep_encode_decode(FieldlessEnumWithDiscriminants::One, &[1]);
+    check_dep_encode_decode(FieldlessEnumWithDiscriminants::Two, &[2]);
+    check_dep_encode_decode(FieldlessEnumWithDiscriminants::Ten, &[10]);
+}
+
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Clone, Debug)]
+enum EnumWithAttributes {
+    #[codec(discriminant = 3)]
+    Compact(#[codec(with = compact_u32)] u32),
+    Skipped {
+        int: u16,
+        #[codec(skip)]
+        cache: u64,
+    },
+    #[codec(discriminant = 0)]
+    Nothing,
+}
+
+#[test]
+fn field_enum_attributes_test() {
+    check_top_encode_decode(EnumWithAttributes::Nothing, &[]);
+    check_dep_encode_decode(EnumWithAttributes::Nothing, &[0]);
+
+    check_top_encode_decode(EnumWithAttributes::Compact(5), &[3, 5]);
+    check_dep_encode_decode(EnumWithAttributes::Compact(5), &[3, 5]);
+
+    let skipped = EnumWithAttributes::Skipped {
+        int: 0x42,
+        cache: 0,
+    };
+    check_top_encode_decode(skipped.clone(), &[4, 0, 0x42]);
+    check_dep_encode_decode(skipped, &[4, 0, 0x42]);
+}
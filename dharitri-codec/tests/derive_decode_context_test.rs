@@ -0,0 +1,113 @@
+#![cfg(feature = "decode-error-context")]
+
+extern crate dharitri_codec_derive;
+use dharitri_codec_derive::*;
+
+use dharitri_codec::{
+    decode_context::{take_last_error_context, DecodeErrorContext},
+    test_util::check_top_encode,
+    DecodeError, NestedDecode, TopDecode,
+};
+
+// to test, run the following command in dharitri-codec folder:
+// cargo test --features decode-error-context --test derive_decode_context_test
+
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Clone, Debug)]
+pub struct Item {
+    pub id: u16,
+    pub price: u64,
+}
+
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Clone, Debug)]
+pub struct Order {
+    pub buyer: Vec<u8>,
+    pub items: Vec<Item>,
+}
+
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Clone, Debug)]
+pub enum Payment {
+    None,
+    Single(u32, Order),
+}
+
+fn sample_order() -> Order {
+    Order {
+        buyer: b"alice".to_vec(),
+        items: vec![
+            Item { id: 1, price: 100 },
+            Item { id: 2, price: 200 },
+            Item { id: 3, price: 300 },
+        ],
+    }
+}
+
+#[test]
+fn decode_context_struct_test() {
+    let bytes = check_top_encode(&sample_order());
+
+    // cut in the middle of the price of the last item
+    let truncated = &bytes[..bytes.len() - 3];
+    assert_eq!(
+        Order::top_decode(truncated),
+        Err(DecodeError::INPUT_TOO_SHORT)
+    );
+    let context = take_last_error_context().unwrap();
+    assert_eq!(
+        context,
+        DecodeErrorContext {
+            path: "Order.items[2].price".to_string(),
+            // buyer: 4 + 5 bytes, items length: 4 bytes, 2 items of 10 bytes, item id: 2 bytes
+            byte_offset: 35,
+        }
+    );
+    assert_eq!(
+        context.describe(&DecodeError::INPUT_TOO_SHORT),
+        "Order.items[2].price: input too short at byte 35"
+    );
+
+    // only reported once
+    assert_eq!(take_last_error_context(), None);
+}
+
+#[test]
+fn decode_context_enum_test() {
+    let payment = Payment::Single(5, sample_order());
+    let bytes = check_top_encode(&payment);
+    let truncated = &bytes[..10];
+    assert_eq!(
+        Payment::dep_decode(&mut &truncated[..]),
+        Err(DecodeError::INPUT_TOO_SHORT)
+    );
+    assert_eq!(
+        take_last_error_context(),
+        Some(DecodeErrorContext {
+            path: "Payment::Single.1.buyer".to_string(),
+            byte_offset: 5,
+        })
+    );
+}
+
+#[test]
+fn decode_context_top_level_vec_test() {
+    let items = vec![Item { id: 1, price: 100 }, Item { id: 2, price: 200 }];
+    let bytes = check_top_encode(&items);
+    assert_eq!(
+        Vec::<Item>::top_decode(&bytes[..bytes.len() - 1]),
+        Err(DecodeError::INPUT_TOO_SHORT)
+    );
+    assert_eq!(
+        take_last_error_context(),
+        Some(DecodeErrorContext {
+            path: "[1].price".to_string(),
+            byte_offset: 12,
+        })
+    );
+}
+
+#[test]
+fn decode_context_cleared_on_success_test() {
+    let bytes = check_top_encode(&sample_order());
+    assert!(Order::top_decode(&bytes[..5]).is_err());
+    assert_eq!(Order::top_decode(bytes.as_slice()), Ok(sample_order()));
+    assert_eq!(take_last_error_context(), None);
+}
@@ -0,0 +1,185 @@
+extern crate dharitri_codec_derive;
+use dharitri_codec_derive::*;
+
+use dharitri_codec::{
+    test_util::{check_dep_decode, check_top_decode, check_top_encode, check_top_encode_decode},
+    DecodeError, TopDecode,
+};
+
+// to test, run the following command in dharitri-codec folder:
+// cargo expand --test derive_struct_versioned_test > expanded.rs
+
+/// The layout of the struct before the upgrade.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Clone, Debug)]
+pub struct StoredStructV1 {
+    pub int: u16,
+    pub seq: Vec<u8>,
+}
+
+/// The layout of the struct after the upgrade, with fields appended.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Clone, Debug)]
+#[codec(versioned)]
+pub struct StoredStructV2 {
+    pub int: u16,
+    pub seq: Vec<u8>,
+    pub added_later: u32,
+    #[codec(default = 100)]
+    pub added_later_with_default: u64,
+}
+
+#[test]
+fn versioned_struct_test() {
+    let v2 = StoredStructV2 {
+        int: 0x42,
+        seq: vec![1, 2],
+        added_later: 3,
+        added_later_with_default: 4,
+    };
+    #[rustfmt::skip]
+    let bytes = &[
+        /* int */ 0, 0x42,
+        /* seq */ 0, 0, 0, 2, 1, 2,
+        /* added_later */ 0, 0, 0, 3,
+        /* added_later_with_default */ 0, 0, 0, 0, 0, 0, 0, 4,
+    ];
+    check_top_encode_decode(v2, bytes);
+
+    let v1 = StoredStructV1 {
+        int: 0x42,
+        seq: vec![1, 2],
+    };
+    let v1_bytes = check_top_encode(&v1);
+    let upgraded = StoredStructV2 {
+        int: 0x42,
+        seq: vec![1, 2],
+        added_later: 0,
+        added_later_with_default: 100,
+    };
+    assert_eq!(upgraded, check_top_decode(&v1_bytes));
+    assert_eq!(upgraded, check_dep_decode(&v1_bytes));
+
+    // empty storage
+    let empty = StoredStructV2 {
+        int: 0,
+        seq: Vec::new(),
+        added_later: 0,
+        added_later_with_default: 100,
+    };
+    assert_eq!(empty, check_top_decode(&[]));
+}
+
+#[test]
+fn versioned_struct_nested_test() {
+    let v1 = StoredStructV1 {
+        int: 0x42,
+        seq: vec![1, 2],
+    };
+    let upgraded = StoredStructV2 {
+        int: 0x42,
+        seq: vec![1, 2],
+        added_later: 0,
+        added_later_with_default: 100,
+    };
+
+    // the defaults are applied when the struct is the last nested value
+    let tuple_bytes = check_top_encode(&(5u8, v1.clone()));
+    assert_eq!((5u8, upgraded), check_top_decode(&tuple_bytes));
+
+    // but not when other values follow it: the missing fields are read from the next value
+    let vec_bytes = check_top_encode(&vec![v1.clone(), v1]);
+    assert_eq!(
+        Vec::<StoredStructV2>::top_decode(&vec_bytes[..]),
+        Err(DecodeError::INPUT_TOO_SHORT)
+    );
+}
+
+fn migrate_from_v1(v1: StoredStructV1) -> TaggedStruct {
+    TaggedStruct {
+        int: v1.int as u32,
+        seq: v1.seq,
+        flag: true,
+    }
+}
+
+/// Version 2 had the same layout, but a different unit for `int`.
+fn migrate_from_v2(v2: StoredStructV1) -> TaggedStruct {
+    TaggedStruct {
+        int: v2.int as u32 * 10,
+        seq: v2.seq,
+        flag: false,
+    }
+}
+
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Clone, Debug)]
+#[codec(version = 3, migrate(1 = migrate_from_v1, 2 = migrate_from_v2))]
+pub struct TaggedStruct {
+    pub int: u32,
+    pub seq: Vec<u8>,
+    pub flag: bool,
+}
+
+#[test]
+fn version_tag_test() {
+    let current = TaggedStruct {
+        int: 5,
+        seq: vec![7],
+        flag: true,
+    };
+    #[rustfmt::skip]
+    let bytes = &[
+        /* version */ 3,
+        /* int */ 0, 0, 0, 5,
+        /* seq */ 0, 0, 0, 1, 7,
+        /* flag */ 1,
+    ];
+    check_top_encode_decode(current, bytes);
+
+    // data tagged with older versions goes through the migration functions
+    #[rustfmt::skip]
+    let v1_bytes = &[
+        /* version */ 1,
+        /* int */ 0, 5,
+        /* seq */ 0, 0, 0, 1, 7,
+    ];
+    assert_eq!(
+        TaggedStruct {
+            int: 5,
+            seq: vec![7],
+            flag: true,
+        },
+        check_top_decode(v1_bytes)
+    );
+    assert_eq!(
+        TaggedStruct {
+            int: 5,
+            seq: vec![7],
+            flag: true,
+        },
+        check_dep_decode(v1_bytes)
+    );
+
+    #[rustfmt::skip]
+    let v2_bytes = &[
+        /* version */ 2,
+        /* int */ 0, 5,
+        /* seq */ 0, 0, 0, 1, 7,
+    ];
+    assert_eq!(
+        TaggedStruct {
+            int: 50,
+            seq: vec![7],
+            flag: false,
+        },
+        check_top_decode(v2_bytes)
+    );
+
+    // unknown versions
+    assert_eq!(
+        TaggedStruct::top_decode(&[4, 0, 0, 0, 5, 0, 0, 0, 0, 0][..]),
+        Err(DecodeError::UNSUPPORTED_VERSION)
+    );
+    assert_eq!(
+        TaggedStruct::top_decode(&[0, 0, 0, 0, 5, 0, 0, 0, 0, 0][..]),
+        Err(DecodeError::UNSUPPORTED_VERSION)
+    );
+}
@@ -10,6 +10,9 @@ pub struct TypeDescriptionJson {
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub docs: Vec<String>,
 
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<u8>,
+
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub variants: Vec<EnumVariantDescriptionJson>,
 
@@ -27,6 +30,7 @@ impl From<&TypeDescription> for TypeDescriptionJson {
         let mut type_desc_json = TypeDescriptionJson {
             content_type: content_type.to_string(),
             docs: abi.docs.iter().map(|line| line.to_string()).collect(),
+            version: abi.version,
             variants: Vec::new(),
             fields: Vec::new(),
         };
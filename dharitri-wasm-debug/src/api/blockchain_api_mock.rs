@@ -64,7 +64,8 @@ impl BlockchainApiImpl for DebugApi {
     }
 
     fn get_gas_left(&self) -> u64 {
-        self.input_ref().gas_limit
+        self.use_gas(|gas_schedule| gas_schedule.dharitri_api_cost.get_gas_left);
+        self.gas_left()
     }
 
     fn get_block_timestamp(&self) -> u64 {
@@ -1,5 +1,5 @@
+use super::crypto_bls_mock;
 use crate::DebugApi;
-use ed25519_dalek::*;
 use dharitri_wasm::{
     api::{
         CryptoApi, CryptoApiImpl, Handle, ManagedBufferApi, KECCAK256_RESULT_LEN,
@@ -7,9 +7,13 @@ use dharitri_wasm::{
     },
     types::{heap::BoxedBytes, MessageHashType},
 };
+use ed25519_dalek::*;
+use ripemd160::Ripemd160;
 use sha2::Sha256;
 use sha3::{Digest, Keccak256};
 
+const SECP256K1_MESSAGE_LEN: usize = 32;
+
 impl CryptoApi for DebugApi {
     type CryptoApiImpl = DebugApi;
 
@@ -45,12 +49,14 @@ impl CryptoApiImpl for DebugApi {
         self.mb_overwrite(dest, &result_bytes[..]);
     }
 
-    fn ripemd160(&self, _data: &[u8]) -> [u8; RIPEMD_RESULT_LEN] {
-        panic!("ripemd160 not implemented yet!")
+    fn ripemd160(&self, data: &[u8]) -> [u8; RIPEMD_RESULT_LEN] {
+        let mut hasher = Ripemd160::new();
+        hasher.update(data);
+        hasher.finalize().into()
     }
 
-    fn verify_bls(&self, _key: &[u8], _message: &[u8], _signature: &[u8]) -> bool {
-        panic!("verify_bls not implemented yet!")
+    fn verify_bls(&self, key: &[u8], message: &[u8], signature: &[u8]) -> bool {
+        crypto_bls_mock::verify_bls(key, message, signature)
     }
 
     fn verify_ed25519(&self, key: &[u8], message: &[u8], signature: &[u8]) -> bool {
@@ -67,21 +73,81 @@ impl CryptoApiImpl for DebugApi {
         public.unwrap().verify(message, &sig.unwrap()).is_ok()
     }
 
-    fn verify_secp256k1(&self, _key: &[u8], _message: &[u8], _signature: &[u8]) -> bool {
-        panic!("verify_secp256k1 not implemented yet!")
+    fn verify_secp256k1(&self, key: &[u8], message: &[u8], signature: &[u8]) -> bool {
+        self.verify_custom_secp256k1(key, message, signature, MessageHashType::ECDSADoubleSha256)
     }
 
     fn verify_custom_secp256k1(
         &self,
-        _key: &[u8],
-        _message: &[u8],
-        _signature: &[u8],
-        _hash_type: MessageHashType,
+        key: &[u8],
+        message: &[u8],
+        signature: &[u8],
+        hash_type: MessageHashType,
     ) -> bool {
-        panic!("verify_custom_secp256k1 not implemented yet!")
+        let public = match libsecp256k1::PublicKey::parse_slice(key, None) {
+            Ok(public) => public,
+            Err(_) => return false,
+        };
+
+        let sig = match libsecp256k1::Signature::parse_der(signature) {
+            Ok(sig) => sig,
+            Err(_) => return false,
+        };
+
+        let message_hash = secp256k1_message_hash(message, hash_type);
+        libsecp256k1::verify(&libsecp256k1::Message::parse(&message_hash), &sig, &public)
+    }
+
+    fn encode_secp256k1_der_signature(&self, r: &[u8], s: &[u8]) -> BoxedBytes {
+        let r = der_encode_integer(r);
+        let s = der_encode_integer(s);
+        let mut result = Vec::with_capacity(2 + r.len() + s.len());
+        result.push(0x30);
+        result.push((r.len() + s.len()) as u8);
+        result.extend_from_slice(&r);
+        result.extend_from_slice(&s);
+        BoxedBytes::from(result)
+    }
+}
+
+/// Hashes the message as the VM does before verifying a secp256k1 signature.
+/// Shorter digests, as well as plain messages, are padded with zeros (or truncated) to 32 bytes.
+fn secp256k1_message_hash(
+    message: &[u8],
+    hash_type: MessageHashType,
+) -> [u8; SECP256K1_MESSAGE_LEN] {
+    let digest = match hash_type {
+        MessageHashType::ECDSAPlainMsg => message.to_vec(),
+        MessageHashType::ECDSASha256 => Sha256::digest(message).to_vec(),
+        MessageHashType::ECDSADoubleSha256 => Sha256::digest(&Sha256::digest(message)).to_vec(),
+        MessageHashType::ECDSAKeccak256 => Keccak256::digest(message).to_vec(),
+        MessageHashType::ECDSARipemd160 => Ripemd160::digest(message).to_vec(),
+    };
+
+    let mut result = [0u8; SECP256K1_MESSAGE_LEN];
+    let len = digest.len().min(SECP256K1_MESSAGE_LEN);
+    result[..len].copy_from_slice(&digest[..len]);
+    result
+}
+
+/// DER INTEGER: big endian, minimal length, with a leading zero if the top bit is set.
+fn der_encode_integer(value: &[u8]) -> Vec<u8> {
+    let first_non_zero = value
+        .iter()
+        .position(|byte| *byte != 0)
+        .unwrap_or(value.len());
+    let mut significant = &value[first_non_zero..];
+    if significant.is_empty() {
+        significant = &[0];
     }
 
-    fn encode_secp256k1_der_signature(&self, _r: &[u8], _s: &[u8]) -> BoxedBytes {
-        panic!("encode_secp256k1_signature not implemented yet!")
+    let mut result = vec![0x02];
+    if significant[0] & 0x80 != 0 {
+        result.push((significant.len() + 1) as u8);
+        result.push(0);
+    } else {
+        result.push(significant.len() as u8);
     }
+    result.extend_from_slice(significant);
+    result
 }
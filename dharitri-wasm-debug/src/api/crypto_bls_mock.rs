@@ -0,0 +1,189 @@
+//! BLS signature verification, compatible with the one performed by the VM.
+//!
+//! The VM relies on the herumi BLS12-381 implementation, with signatures in G1 and public keys in G2.
+//! Points are serialized as their x coordinate in little endian, with the top bit flagging an odd y.
+//! Messages are hashed with SHA-512 and mapped to G1 using the Shallue-van de Woestijne method.
+
+use crate::num_bigint::BigUint;
+use bls12_381::{pairing, G1Affine, G1Projective, G2Affine, Scalar};
+use num_traits::{One, Zero};
+use sha2::{Digest, Sha512};
+use std::convert::TryInto;
+
+const FP_LEN: usize = 48;
+
+const FIELD_MODULUS_HEX: &[u8] = b"1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab";
+
+/// The G1 cofactor, `(z - 1)^2 / 3`.
+const G1_COFACTOR: [u64; 4] = [0x8c00_aaab_0000_aaab, 0x396c_8c00_5555_e156, 0, 0];
+
+/// The G2 point that public keys are derived from, as `x.c0, x.c1, y.c0, y.c1`, in decimal.
+const PUBLIC_KEY_GENERATOR: [&[u8]; 4] = [
+    b"2345388737500083945391657505708625859903954047151773287623537600586029428359739211026111121073980842558223033704140",
+    b"3558041178357727243543283929018475959655787667816024413880422701270944718005964809191925861299660390662341819212979",
+    b"1111454484298065649047920916747797835589661734985194316226909186591481448224600088430816898704234962594609579273169",
+    b"3988173108836042169913782128392219399166696378042311135661652175544044220584995583525611110036064603671142074680982",
+];
+
+/// Checks that `e(signature, Q) == e(H(message), key)`.
+/// Malformed keys and signatures, as well as points outside the prime order subgroups, yield `false`.
+pub(super) fn verify_bls(key: &[u8], message: &[u8], signature: &[u8]) -> bool {
+    let (public_key, signature) = match (g2_from_bytes(key), g1_from_bytes(signature)) {
+        (Some(public_key), Some(signature)) => (public_key, signature),
+        _ => return false,
+    };
+    let message_point = match hash_to_g1(message) {
+        Some(message_point) => message_point,
+        None => return false,
+    };
+
+    pairing(&signature, &public_key_generator()) == pairing(&message_point, &public_key)
+}
+
+fn field_modulus() -> BigUint {
+    BigUint::parse_bytes(FIELD_MODULUS_HEX, 16).unwrap()
+}
+
+fn fp_neg(a: &BigUint, p: &BigUint) -> BigUint {
+    (p - a % p) % p
+}
+
+fn fp_inv(a: &BigUint, p: &BigUint) -> BigUint {
+    a.modpow(&(p - 2u32), p)
+}
+
+/// The field modulus is 3 mod 4, so the candidate root is `a^((p + 1) / 4)`.
+fn fp_sqrt(a: &BigUint, p: &BigUint) -> Option<BigUint> {
+    let root = a.modpow(&((p + 1u32) / 4u32), p);
+    if &root * &root % p == a % p {
+        Some(root)
+    } else {
+        None
+    }
+}
+
+fn fp_is_quadratic_non_residue(a: &BigUint, p: &BigUint) -> bool {
+    a.modpow(&((p - 1u32) / 2u32), p) == p - 1u32
+}
+
+fn fp_to_be_bytes(a: &BigUint) -> [u8; FP_LEN] {
+    let bytes = a.to_bytes_be();
+    let mut result = [0u8; FP_LEN];
+    result[FP_LEN - bytes.len()..].copy_from_slice(&bytes);
+    result
+}
+
+/// Converts a herumi serialized coordinate to the big endian compressed format expected by `bls12_381`.
+/// Also returns whether the y coordinate is odd.
+fn herumi_to_compressed(bytes: &[u8]) -> Option<(Vec<u8>, bool)> {
+    if bytes.iter().all(|byte| *byte == 0) {
+        return None;
+    }
+
+    let mut compressed = bytes.to_vec();
+    compressed.reverse();
+    let y_is_odd = compressed[0] & 0x80 != 0;
+    if compressed[0] & 0x60 != 0 {
+        return None;
+    }
+    compressed[0] |= 0x80;
+    Some((compressed, y_is_odd))
+}
+
+fn g1_from_bytes(bytes: &[u8]) -> Option<G1Affine> {
+    if bytes.len() != FP_LEN {
+        return None;
+    }
+    let (compressed, y_is_odd) = herumi_to_compressed(bytes)?;
+    let point = Option::<G1Affine>::from(G1Affine::from_compressed(
+        &compressed.as_slice().try_into().unwrap(),
+    ))?;
+
+    let y = BigUint::from_bytes_be(&point.to_uncompressed()[FP_LEN..]);
+    if y.bit(0) == y_is_odd {
+        Some(point)
+    } else {
+        Some(-point)
+    }
+}
+
+/// The parity of an Fp2 element is the parity of its real part.
+fn g2_from_bytes(bytes: &[u8]) -> Option<G2Affine> {
+    if bytes.len() != 2 * FP_LEN {
+        return None;
+    }
+    let (compressed, y_is_odd) = herumi_to_compressed(bytes)?;
+    let point = Option::<G2Affine>::from(G2Affine::from_compressed(
+        &compressed.as_slice().try_into().unwrap(),
+    ))?;
+
+    // uncompressed layout: x.c1 | x.c0 | y.c1 | y.c0
+    let y_c0 = BigUint::from_bytes_be(&point.to_uncompressed()[3 * FP_LEN..]);
+    if y_c0.bit(0) == y_is_odd {
+        Some(point)
+    } else {
+        Some(-point)
+    }
+}
+
+fn public_key_generator() -> G2Affine {
+    let coordinates: Vec<[u8; FP_LEN]> = PUBLIC_KEY_GENERATOR
+        .iter()
+        .map(|decimal| fp_to_be_bytes(&BigUint::parse_bytes(decimal, 10).unwrap()))
+        .collect();
+    let mut uncompressed = [0u8; 4 * FP_LEN];
+    for (i, coordinate_index) in [1, 0, 3, 2].iter().enumerate() {
+        uncompressed[i * FP_LEN..(i + 1) * FP_LEN].copy_from_slice(&coordinates[*coordinate_index]);
+    }
+    G2Affine::from_uncompressed(&uncompressed).unwrap()
+}
+
+/// The SHA-512 digest is read in little endian and masked to 380 bits, so that it fits in the field.
+fn hash_to_fp(message: &[u8]) -> BigUint {
+    let digest = Sha512::digest(message);
+    let value = BigUint::from_bytes_le(&digest[..FP_LEN]);
+    let mask = (BigUint::one() << (8 * FP_LEN - 4)) - 1u32;
+    value & mask
+}
+
+fn hash_to_g1(message: &[u8]) -> Option<G1Affine> {
+    let point = map_to_g1_curve(&hash_to_fp(message))?;
+    Some(G1Affine::from(
+        G1Projective::from(point) * Scalar::from_raw(G1_COFACTOR),
+    ))
+}
+
+/// Shallue-van de Woestijne map to the curve `y^2 = x^3 + 4`, not yet in the prime order subgroup.
+fn map_to_g1_curve(t: &BigUint) -> Option<G1Affine> {
+    const B: u32 = 4;
+    let p = field_modulus();
+    if t.is_zero() {
+        return None;
+    }
+
+    let c1 = fp_sqrt(&fp_neg(&BigUint::from(3u32), &p), &p)?;
+    let c2 = (&c1 + &p - 1u32) * fp_inv(&BigUint::from(2u32), &p) % &p;
+
+    let w = (t * t + B + 1u32) % &p;
+    if w.is_zero() {
+        return None;
+    }
+    let w = fp_inv(&w, &p) * &c1 % &p * t % &p;
+
+    let x1 = (fp_neg(&(t * &w % &p), &p) + &c2) % &p;
+    let x2 = (fp_neg(&x1, &p) + &p - 1u32) % &p;
+    let x3 = (fp_inv(&(&w * &w % &p), &p) + 1u32) % &p;
+    for x in [x1, x2, x3].iter() {
+        let y_squared = (x * x % &p * x + B) % &p;
+        if let Some(mut y) = fp_sqrt(&y_squared, &p) {
+            if fp_is_quadratic_non_residue(t, &p) {
+                y = fp_neg(&y, &p);
+            }
+            let mut uncompressed = [0u8; 2 * FP_LEN];
+            uncompressed[..FP_LEN].copy_from_slice(&fp_to_be_bytes(x));
+            uncompressed[FP_LEN..].copy_from_slice(&fp_to_be_bytes(&y));
+            return Option::from(G1Affine::from_uncompressed_unchecked(&uncompressed));
+        }
+    }
+    None
+}
@@ -1,5 +1,8 @@
 use crate::{tx_mock::TxPanic, DebugApi};
-use dharitri_wasm::api::{ErrorApi, ErrorApiImpl, Handle, ManagedBufferApi};
+use dharitri_wasm::{
+    api::{ErrorApi, ErrorApiImpl, Handle, ManagedBufferApi},
+    dharitri_codec::decode_context,
+};
 
 impl ErrorApi for DebugApi {
     type ErrorApiImpl = DebugApi;
@@ -13,7 +16,15 @@ impl ErrorApiImpl for DebugApi {
     fn signal_error(&self, message: &[u8]) -> ! {
         // can sometimes help in tests
         // run `clear & cargo test -- --nocapture` to see the output
-        println!("{}", std::str::from_utf8(message).unwrap());
+        if let Some(context) = decode_context::current_error_context() {
+            println!("{} (at {})", std::str::from_utf8(message).unwrap(), context);
+        } else {
+            println!("{}", std::str::from_utf8(message).unwrap());
+        }
+
+        // context left over from an earlier failed decode must not get attached to this error,
+        // the current one, if any, gets recorded while unwinding the decode
+        decode_context::take_last_error_context();
 
         std::panic::panic_any(TxPanic {
             status: 4,
@@ -20,8 +20,9 @@ fn assert_positive(bi: &num_bigint::BigInt) {
 }
 
 macro_rules! binary_op_method {
-    ($method_name:ident, $rust_op_name:ident) => {
+    ($method_name:ident, $rust_op_name:ident, $gas_cost:ident) => {
         fn $method_name(&self, dest: Handle, x: Handle, y: Handle) {
+            self.use_gas(|gas_schedule| gas_schedule.big_int_api_cost.$gas_cost);
             let mut managed_types = self.m_types_borrow_mut();
             let bi_x = managed_types.big_int_map.get(x);
             let bi_y = managed_types.big_int_map.get(y);
@@ -32,8 +33,9 @@ macro_rules! binary_op_method {
 }
 
 macro_rules! binary_bitwise_op_method {
-    ($method_name:ident, $rust_op_name:ident) => {
+    ($method_name:ident, $rust_op_name:ident, $gas_cost:ident) => {
         fn $method_name(&self, dest: Handle, x: Handle, y: Handle) {
+            self.use_gas(|gas_schedule| gas_schedule.big_int_api_cost.$gas_cost);
             let mut managed_types = self.m_types_borrow_mut();
             let bi_x = managed_types.big_int_map.get(x);
             assert_positive(&bi_x);
@@ -46,8 +48,9 @@ macro_rules! binary_bitwise_op_method {
 }
 
 macro_rules! unary_op_method {
-    ($method_name:ident, $rust_op_name:ident) => {
+    ($method_name:ident, $rust_op_name:ident, $gas_cost:ident) => {
         fn $method_name(&self, dest: Handle, x: Handle) {
+            self.use_gas(|gas_schedule| gas_schedule.big_int_api_cost.$gas_cost);
             let mut managed_types = self.m_types_borrow_mut();
             let bi_x = managed_types.big_int_map.get(x);
             let result = bi_x.$rust_op_name();
@@ -66,6 +69,7 @@ impl DebugApi {
 impl BigIntApi for DebugApi {
     #[allow(dead_code)]
     fn bi_new(&self, value: i64) -> Handle {
+        self.use_gas(|gas_schedule| gas_schedule.big_int_api_cost.big_int_new);
         let mut managed_types = self.m_types_borrow_mut();
         managed_types
             .big_int_map
@@ -73,6 +77,7 @@ impl BigIntApi for DebugApi {
     }
 
     fn bi_set_int64(&self, destination: Handle, value: i64) {
+        self.use_gas(|gas_schedule| gas_schedule.big_int_api_cost.big_int_new);
         self.bi_overwrite(destination, num_bigint::BigInt::from(value))
     }
 
@@ -81,6 +86,7 @@ impl BigIntApi for DebugApi {
     }
 
     fn bi_get_unsigned_bytes(&self, handle: Handle) -> BoxedBytes {
+        self.use_gas(|gas_schedule| gas_schedule.big_int_api_cost.big_int_get_bytes);
         let managed_types = self.m_types_borrow();
         let bi = managed_types.big_int_map.get(handle);
         if bi.is_zero() {
@@ -92,6 +98,7 @@ impl BigIntApi for DebugApi {
     }
 
     fn bi_set_unsigned_bytes(&self, dest: Handle, bytes: &[u8]) {
+        self.use_gas(|gas_schedule| gas_schedule.big_int_api_cost.big_int_set_bytes);
         let mut managed_types = self.m_types_borrow_mut();
         let result = num_bigint::BigInt::from_bytes_be(num_bigint::Sign::Plus, bytes);
         managed_types.big_int_map.insert(dest, result);
@@ -102,6 +109,7 @@ impl BigIntApi for DebugApi {
     }
 
     fn bi_get_signed_bytes(&self, handle: Handle) -> BoxedBytes {
+        self.use_gas(|gas_schedule| gas_schedule.big_int_api_cost.big_int_get_bytes);
         let managed_types = self.m_types_borrow();
         let bi = managed_types.big_int_map.get(handle);
         if bi.is_zero() {
@@ -112,6 +120,7 @@ impl BigIntApi for DebugApi {
     }
 
     fn bi_set_signed_bytes(&self, dest: Handle, bytes: &[u8]) {
+        self.use_gas(|gas_schedule| gas_schedule.big_int_api_cost.big_int_set_bytes);
         let mut managed_types = self.m_types_borrow_mut();
         let result = num_bigint::BigInt::from_signed_bytes_be(bytes);
         managed_types.big_int_map.insert(dest, result);
@@ -123,10 +132,11 @@ impl BigIntApi for DebugApi {
         big_int_to_i64(bi)
     }
 
-    binary_op_method! {bi_add, add}
-    binary_op_method! {bi_sub, sub}
+    binary_op_method! {bi_add, add, big_int_add}
+    binary_op_method! {bi_sub, sub, big_int_sub}
 
     fn bi_sub_unsigned(&self, dest: Handle, x: Handle, y: Handle) {
+        self.use_gas(|gas_schedule| gas_schedule.big_int_api_cost.big_int_sub);
         let mut managed_types = self.m_types_borrow_mut();
         let bi_x = managed_types.big_int_map.get(x);
         let bi_y = managed_types.big_int_map.get(y);
@@ -137,14 +147,15 @@ impl BigIntApi for DebugApi {
         managed_types.big_int_map.insert(dest, result);
     }
 
-    binary_op_method! {bi_mul, mul}
-    binary_op_method! {bi_t_div, div}
-    binary_op_method! {bi_t_mod, rem}
+    binary_op_method! {bi_mul, mul, big_int_mul}
+    binary_op_method! {bi_t_div, div, big_int_t_div}
+    binary_op_method! {bi_t_mod, rem, big_int_t_mod}
 
-    unary_op_method! {bi_abs, abs}
-    unary_op_method! {bi_neg, neg}
+    unary_op_method! {bi_abs, abs, big_int_abs}
+    unary_op_method! {bi_neg, neg, big_int_neg}
 
     fn bi_sign(&self, x: Handle) -> dharitri_wasm::api::Sign {
+        self.use_gas(|gas_schedule| gas_schedule.big_int_api_cost.big_int_sign);
         let managed_types = self.m_types_borrow();
         let bi = managed_types.big_int_map.get(x);
         match bi.sign() {
@@ -155,15 +166,17 @@ impl BigIntApi for DebugApi {
     }
 
     fn bi_cmp(&self, x: Handle, y: Handle) -> Ordering {
+        self.use_gas(|gas_schedule| gas_schedule.big_int_api_cost.big_int_cmp);
         let managed_types = self.m_types_borrow();
         let bi_x = managed_types.big_int_map.get(x);
         let bi_y = managed_types.big_int_map.get(y);
         bi_x.cmp(bi_y)
     }
 
-    unary_op_method! {bi_sqrt, sqrt}
+    unary_op_method! {bi_sqrt, sqrt, big_int_sqrt}
 
     fn bi_pow(&self, dest: Handle, x: Handle, y: Handle) {
+        self.use_gas(|gas_schedule| gas_schedule.big_int_api_cost.big_int_pow);
         let mut managed_types = self.m_types_borrow_mut();
         let bi_x = managed_types.big_int_map.get(x);
         let bi_y = managed_types.big_int_map.get(y);
@@ -173,16 +186,18 @@ impl BigIntApi for DebugApi {
     }
 
     fn bi_log2(&self, x: Handle) -> u32 {
+        self.use_gas(|gas_schedule| gas_schedule.big_int_api_cost.big_int_log);
         let managed_types = self.m_types_borrow();
         let bi_x = managed_types.big_int_map.get(x);
         bi_x.bits() as u32 - 1
     }
 
-    binary_bitwise_op_method! {bi_and, bitand}
-    binary_bitwise_op_method! {bi_or, bitor}
-    binary_bitwise_op_method! {bi_xor, bitxor}
+    binary_bitwise_op_method! {bi_and, bitand, big_int_and}
+    binary_bitwise_op_method! {bi_or, bitor, big_int_or}
+    binary_bitwise_op_method! {bi_xor, bitxor, big_int_xor}
 
     fn bi_shr(&self, dest: Handle, x: Handle, bits: usize) {
+        self.use_gas(|gas_schedule| gas_schedule.big_int_api_cost.big_int_shr);
         let mut managed_types = self.m_types_borrow_mut();
         let bi_x = managed_types.big_int_map.get(x);
         assert_positive(bi_x);
@@ -191,6 +206,7 @@ impl BigIntApi for DebugApi {
     }
 
     fn bi_shl(&self, dest: Handle, x: Handle, bits: usize) {
+        self.use_gas(|gas_schedule| gas_schedule.big_int_api_cost.big_int_shl);
         let mut managed_types = self.m_types_borrow_mut();
         let bi_x = managed_types.big_int_map.get(x);
         assert_positive(bi_x);
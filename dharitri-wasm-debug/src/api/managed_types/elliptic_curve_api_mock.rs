@@ -2,133 +2,270 @@ use dharitri_wasm::{
     api::{EllipticCurveApi, Handle},
     types::heap::BoxedBytes,
 };
+use num_traits::Zero;
 
-use crate::DebugApi;
+use crate::{
+    num_bigint::BigUint,
+    tx_mock::{EllipticCurveData, EllipticCurvePoint, TxPanic},
+    DebugApi,
+};
+
+const POINT_NOT_ON_CURVE: &str = "point is not on curve";
+const INVALID_BUFFER_LENGTH: &str = "length of buffer is not correct";
+
+/// The VM fails execution (rather than signalling a user error) when curve operations are misused.
+fn ec_fail(message: &str) -> ! {
+    std::panic::panic_any(TxPanic {
+        status: 10,
+        message: message.to_string(),
+    })
+}
+
+/// Pads the big endian representation with zeros on the left, up to `len` bytes.
+fn to_padded_bytes(value: &BigUint, len: usize) -> Vec<u8> {
+    let bytes = value.to_bytes_be();
+    let mut result = vec![0u8; len - bytes.len()];
+    result.extend_from_slice(&bytes);
+    result
+}
+
+impl DebugApi {
+    fn ec_data(&self, ec_handle: Handle) -> EllipticCurveData {
+        let managed_types = self.m_types_borrow();
+        managed_types.elliptic_curve_map.get(ec_handle).clone()
+    }
+
+    fn ec_point(&self, x_handle: Handle, y_handle: Handle) -> EllipticCurvePoint {
+        (
+            self.big_uint_handle_to_value(x_handle),
+            self.big_uint_handle_to_value(y_handle),
+        )
+    }
+
+    fn ec_point_on_curve(
+        &self,
+        curve: &EllipticCurveData,
+        x_handle: Handle,
+        y_handle: Handle,
+    ) -> EllipticCurvePoint {
+        let point = self.ec_point(x_handle, y_handle);
+        if !curve.is_on_curve(&point) {
+            ec_fail(POINT_NOT_ON_CURVE);
+        }
+        point
+    }
+
+    fn ec_set_point(&self, x_handle: Handle, y_handle: Handle, point: EllipticCurvePoint) {
+        let (x, y) = point;
+        self.set_big_uint(x_handle, x);
+        self.set_big_uint(y_handle, y);
+    }
+}
 
 impl EllipticCurveApi for DebugApi {
-    fn ec_create(&self, _name: &[u8]) -> Handle {
-        panic!("ec_create not implemented")
+    fn ec_create(&self, name: &[u8]) -> Handle {
+        let curve =
+            EllipticCurveData::from_name(name).unwrap_or_else(|| ec_fail("unknown elliptic curve"));
+        let mut managed_types = self.m_types_borrow_mut();
+        managed_types.elliptic_curve_map.insert_new_handle(curve)
     }
 
     fn ec_get_values(
         &self,
-        _ec_handle: Handle,
-        _field_order_handle: Handle,
-        _base_point_order_handle: Handle,
-        _eq_constant_handle: Handle,
-        _x_base_point_handle: Handle,
-        _y_base_point_handle: Handle,
+        ec_handle: Handle,
+        field_order_handle: Handle,
+        base_point_order_handle: Handle,
+        eq_constant_handle: Handle,
+        x_base_point_handle: Handle,
+        y_base_point_handle: Handle,
     ) {
-        panic!("ec_get_values not implemented")
+        let curve = self.ec_data(ec_handle);
+        self.set_big_uint(field_order_handle, curve.field_order);
+        self.set_big_uint(base_point_order_handle, curve.base_point_order);
+        self.set_big_uint(eq_constant_handle, curve.eq_constant);
+        self.set_big_uint(x_base_point_handle, curve.x_base_point);
+        self.set_big_uint(y_base_point_handle, curve.y_base_point);
     }
 
-    fn ec_curve_length(&self, _ec_handle: Handle) -> u32 {
-        panic!("ec_curve_length not implemented")
+    fn ec_curve_length(&self, ec_handle: Handle) -> u32 {
+        self.ec_data(ec_handle).size_of_field
     }
 
-    fn ec_private_key_byte_length(&self, _ec_handle: Handle) -> u32 {
-        panic!("ec_private_key_byte_length not implemented")
+    fn ec_private_key_byte_length(&self, ec_handle: Handle) -> u32 {
+        self.ec_data(ec_handle).byte_length() as u32
     }
 
     fn ec_add(
         &self,
-        _x_result_handle: Handle,
-        _y_result_handle: Handle,
-        _ec_handle: Handle,
-        _x_first_point: Handle,
-        _y_first_point: Handle,
-        _x_second_point: Handle,
-        _y_second_point: Handle,
+        x_result_handle: Handle,
+        y_result_handle: Handle,
+        ec_handle: Handle,
+        x_first_point: Handle,
+        y_first_point: Handle,
+        x_second_point: Handle,
+        y_second_point: Handle,
     ) {
-        panic!("ec_add not implemented")
+        let curve = self.ec_data(ec_handle);
+        let first = self.ec_point_on_curve(&curve, x_first_point, y_first_point);
+        let second = self.ec_point_on_curve(&curve, x_second_point, y_second_point);
+        self.ec_set_point(x_result_handle, y_result_handle, curve.add(&first, &second));
     }
 
     fn ec_double(
         &self,
-        _x_result_handle: Handle,
-        _y_result_handle: Handle,
-        _ec_handle: Handle,
-        _x_point_handle: Handle,
-        _y_point_handle: Handle,
+        x_result_handle: Handle,
+        y_result_handle: Handle,
+        ec_handle: Handle,
+        x_point_handle: Handle,
+        y_point_handle: Handle,
     ) {
-        panic!("ec_double not implemented")
+        let curve = self.ec_data(ec_handle);
+        let point = self.ec_point_on_curve(&curve, x_point_handle, y_point_handle);
+        self.ec_set_point(x_result_handle, y_result_handle, curve.double(&point));
     }
 
     fn ec_is_on_curve(
         &self,
-        _ec_handle: Handle,
-        _x_point_handle: Handle,
-        _y_point_handle: Handle,
+        ec_handle: Handle,
+        x_point_handle: Handle,
+        y_point_handle: Handle,
     ) -> bool {
-        panic!("ec_is_on_curve not implemented")
+        let curve = self.ec_data(ec_handle);
+        curve.is_on_curve(&self.ec_point(x_point_handle, y_point_handle))
     }
 
     fn ec_scalar_mult(
         &self,
-        _x_result_handle: Handle,
-        _y_result_handle: Handle,
-        _ec_handle: Handle,
-        _x_point_handle: Handle,
-        _y_point_handle: Handle,
-        _data: &[u8],
+        x_result_handle: Handle,
+        y_result_handle: Handle,
+        ec_handle: Handle,
+        x_point_handle: Handle,
+        y_point_handle: Handle,
+        data: &[u8],
     ) {
-        panic!("ec_scalar_mult not implemented")
+        let curve = self.ec_data(ec_handle);
+        let point = self.ec_point_on_curve(&curve, x_point_handle, y_point_handle);
+        self.ec_set_point(
+            x_result_handle,
+            y_result_handle,
+            curve.scalar_mult(&point, data),
+        );
     }
 
     fn ec_scalar_base_mult(
         &self,
-        _x_result_handle: Handle,
-        _y_result_handle: Handle,
-        _ec_handle: Handle,
-        _data: &[u8],
+        x_result_handle: Handle,
+        y_result_handle: Handle,
+        ec_handle: Handle,
+        data: &[u8],
     ) {
-        panic!("ec_scalar_base_mult not implemented")
+        let curve = self.ec_data(ec_handle);
+        let result = curve.scalar_mult(&curve.base_point(), data);
+        self.ec_set_point(x_result_handle, y_result_handle, result);
     }
 
+    /// Uncompressed form: `0x04 | x | y`.
     fn ec_marshal(
         &self,
-        _ec_handle: Handle,
-        _x_pair_handle: Handle,
-        _y_pair_handle: Handle,
+        ec_handle: Handle,
+        x_pair_handle: Handle,
+        y_pair_handle: Handle,
     ) -> BoxedBytes {
-        panic!("ec_marshal not implemented")
+        let curve = self.ec_data(ec_handle);
+        let (x, y) = self.ec_point_on_curve(&curve, x_pair_handle, y_pair_handle);
+
+        let mut result = vec![4u8];
+        result.extend_from_slice(&to_padded_bytes(&x, curve.byte_length()));
+        result.extend_from_slice(&to_padded_bytes(&y, curve.byte_length()));
+        result.into()
     }
 
+    /// Compressed form: `0x02 | x` for an even y, `0x03 | x` for an odd y.
     fn ec_marshal_compressed(
         &self,
-        _ec_handle: Handle,
-        _x_pair_handle: Handle,
-        _y_pair_handle: Handle,
+        ec_handle: Handle,
+        x_pair_handle: Handle,
+        y_pair_handle: Handle,
     ) -> BoxedBytes {
-        panic!("ec_marshal_compressed not implemented")
+        let curve = self.ec_data(ec_handle);
+        let (x, y) = self.ec_point_on_curve(&curve, x_pair_handle, y_pair_handle);
+
+        let mut result = vec![2u8 | y.bit(0) as u8];
+        result.extend_from_slice(&to_padded_bytes(&x, curve.byte_length()));
+        result.into()
     }
 
     fn ec_unmarshal(
         &self,
-        _x_result_handle: Handle,
-        _y_result_handle: Handle,
-        _ec_handle: Handle,
-        _data: &[u8],
+        x_result_handle: Handle,
+        y_result_handle: Handle,
+        ec_handle: Handle,
+        data: &[u8],
     ) {
-        panic!("ec_unmarshal not implemented")
+        let curve = self.ec_data(ec_handle);
+        let byte_length = curve.byte_length();
+        if data.len() != 1 + 2 * byte_length {
+            ec_fail(INVALID_BUFFER_LENGTH);
+        }
+        if data[0] != 4 {
+            ec_fail(POINT_NOT_ON_CURVE);
+        }
+
+        let point = (
+            BigUint::from_bytes_be(&data[1..=byte_length]),
+            BigUint::from_bytes_be(&data[1 + byte_length..]),
+        );
+        if !curve.is_on_curve(&point) {
+            ec_fail(POINT_NOT_ON_CURVE);
+        }
+        self.ec_set_point(x_result_handle, y_result_handle, point);
     }
 
     fn ec_unmarshal_compressed(
         &self,
-        _x_result_handle: Handle,
-        _y_result_handle: Handle,
-        _ec_handle: Handle,
-        _data: &[u8],
+        x_result_handle: Handle,
+        y_result_handle: Handle,
+        ec_handle: Handle,
+        data: &[u8],
     ) {
-        panic!("ec_unmarshal_compressed not implemented")
+        let curve = self.ec_data(ec_handle);
+        if data.len() != 1 + curve.byte_length() {
+            ec_fail(INVALID_BUFFER_LENGTH);
+        }
+        if data[0] != 2 && data[0] != 3 {
+            ec_fail(POINT_NOT_ON_CURVE);
+        }
+
+        let x = BigUint::from_bytes_be(&data[1..]);
+        let point = curve
+            .decompress(&x, data[0] == 3)
+            .unwrap_or_else(|| ec_fail(POINT_NOT_ON_CURVE));
+        self.ec_set_point(x_result_handle, y_result_handle, point);
     }
 
+    /// The private key is drawn from the transaction random number generator, so results are reproducible.
     fn ec_generate_key(
         &self,
-        _x_pub_key_handle: Handle,
-        _y_pub_key_handle: Handle,
-        _ec_handle: Handle,
+        x_pub_key_handle: Handle,
+        y_pub_key_handle: Handle,
+        ec_handle: Handle,
     ) -> BoxedBytes {
-        panic!("ec_generate_key not implemented")
+        let curve = self.ec_data(ec_handle);
+        let order_bits = curve.base_point_order.bits();
+        let mut private_key = vec![0u8; ((order_bits + 7) / 8) as usize];
+        loop {
+            self.rng_borrow_mut().fill(&mut private_key[..]);
+            if order_bits % 8 != 0 {
+                private_key[0] &= (1u8 << (order_bits % 8)) - 1;
+            }
+            let scalar = BigUint::from_bytes_be(&private_key);
+            if !scalar.is_zero() && scalar < curve.base_point_order {
+                break;
+            }
+        }
+
+        let public_key = curve.scalar_mult(&curve.base_point(), &private_key);
+        self.ec_set_point(x_pub_key_handle, y_pub_key_handle, public_key);
+        private_key.into()
     }
 }
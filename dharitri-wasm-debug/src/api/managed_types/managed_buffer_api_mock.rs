@@ -23,6 +23,7 @@ impl DebugApi {
 
 impl ManagedBufferApi for DebugApi {
     fn mb_new_empty(&self) -> Handle {
+        self.use_gas(|gas_schedule| gas_schedule.managed_buffer_api_cost.m_buffer_new);
         let mut managed_types = self.m_types_borrow_mut();
         managed_types
             .managed_buffer_map
@@ -30,6 +31,7 @@ impl ManagedBufferApi for DebugApi {
     }
 
     fn mb_new_from_bytes(&self, bytes: &[u8]) -> Handle {
+        self.use_gas(|gas_schedule| gas_schedule.managed_buffer_api_cost.m_buffer_new_from_bytes);
         let mut managed_types = self.m_types_borrow_mut();
         managed_types
             .managed_buffer_map
@@ -37,6 +39,7 @@ impl ManagedBufferApi for DebugApi {
     }
 
     fn mb_len(&self, handle: Handle) -> usize {
+        self.use_gas(|gas_schedule| gas_schedule.managed_buffer_api_cost.m_buffer_get_length);
         let managed_types = self.m_types_borrow();
         let data = managed_types.managed_buffer_map.get(handle);
         data.len()
@@ -54,6 +57,7 @@ impl ManagedBufferApi for DebugApi {
         starting_position: usize,
         dest_slice: &mut [u8],
     ) -> Result<(), InvalidSliceError> {
+        self.use_gas(|gas_schedule| gas_schedule.managed_buffer_api_cost.m_buffer_get_byte_slice);
         let opt_slice = self.mb_get_slice(source_handle, starting_position, dest_slice.len());
         if let Some(slice) = opt_slice {
             dest_slice.copy_from_slice(slice.as_slice());
@@ -70,6 +74,11 @@ impl ManagedBufferApi for DebugApi {
         slice_len: usize,
         dest_handle: Handle,
     ) -> Result<(), InvalidSliceError> {
+        self.use_gas(|gas_schedule| {
+            gas_schedule
+                .managed_buffer_api_cost
+                .m_buffer_copy_byte_slice
+        });
         let opt_slice = self.mb_get_slice(source_handle, starting_position, slice_len);
         if let Some(slice) = opt_slice {
             let mut managed_types = self.m_types_borrow_mut();
@@ -99,6 +108,7 @@ impl ManagedBufferApi for DebugApi {
         starting_position: usize,
         source_slice: &[u8],
     ) -> Result<(), InvalidSliceError> {
+        self.use_gas(|gas_schedule| gas_schedule.managed_buffer_api_cost.m_buffer_set_byte_slice);
         let mut managed_types = self.m_types_borrow_mut();
         let bytes = managed_types.managed_buffer_map.get_mut(dest_handle);
         let end_position = starting_position + source_slice.len();
@@ -111,6 +121,7 @@ impl ManagedBufferApi for DebugApi {
     }
 
     fn mb_set_random(&self, dest_handle: Handle, length: usize) {
+        self.use_gas(|gas_schedule| gas_schedule.managed_buffer_api_cost.m_buffer_set_random);
         let mut bytes = Vec::<u8>::new();
         bytes.resize(length, 0);
         let mut rng = self.rng_borrow_mut();
@@ -119,6 +130,7 @@ impl ManagedBufferApi for DebugApi {
     }
 
     fn mb_append(&self, accumulator_handle: Handle, data_handle: Handle) {
+        self.use_gas(|gas_schedule| gas_schedule.managed_buffer_api_cost.m_buffer_append);
         let mut managed_types = self.m_types_borrow_mut();
         let mut data = managed_types.managed_buffer_map.get(data_handle).clone();
         let accumulator = managed_types.managed_buffer_map.get_mut(accumulator_handle);
@@ -126,6 +138,7 @@ impl ManagedBufferApi for DebugApi {
     }
 
     fn mb_append_bytes(&self, accumulator_handle: Handle, bytes: &[u8]) {
+        self.use_gas(|gas_schedule| gas_schedule.managed_buffer_api_cost.m_buffer_append_bytes);
         let mut managed_types = self.m_types_borrow_mut();
         let accumulator = managed_types.managed_buffer_map.get_mut(accumulator_handle);
         accumulator.extend_from_slice(bytes);
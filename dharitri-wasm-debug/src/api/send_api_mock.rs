@@ -1,7 +1,9 @@
 use crate::{
     num_bigint,
     tx_execution::{deploy_contract, execute_builtin_function_or_default},
-    tx_mock::{AsyncCallTxData, BlockchainUpdate, Promise, TxCache, TxInput, TxPanic, TxResult},
+    tx_mock::{
+        AsyncCallTxData, BlockchainUpdate, Promise, TxCache, TxInput, TxInputDCT, TxPanic, TxResult,
+    },
     DebugApi,
 };
 use dharitri_wasm::{
@@ -34,11 +36,20 @@ impl DebugApi {
         }
     }
 
+    /// DCT sent by the contract back to its caller.
+    /// When the current call is an async call, the callback receives them as call value.
+    fn add_back_transfers(&self, recipient: &Address, payments: Vec<TxInputDCT>) {
+        if recipient == &self.input_ref().from {
+            self.result_borrow_mut().back_transfers.extend(payments);
+        }
+    }
+
     fn sync_call_post_processing(
         &self,
         tx_result: TxResult,
         blockchain_updates: BlockchainUpdate,
     ) -> Vec<Vec<u8>> {
+        self.use_gas_amount(tx_result.gas_used);
         self.blockchain_cache().commit_updates(blockchain_updates);
 
         self.result_borrow_mut().merge_after_sync_call(&tx_result);
@@ -62,7 +73,7 @@ impl DebugApi {
             dct_values: Vec::new(),
             func_name,
             args,
-            gas_limit: 1000,
+            gas_limit: self.gas_left(),
             gas_price: 0,
             tx_hash,
         }
@@ -75,6 +86,7 @@ impl DebugApi {
         func_name: Vec<u8>,
         args: Vec<Vec<u8>>,
     ) -> Vec<Vec<u8>> {
+        self.use_gas(|gas_schedule| gas_schedule.dharitri_api_cost.execute_on_dest_context);
         let tx_input = self.prepare_execute_on_dest_context_input(to, moax_value, func_name, args);
         let tx_cache = TxCache::new(self.blockchain_cache_rc());
         let (tx_result, blockchain_updates) =
@@ -98,6 +110,7 @@ impl DebugApi {
         func_name: Vec<u8>,
         args: Vec<Vec<u8>>,
     ) -> Vec<Vec<u8>> {
+        self.use_gas(|gas_schedule| gas_schedule.dharitri_api_cost.transfer_value);
         let tx_input = self.prepare_execute_on_dest_context_input(to, moax_value, func_name, args);
         let tx_cache = TxCache::new(self.blockchain_cache_rc());
         let (tx_result, blockchain_updates) =
@@ -120,6 +133,7 @@ impl DebugApi {
         moax_value: num_bigint::BigUint,
         args: Vec<Vec<u8>>,
     ) -> (Address, Vec<Vec<u8>>) {
+        self.use_gas(|gas_schedule| gas_schedule.dharitri_api_cost.create_contract);
         let contract_address = &self.input_ref().to;
         let tx_hash = self.get_tx_hash_legacy();
         let tx_input = TxInput {
@@ -129,7 +143,7 @@ impl DebugApi {
             dct_values: Vec::new(),
             func_name: Vec::new(),
             args,
-            gas_limit: 1000,
+            gas_limit: self.gas_left(),
             gas_price: 0,
             tx_hash,
         };
@@ -153,6 +167,12 @@ impl DebugApi {
         }
     }
 
+    /// The async call step is charged, then all the remaining gas is handed over to the async call.
+    fn async_call_gas_limit(&self) -> u64 {
+        self.use_gas(|gas_schedule| gas_schedule.dharitri_api_cost.async_call_step);
+        self.gas_left()
+    }
+
     fn perform_async_call(&self, call: AsyncCallTxData) -> ! {
         // the cell is no longer needed, since we end in a panic
         let mut tx_result = self.extract_result();
@@ -183,8 +203,10 @@ impl DebugApi {
             from: contract_address,
             to: recipient,
             call_value,
+            dct_values: Vec::new(),
             endpoint_name: UPGRADE_CONTRACT_FUNC_NAME.to_vec(),
             arguments,
+            gas_limit: self.async_call_gas_limit(),
             tx_hash,
         };
         self.perform_async_call(call)
@@ -211,6 +233,7 @@ impl SendApiImpl for DebugApi {
         M: ManagedTypeApi,
         D: Into<ManagedBuffer<M>>,
     {
+        self.use_gas(|gas_schedule| gas_schedule.dharitri_api_cost.transfer_value);
         let amount_value = self.big_uint_handle_to_value(amount.get_raw_handle());
         let available_moax_balance =
             self.with_contract_account(|account| account.moax_balance.clone());
@@ -268,12 +291,21 @@ impl SendApiImpl for DebugApi {
         Self::append_endpoint_name_and_args(&mut args, endpoint_name, arg_buffer);
 
         let _ = self.perform_transfer_execute(
-            recipient,
+            recipient.clone(),
             num_bigint::BigUint::zero(),
             DCT_TRANSFER_FUNC_NAME.to_vec(),
             args,
         );
 
+        self.add_back_transfers(
+            &recipient,
+            vec![TxInputDCT {
+                token_identifier: top_encode_to_vec_u8(token).unwrap(),
+                nonce: 0,
+                value: self.big_uint_handle_to_value(amount.get_raw_handle()),
+            }],
+        );
+
         Ok(())
     }
 
@@ -310,6 +342,15 @@ impl SendApiImpl for DebugApi {
             args,
         );
 
+        self.add_back_transfers(
+            &recipient,
+            vec![TxInputDCT {
+                token_identifier: top_encode_to_vec_u8(token).unwrap(),
+                nonce,
+                value: self.big_uint_handle_to_value(amount.get_raw_handle()),
+            }],
+        );
+
         Ok(())
     }
 
@@ -354,6 +395,18 @@ impl SendApiImpl for DebugApi {
             args,
         );
 
+        self.add_back_transfers(
+            &recipient,
+            payments
+                .into_iter()
+                .map(|payment| TxInputDCT {
+                    token_identifier: top_encode_to_vec_u8(&payment.token_identifier).unwrap(),
+                    nonce: payment.token_nonce,
+                    value: self.big_uint_handle_to_value(payment.amount.get_raw_handle()),
+                })
+                .collect(),
+        );
+
         Ok(())
     }
 
@@ -368,14 +421,19 @@ impl SendApiImpl for DebugApi {
         let contract_address = self.input_ref().to.clone();
         let recipient = to.to_address();
         let tx_hash = self.get_tx_hash_legacy();
-        let call = AsyncCallTxData {
+        let mut call = AsyncCallTxData {
             from: contract_address,
             to: recipient,
             call_value: amount_value,
+            dct_values: Vec::new(),
             endpoint_name: endpoint_name.to_boxed_bytes().into_vec(),
             arguments: arg_buffer.to_raw_args_vec(),
+            gas_limit: self.async_call_gas_limit(),
             tx_hash,
         };
+        if let Err(message) = call.unpack_dct_transfer() {
+            std::panic::panic_any(TxResult::from_vm_error(message));
+        }
         self.perform_async_call(call)
     }
 
@@ -386,28 +444,35 @@ impl SendApiImpl for DebugApi {
         endpoint_name: &ManagedBuffer<M>,
         success_callback: &'static [u8],
         error_callback: &'static [u8],
-        _gas: u64,
-        _extra_gas_for_callback: u64,
+        gas: u64,
+        extra_gas_for_callback: u64,
         arg_buffer: &ManagedArgBuffer<M>,
     ) {
+        self.use_gas(|gas_schedule| gas_schedule.dharitri_api_cost.async_call_step);
         let amount_value = self.big_uint_handle_to_value(amount.get_raw_handle());
         let contract_address = self.input_ref().to.clone();
         let recipient = to.to_address();
         let tx_hash = self.get_tx_hash_legacy();
 
-        let call = AsyncCallTxData {
+        let mut call = AsyncCallTxData {
             from: contract_address,
             to: recipient,
             call_value: amount_value,
+            dct_values: Vec::new(),
             endpoint_name: endpoint_name.to_boxed_bytes().into_vec(),
             arguments: arg_buffer.to_raw_args_vec(),
+            gas_limit: gas,
             tx_hash,
         };
+        if let Err(message) = call.unpack_dct_transfer() {
+            std::panic::panic_any(TxResult::from_vm_error(message));
+        }
 
         let promise = Promise {
             endpoint: call,
             success_callback,
             error_callback,
+            extra_gas_for_callback,
         };
 
         let mut tx_result = self.result_borrow_mut();
@@ -20,10 +20,22 @@ impl StorageReadApi for DebugApi {
 
 impl DebugApi {
     fn storage_load_vec_u8(&self, key: &[u8]) -> Vec<u8> {
-        self.with_contract_account(|account| match account.storage.get(&key.to_vec()) {
-            None => Vec::with_capacity(0),
-            Some(value) => value.clone(),
-        })
+        self.blockchain_ref()
+            .with_coverage(|coverage| coverage.record_storage_read(key));
+        let value =
+            self.with_contract_account(|account| match account.storage.get(&key.to_vec()) {
+                None => Vec::with_capacity(0),
+                Some(value) => value.clone(),
+            });
+        self.use_storage_load_gas(value.len());
+        value
+    }
+
+    fn use_storage_load_gas(&self, value_len: usize) {
+        self.use_gas(|gas_schedule| {
+            gas_schedule.dharitri_api_cost.storage_load
+                + gas_schedule.base_operation_cost.data_copy_per_byte * value_len as u64
+        });
     }
 }
 
@@ -79,12 +91,15 @@ impl StorageReadApiImpl for DebugApi {
             self.mb_to_boxed_bytes(address_handle).as_slice(),
         );
         let key_bytes = self.mb_to_boxed_bytes(key_handle);
-        self.with_account(&address, |account| {
-            match account.storage.get(key_bytes.as_slice()) {
-                None => self.mb_overwrite(dest, &[]),
-                Some(value) => self.mb_overwrite(dest, value.as_slice()),
-            }
-        })
+        let value = self.with_account(&address, |account| {
+            account
+                .storage
+                .get(key_bytes.as_slice())
+                .cloned()
+                .unwrap_or_default()
+        });
+        self.use_storage_load_gas(value.len());
+        self.mb_overwrite(dest, value.as_slice());
     }
 }
 
@@ -106,6 +121,13 @@ impl StorageWriteApiImpl for DebugApi {
             });
         }
 
+        self.use_gas(|gas_schedule| {
+            gas_schedule.dharitri_api_cost.storage_store
+                + gas_schedule.base_operation_cost.store_per_byte * value.len() as u64
+        });
+
+        self.blockchain_ref()
+            .with_coverage(|coverage| coverage.record_storage_write(key));
         self.with_contract_account_mut(|account| {
             account.storage.insert(key.to_vec(), value.to_vec());
         });
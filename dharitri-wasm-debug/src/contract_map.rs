@@ -10,6 +10,7 @@ pub type ContractCallFactory = Box<dyn Fn(DebugApi) -> Box<dyn CallableContract>
 
 pub struct ContractMap {
     contract_objs: HashMap<Vec<u8>, Box<dyn CallableContract>>,
+    contract_names: HashMap<Vec<u8>, String>,
 }
 
 impl fmt::Debug for ContractMap {
@@ -22,6 +23,7 @@ impl ContractMap {
     pub fn new() -> Self {
         ContractMap {
             contract_objs: HashMap::new(),
+            contract_names: HashMap::new(),
         }
     }
 
@@ -49,6 +51,23 @@ impl ContractMap {
     pub fn contains_contract(&self, contract_bytes: &[u8]) -> bool {
         self.contract_objs.contains_key(contract_bytes)
     }
+
+    /// Keeps the expression used to register the contract, for reporting.
+    pub fn register_contract_name(&mut self, contract_bytes: Vec<u8>, name: &str) {
+        self.contract_names.insert(contract_bytes, name.to_string());
+    }
+
+    /// The expression the contract was registered with, e.g. `file:output/adder.wasm`.
+    pub fn contract_name(&self, contract_identifier: &[u8]) -> String {
+        if let Some(name) = self.contract_names.get(contract_identifier) {
+            name.clone()
+        } else {
+            format!(
+                "unnamed contract of length {} bytes",
+                contract_identifier.len()
+            )
+        }
+    }
 }
 
 fn unknown_contract_panic(contract_identifier: &[u8]) -> ! {
@@ -62,6 +81,22 @@ fn unknown_contract_panic(contract_identifier: &[u8]) -> ! {
     }
 }
 
+/// Contract objects are stateless, so cloning them yields equivalent contracts.
+impl Clone for ContractMap {
+    fn clone(&self) -> Self {
+        ContractMap {
+            contract_objs: self
+                .contract_objs
+                .iter()
+                .map(|(contract_bytes, contract_obj)| {
+                    (contract_bytes.clone(), contract_obj.clone_obj())
+                })
+                .collect(),
+            contract_names: self.contract_names.clone(),
+        }
+    }
+}
+
 impl Default for ContractMap {
     fn default() -> Self {
         Self::new()
@@ -0,0 +1,98 @@
+use std::path::PathBuf;
+
+use crate::tx_mock::TxResult;
+
+use super::CoverageReport;
+
+/// Status of the errors signalled by the contract itself, e.g. via `require!`.
+const USER_ERROR_STATUS: u64 = 4;
+
+/// Collects coverage data while transactions are executed.
+///
+/// Storage accesses are attributed to the contract currently executing,
+/// which is why entering and exiting contract calls must be recorded.
+#[derive(Debug)]
+pub struct CoverageRecorder {
+    pub report: CoverageReport,
+    pub report_dir: PathBuf,
+    pub current_scenario: String,
+    contract_stack: Vec<String>,
+}
+
+impl CoverageRecorder {
+    pub fn new(report_dir: PathBuf) -> Self {
+        CoverageRecorder {
+            report: CoverageReport::default(),
+            report_dir,
+            current_scenario: String::new(),
+            contract_stack: Vec::new(),
+        }
+    }
+
+    pub fn enter_contract(&mut self, contract_name: String) {
+        self.contract_stack.push(contract_name);
+    }
+
+    pub fn exit_contract(&mut self, endpoint_name: &[u8], tx_result: &TxResult) {
+        let contract_name = self
+            .contract_stack
+            .pop()
+            .expect("coverage: exiting contract that was never entered");
+        let endpoint_coverage = self
+            .report
+            .contract_mut(contract_name.as_str())
+            .endpoints
+            .entry(String::from_utf8_lossy(endpoint_name).into_owned())
+            .or_default();
+
+        endpoint_coverage.calls += 1;
+        if !self.current_scenario.is_empty() {
+            endpoint_coverage
+                .scenarios
+                .insert(self.current_scenario.clone());
+        }
+        if tx_result.result_status == USER_ERROR_STATUS {
+            *endpoint_coverage
+                .user_errors
+                .entry(tx_result.result_message.clone())
+                .or_default() += 1;
+        }
+    }
+
+    pub fn record_storage_read(&mut self, key: &[u8]) {
+        if let Some(contract_name) = self.contract_stack.last() {
+            self.report
+                .contracts
+                .entry(contract_name.clone())
+                .or_default()
+                .storage_reads
+                .insert(storage_key_name(key));
+        }
+    }
+
+    pub fn record_storage_write(&mut self, key: &[u8]) {
+        if let Some(contract_name) = self.contract_stack.last() {
+            self.report
+                .contracts
+                .entry(contract_name.clone())
+                .or_default()
+                .storage_writes
+                .insert(storage_key_name(key));
+        }
+    }
+}
+
+/// Storage mappers build their keys from a readable base key, followed by encoded arguments.
+/// Only the base key is kept, otherwise every user address, for instance, would show up as a different key.
+pub fn storage_key_name(key: &[u8]) -> String {
+    let readable_len = key.iter().take_while(|b| b.is_ascii_graphic()).count();
+    if readable_len == 0 {
+        return format!("0x{}", hex::encode(key));
+    }
+    let base_key = String::from_utf8_lossy(&key[..readable_len]).into_owned();
+    if readable_len == key.len() {
+        base_key
+    } else {
+        format!("{}[..]", base_key)
+    }
+}
@@ -0,0 +1,148 @@
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt,
+};
+
+/// What the scenarios exercised, per contract.
+///
+/// Contracts are identified by the expression they were registered with, e.g. `file:output/adder.wasm`.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct CoverageReport {
+    pub contracts: BTreeMap<String, ContractCoverage>,
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContractCoverage {
+    pub endpoints: BTreeMap<String, EndpointCoverage>,
+    pub storage_reads: BTreeSet<String>,
+    pub storage_writes: BTreeSet<String>,
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EndpointCoverage {
+    pub calls: u64,
+
+    /// Number of times each user error (e.g. a failed `require!`) was signalled, by error message.
+    pub user_errors: BTreeMap<String, u64>,
+
+    pub scenarios: BTreeSet<String>,
+}
+
+impl CoverageReport {
+    pub fn contract_mut(&mut self, contract_name: &str) -> &mut ContractCoverage {
+        self.contracts.entry(contract_name.to_string()).or_default()
+    }
+
+    /// Makes the endpoints show up in the report even if no scenario calls them.
+    pub fn add_known_endpoints<'a, I: Iterator<Item = &'a str>>(
+        &mut self,
+        contract_name: &str,
+        endpoint_names: I,
+    ) {
+        let contract_coverage = self.contract_mut(contract_name);
+        for endpoint_name in endpoint_names {
+            contract_coverage
+                .endpoints
+                .entry(endpoint_name.to_string())
+                .or_default();
+        }
+    }
+
+    pub fn merge(&mut self, other: &CoverageReport) {
+        for (contract_name, other_contract) in &other.contracts {
+            let contract_coverage = self.contract_mut(contract_name);
+            for (endpoint_name, other_endpoint) in &other_contract.endpoints {
+                let endpoint_coverage = contract_coverage
+                    .endpoints
+                    .entry(endpoint_name.clone())
+                    .or_default();
+                endpoint_coverage.calls += other_endpoint.calls;
+                for (message, count) in &other_endpoint.user_errors {
+                    *endpoint_coverage
+                        .user_errors
+                        .entry(message.clone())
+                        .or_default() += count;
+                }
+                endpoint_coverage
+                    .scenarios
+                    .extend(other_endpoint.scenarios.iter().cloned());
+            }
+            contract_coverage
+                .storage_reads
+                .extend(other_contract.storage_reads.iter().cloned());
+            contract_coverage
+                .storage_writes
+                .extend(other_contract.storage_writes.iter().cloned());
+        }
+    }
+
+    pub fn to_json_string(&self) -> String {
+        let mut serialized = serde_json::to_string_pretty(self).unwrap();
+        serialized.push('\n');
+        serialized
+    }
+
+    pub fn from_json_str(s: &str) -> Self {
+        serde_json::from_str(s).unwrap()
+    }
+}
+
+impl ContractCoverage {
+    /// Endpoints known from the ABI, that no scenario called.
+    pub fn uncovered_endpoints(&self) -> Vec<&str> {
+        self.endpoints
+            .iter()
+            .filter(|(_, endpoint_coverage)| endpoint_coverage.calls == 0)
+            .map(|(endpoint_name, _)| endpoint_name.as_str())
+            .collect()
+    }
+}
+
+/// Human-readable summary.
+impl fmt::Display for CoverageReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (contract_name, contract_coverage) in &self.contracts {
+            let num_endpoints = contract_coverage.endpoints.len();
+            let num_uncovered = contract_coverage.uncovered_endpoints().len();
+            writeln!(
+                f,
+                "{}: {} of {} endpoints covered",
+                contract_name,
+                num_endpoints - num_uncovered,
+                num_endpoints
+            )?;
+
+            for (endpoint_name, endpoint_coverage) in &contract_coverage.endpoints {
+                if endpoint_coverage.calls == 0 {
+                    writeln!(f, "    {}: NOT COVERED", endpoint_name)?;
+                    continue;
+                }
+                writeln!(
+                    f,
+                    "    {}: {} calls, {} scenarios",
+                    endpoint_name,
+                    endpoint_coverage.calls,
+                    endpoint_coverage.scenarios.len()
+                )?;
+                for (message, count) in &endpoint_coverage.user_errors {
+                    writeln!(f, "        user error {:?}: {}", message, count)?;
+                }
+            }
+
+            writeln!(
+                f,
+                "    storage read: {}",
+                itertools::join(contract_coverage.storage_reads.iter(), ", ")
+            )?;
+            writeln!(
+                f,
+                "    storage written: {}",
+                itertools::join(contract_coverage.storage_writes.iter(), ", ")
+            )?;
+        }
+        Ok(())
+    }
+}
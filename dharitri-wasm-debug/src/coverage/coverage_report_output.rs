@@ -0,0 +1,41 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use super::CoverageReport;
+
+pub const COVERAGE_JSON_FILE_NAME: &str = "denali-coverage.json";
+pub const COVERAGE_SUMMARY_FILE_NAME: &str = "denali-coverage.txt";
+
+/// Tests run in parallel threads, each with its own `BlockchainMock`.
+/// Their coverage is accumulated here, per report directory, for the whole test process.
+static PROCESS_COVERAGE: Mutex<BTreeMap<PathBuf, CoverageReport>> = Mutex::new(BTreeMap::new());
+
+/// Adds the report to everything recorded so far by the test process in the same directory,
+/// then (over)writes the JSON and the human-readable summaries there.
+///
+/// After all tests finish, the files describe the entire test suite.
+pub fn write_coverage_report(report_dir: &Path, report: &CoverageReport) {
+    let mut process_coverage = PROCESS_COVERAGE
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let cumulated_report = process_coverage
+        .entry(report_dir.to_path_buf())
+        .or_default();
+    cumulated_report.merge(report);
+
+    fs::create_dir_all(report_dir).unwrap();
+    fs::write(
+        report_dir.join(COVERAGE_JSON_FILE_NAME),
+        cumulated_report.to_json_string(),
+    )
+    .unwrap();
+    fs::write(
+        report_dir.join(COVERAGE_SUMMARY_FILE_NAME),
+        cumulated_report.to_string(),
+    )
+    .unwrap();
+}
@@ -0,0 +1,7 @@
+mod coverage_recorder;
+mod coverage_report;
+mod coverage_report_output;
+
+pub use coverage_recorder::*;
+pub use coverage_report::*;
+pub use coverage_report_output::*;
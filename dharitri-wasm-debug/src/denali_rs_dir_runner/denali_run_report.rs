@@ -0,0 +1,76 @@
+use std::{fmt, time::Duration};
+
+#[derive(Debug)]
+pub struct ScenarioRunResult {
+    /// Relative to the current dir, as it would be passed to `denali_rs`.
+    pub path: String,
+    pub duration: Duration,
+    /// The panic message, if the scenario failed.
+    pub error: Option<String>,
+}
+
+impl ScenarioRunResult {
+    pub fn is_ok(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct DenaliRunReport {
+    pub results: Vec<ScenarioRunResult>,
+    pub num_filtered_out: usize,
+}
+
+impl DenaliRunReport {
+    pub fn num_passed(&self) -> usize {
+        self.results.iter().filter(|result| result.is_ok()).count()
+    }
+
+    pub fn num_failed(&self) -> usize {
+        self.results.len() - self.num_passed()
+    }
+
+    pub fn assert_all_passed(&self) {
+        assert!(
+            self.num_failed() == 0,
+            "{} of {} denali scenarios failed",
+            self.num_failed(),
+            self.results.len()
+        );
+    }
+}
+
+/// Consolidated pass/fail table, with timings.
+impl fmt::Display for DenaliRunReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let path_width = self
+            .results
+            .iter()
+            .map(|result| result.path.len())
+            .max()
+            .unwrap_or_default();
+        for result in &self.results {
+            let outcome = if result.is_ok() { "ok" } else { "FAILED" };
+            writeln!(
+                f,
+                "{:<width$}  {:<6}  {:>6} ms",
+                result.path,
+                outcome,
+                result.duration.as_millis(),
+                width = path_width
+            )?;
+            if let Some(error) = &result.error {
+                for line in error.lines() {
+                    writeln!(f, "    {}", line)?;
+                }
+            }
+        }
+        writeln!(
+            f,
+            "denali scenarios: {} passed; {} failed; {} filtered out",
+            self.num_passed(),
+            self.num_failed(),
+            self.num_filtered_out
+        )
+    }
+}
@@ -0,0 +1,216 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread,
+    time::Instant,
+};
+
+use crate::{denali_rs, world_mock::BlockchainMock};
+
+use super::{glob_match, DenaliRunReport, ScenarioRunResult};
+
+const SCENARIO_FILE_SUFFIX: &str = ".scen.json";
+
+/// Discovers and runs all `*.scen.json` files in a directory (recursively), in parallel.
+///
+/// Every scenario runs in its own thread, with its own `BlockchainMock`, created by the `world` function,
+/// so scenarios are isolated from each other.
+///
+/// Typical usage, replacing the individual tests generated with `test-gen`:
+///
+/// ```ignore
+/// #[test]
+/// fn all_scenarios_rs() {
+///     DenaliRsDirRunner::new("denali", world)
+///         .exclude("*-go-only.scen.json")
+///         .run()
+///         .assert_all_passed();
+/// }
+/// ```
+pub struct DenaliRsDirRunner<F>
+where
+    F: Fn() -> BlockchainMock + Sync,
+{
+    relative_dir: PathBuf,
+    world: F,
+    include_globs: Vec<String>,
+    exclude_globs: Vec<String>,
+    include_tags: Vec<String>,
+    exclude_tags: Vec<String>,
+    num_threads: usize,
+}
+
+impl<F> DenaliRsDirRunner<F>
+where
+    F: Fn() -> BlockchainMock + Sync,
+{
+    /// The directory is relative to the current dir of the worlds created.
+    pub fn new<P: AsRef<Path>>(relative_dir: P, world: F) -> Self {
+        DenaliRsDirRunner {
+            relative_dir: relative_dir.as_ref().to_path_buf(),
+            world,
+            include_globs: Vec::new(),
+            exclude_globs: Vec::new(),
+            include_tags: Vec::new(),
+            exclude_tags: Vec::new(),
+            num_threads: thread::available_parallelism().map_or(1, |n| n.get()),
+        }
+    }
+
+    /// Only runs the scenarios whose path matches at least one of the include globs.
+    /// Paths are relative to the current dir, e.g. `denali/adder.scen.json`.
+    #[must_use]
+    pub fn include(mut self, glob: &str) -> Self {
+        self.include_globs.push(glob.to_string());
+        self
+    }
+
+    /// Skips the scenarios whose path matches any of the exclude globs.
+    #[must_use]
+    pub fn exclude(mut self, glob: &str) -> Self {
+        self.exclude_globs.push(glob.to_string());
+        self
+    }
+
+    /// Only runs the scenarios that have at least one of the included tags.
+    #[must_use]
+    pub fn include_tag(mut self, tag: &str) -> Self {
+        self.include_tags.push(tag.to_string());
+        self
+    }
+
+    /// Skips the scenarios that have any of the excluded tags.
+    #[must_use]
+    pub fn exclude_tag(mut self, tag: &str) -> Self {
+        self.exclude_tags.push(tag.to_string());
+        self
+    }
+
+    /// Defaults to the available parallelism.
+    #[must_use]
+    pub fn num_threads(mut self, num_threads: usize) -> Self {
+        assert!(num_threads > 0, "at least 1 thread required");
+        self.num_threads = num_threads;
+        self
+    }
+
+    /// Runs the selected scenarios, prints the results table and returns it.
+    pub fn run(self) -> DenaliRunReport {
+        let current_dir = (self.world)().current_dir;
+        let mut all_paths = Vec::new();
+        find_scenario_files(
+            current_dir.as_path(),
+            self.relative_dir.as_path(),
+            &mut all_paths,
+        );
+        let num_found = all_paths.len();
+        let selected_paths: Vec<String> = all_paths
+            .into_iter()
+            .filter(|path| self.is_selected(current_dir.as_path(), path))
+            .collect();
+
+        let next_index = AtomicUsize::new(0);
+        let results = Mutex::new(Vec::new());
+        thread::scope(|scope| {
+            for _ in 0..self.num_threads.min(selected_paths.len()) {
+                scope.spawn(|| loop {
+                    let index = next_index.fetch_add(1, Ordering::SeqCst);
+                    if index >= selected_paths.len() {
+                        break;
+                    }
+                    let result = self.run_isolated(selected_paths[index].as_str());
+                    results.lock().unwrap().push(result);
+                });
+            }
+        });
+
+        let mut results = results.into_inner().unwrap();
+        results.sort_by(|a, b| a.path.cmp(&b.path));
+        let report = DenaliRunReport {
+            num_filtered_out: num_found - results.len(),
+            results,
+        };
+        println!("{}", report);
+        report
+    }
+
+    fn is_selected(&self, current_dir: &Path, path: &str) -> bool {
+        if !self.include_globs.is_empty()
+            && !self.include_globs.iter().any(|glob| glob_match(glob, path))
+        {
+            return false;
+        }
+        if self.exclude_globs.iter().any(|glob| glob_match(glob, path)) {
+            return false;
+        }
+        if self.include_tags.is_empty() && self.exclude_tags.is_empty() {
+            return true;
+        }
+
+        let tags = denali::parse_scenario_raw(current_dir.join(path)).tags;
+        if !self.include_tags.is_empty() && !tags.iter().any(|tag| self.include_tags.contains(tag))
+        {
+            return false;
+        }
+        !tags.iter().any(|tag| self.exclude_tags.contains(tag))
+    }
+
+    /// A separate thread for each scenario guarantees that a failed scenario leaves nothing behind
+    /// in the thread-local state of the debugger.
+    fn run_isolated(&self, path: &str) -> ScenarioRunResult {
+        let start = Instant::now();
+        let outcome = thread::scope(|scope| scope.spawn(|| denali_rs(path, (self.world)())).join());
+        ScenarioRunResult {
+            path: path.to_string(),
+            duration: start.elapsed(),
+            error: outcome
+                .err()
+                .map(|panic_any| panic_message(panic_any.as_ref())),
+        }
+    }
+}
+
+/// Runs all scenarios in the directory, in parallel, and fails if any of them fails.
+pub fn denali_rs_dir<P, F>(relative_dir: P, world: F)
+where
+    P: AsRef<Path>,
+    F: Fn() -> BlockchainMock + Sync,
+{
+    DenaliRsDirRunner::new(relative_dir, world)
+        .run()
+        .assert_all_passed();
+}
+
+fn find_scenario_files(current_dir: &Path, relative_dir: &Path, result: &mut Vec<String>) {
+    let dir_entries = fs::read_dir(current_dir.join(relative_dir))
+        .unwrap_or_else(|e| panic!("cannot read dir {:?}: {}", relative_dir, e));
+    let mut relative_paths: Vec<PathBuf> = dir_entries
+        .map(|entry| relative_dir.join(entry.unwrap().file_name()))
+        .collect();
+    relative_paths.sort();
+
+    for relative_path in relative_paths {
+        if current_dir.join(&relative_path).is_dir() {
+            find_scenario_files(current_dir, relative_path.as_path(), result);
+        } else {
+            let path_str = relative_path.to_string_lossy().into_owned();
+            if path_str.ends_with(SCENARIO_FILE_SUFFIX) {
+                result.push(path_str);
+            }
+        }
+    }
+}
+
+fn panic_message(panic_any: &(dyn std::any::Any + Send)) -> String {
+    if let Some(s) = panic_any.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = panic_any.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
@@ -0,0 +1,7 @@
+mod denali_run_report;
+mod dir_runner;
+mod glob_match;
+
+pub use denali_run_report::*;
+pub use dir_runner::*;
+pub use glob_match::glob_match;
@@ -8,10 +8,16 @@ use std::path::Path;
 /// Runs denali test using the Rust infrastructure and the debug mode.
 /// Uses a contract map to replace the references to the wasm bytecode
 /// with the contracts running in debug mode.
+///
+/// If coverage is enabled, the scenario's contribution is added to the coverage report afterwards.
 pub fn denali_rs<P: AsRef<Path>>(relative_path: P, mut world: BlockchainMock) {
     let mut absolute_path = world.current_dir.clone();
-    absolute_path.push(relative_path);
+    absolute_path.push(relative_path.as_ref());
+    world.with_coverage(|coverage| {
+        coverage.current_scenario = relative_path.as_ref().to_string_lossy().into_owned()
+    });
     let _ = parse_execute_denali_steps(absolute_path.as_ref(), &mut world);
+    world.write_coverage_report();
 }
 
 fn parse_execute_denali_steps(steps_path: &Path, state: &mut BlockchainMock) {
@@ -48,6 +54,12 @@ fn parse_execute_denali_steps(steps_path: &Path, state: &mut BlockchainMock) {
             Step::DumpState(_) => {
                 state.denali_dump_state();
             },
+            Step::Snapshot(snapshot_step) => {
+                state.denali_snapshot(snapshot_step);
+            },
+            Step::RestoreSnapshot(restore_step) => {
+                state.denali_restore_snapshot(restore_step);
+            },
         }
     }
 }
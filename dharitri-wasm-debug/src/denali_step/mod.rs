@@ -5,6 +5,7 @@ pub mod sc_call;
 pub mod sc_deploy;
 pub mod sc_query;
 mod set_state;
+mod snapshot;
 mod transfer;
 mod tx_output_check;
 
@@ -11,7 +11,7 @@ use crate::{
     CallBuilder, DebugApi,
 };
 
-use super::check_tx_output;
+use super::{check_tx_gas, check_tx_output};
 
 impl BlockchainMock {
     /// Adds a denali SC call step, as specified in the `sc_call_step` argument, then executes it.
@@ -78,6 +78,14 @@ fn execute_and_check(
     let (tx_result, state) = execute(state, sc_call_step);
     if let Some(tx_expect) = &sc_call_step.expect {
         check_tx_output(&sc_call_step.tx_id, tx_expect, &tx_result);
+        if state.gas_schedule.is_some() {
+            check_tx_gas(
+                &sc_call_step.tx_id,
+                tx_expect,
+                sc_call_step.tx.gas_limit.value,
+                &tx_result,
+            );
+        }
     }
     (tx_result, state)
 }
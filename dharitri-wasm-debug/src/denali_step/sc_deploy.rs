@@ -11,7 +11,7 @@ use crate::{
     CallBuilder, DebugApi,
 };
 
-use super::check_tx_output;
+use super::{check_tx_gas, check_tx_output};
 
 impl BlockchainMock {
     /// Adds a denali SC deploy step, as specified in the `sc_deploy_step` argument, then executes it.
@@ -85,6 +85,14 @@ fn execute_and_check(
     let (tx_result, address, state) = execute(state, sc_deploy_step);
     if let Some(tx_expect) = &sc_deploy_step.expect {
         check_tx_output(&sc_deploy_step.tx_id, tx_expect, &tx_result);
+        if state.gas_schedule.is_some() {
+            check_tx_gas(
+                &sc_deploy_step.tx_id,
+                tx_expect,
+                sc_deploy_step.tx.gas_limit.value,
+                &tx_result,
+            );
+        }
     }
     (tx_result, address, state)
 }
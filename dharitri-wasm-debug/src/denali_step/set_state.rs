@@ -58,6 +58,22 @@ fn execute(state: &mut BlockchainMock, set_state_step: &SetStateStep) {
                 .map(|address_value| address_value.value.clone()),
         });
     }
+    for (token_identifier, dct_token) in set_state_step.dct_tokens.iter() {
+        state.update_system_sc_token_data(token_identifier.value.as_slice(), |token_data| {
+            if let Some(owner) = &dct_token.owner {
+                token_data.owner = owner.value.clone();
+            }
+            if let Some(u64_value) = &dct_token.paused {
+                token_data.paused = u64_value.value > 0;
+            }
+            if let Some(u64_value) = &dct_token.can_freeze {
+                token_data.set_property(b"canFreeze", u64_value.value > 0);
+            }
+            if let Some(u64_value) = &dct_token.can_wipe {
+                token_data.set_property(b"canWipe", u64_value.value > 0);
+            }
+        });
+    }
     for new_address in set_state_step.new_addresses.iter() {
         assert!(
             is_smart_contract_address(&new_address.new_address.value),
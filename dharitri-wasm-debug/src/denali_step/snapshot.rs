@@ -0,0 +1,19 @@
+use denali::model::{RestoreSnapshotStep, SnapshotStep, Step};
+
+use crate::world_mock::BlockchainMock;
+
+impl BlockchainMock {
+    pub fn denali_snapshot(&mut self, snapshot_step: SnapshotStep) -> &mut Self {
+        self.save_named_snapshot(snapshot_step.id.as_str());
+        self.denali_trace.steps.push(Step::Snapshot(snapshot_step));
+        self
+    }
+
+    pub fn denali_restore_snapshot(&mut self, restore_step: RestoreSnapshotStep) -> &mut Self {
+        self.restore_named_snapshot(restore_step.id.as_str());
+        self.denali_trace
+            .steps
+            .push(Step::RestoreSnapshot(restore_step));
+        self
+    }
+}
@@ -3,7 +3,11 @@ use denali::model::{CheckLogs, Checkable, TxExpect};
 use crate::{address_hex, bytes_to_string, tx_mock::TxResult, verbose_hex, verbose_hex_list};
 
 pub fn check_tx_output(tx_id: &str, tx_expect: &TxExpect, tx_result: &TxResult) {
-    let have_str = tx_result.result_message.as_str();
+    let have_str = format!(
+        "{}{}",
+        tx_result.result_message,
+        tx_result.decode_error_context_suffix()
+    );
     assert!(
         tx_expect.status.check(tx_result.result_status),
         "result code mismatch. Tx id: {}. Want: {}. Have: {}. Message: {}",
@@ -15,10 +19,11 @@ pub fn check_tx_output(tx_id: &str, tx_expect: &TxExpect, tx_result: &TxResult)
 
     assert!(
         tx_expect.out.check(tx_result.result_values.as_slice()),
-        "bad out value. Tx id: {}. Want: [{}]. Have: [{}]",
+        "bad out value. Tx id: {}. Want: [{}]. Have: [{}]{}",
         tx_id,
         tx_expect.out_to_string(),
-        tx_result.result_values_to_string()
+        tx_result.result_values_to_string(),
+        tx_expect.out_typed_diff(tx_result.result_values.as_slice())
     );
 
     assert!(
@@ -72,3 +77,17 @@ pub fn check_tx_output(tx_id: &str, tx_expect: &TxExpect, tx_result: &TxResult)
         },
     }
 }
+
+/// The expected gas is the gas remaining after the transaction, as in denali-go.
+/// Only meaningful when the blockchain mock meters gas.
+pub fn check_tx_gas(tx_id: &str, tx_expect: &TxExpect, gas_limit: u64, tx_result: &TxResult) {
+    let gas_remaining = gas_limit.saturating_sub(tx_result.gas_used);
+    assert!(
+        tx_expect.gas.check(gas_remaining),
+        "gas remaining mismatch. Tx id: {}. Want: {}. Have: {}. Gas used: {}",
+        tx_id,
+        tx_expect.gas,
+        gas_remaining,
+        tx_result.gas_used,
+    );
+}
@@ -5,9 +5,11 @@
 pub mod abi_json;
 pub mod api;
 mod contract_map;
+pub mod coverage;
 mod display_util;
 mod managed_test_util;
 mod denali_go_runner;
+mod denali_rs_dir_runner;
 mod denali_rs_runner;
 mod denali_step;
 pub mod meta;
@@ -22,6 +24,7 @@ pub use managed_test_util::*;
 pub use denali_step::*;
 
 pub use denali_go_runner::denali_go;
+pub use denali_rs_dir_runner::*;
 pub use denali_rs_runner::denali_rs;
 pub use tx_mock::DebugApi;
 pub use world_mock::BlockchainMock;
@@ -0,0 +1,106 @@
+use std::collections::BTreeMap;
+
+use sha2::{Digest, Sha256};
+
+use crate::world_mock::BlockInfo;
+
+use super::BlockchainStateWrapper;
+
+const DEFAULT_ROUND_DURATION_SECONDS: u64 = 6;
+const DEFAULT_ROUNDS_PER_EPOCH: u64 = 14400;
+const RANDOM_SEED_LEN: usize = 48;
+const RANDOM_SEED_DOMAIN: &[u8] = b"block random seed";
+
+pub(crate) type BlockHook = Box<dyn FnOnce(&mut BlockchainStateWrapper)>;
+
+/// Produces new blocks for the testing framework,
+/// keeping nonces, rounds, epochs, timestamps and random seeds consistent with each other.
+pub(crate) struct BlockProducer {
+    pub round_duration_seconds: u64,
+    pub rounds_per_epoch: u64,
+    scheduled_hooks: BTreeMap<u64, Vec<BlockHook>>,
+}
+
+impl Default for BlockProducer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BlockProducer {
+    pub fn new() -> Self {
+        Self {
+            round_duration_seconds: DEFAULT_ROUND_DURATION_SECONDS,
+            rounds_per_epoch: DEFAULT_ROUNDS_PER_EPOCH,
+            scheduled_hooks: BTreeMap::new(),
+        }
+    }
+
+    /// Same settings, but the scheduled hooks stay with the original.
+    pub fn fork(&self) -> Self {
+        Self {
+            round_duration_seconds: self.round_duration_seconds,
+            rounds_per_epoch: self.rounds_per_epoch,
+            scheduled_hooks: BTreeMap::new(),
+        }
+    }
+
+    pub fn schedule_hook(&mut self, block_nonce: u64, hook: BlockHook) {
+        self.scheduled_hooks
+            .entry(block_nonce)
+            .or_default()
+            .push(hook);
+    }
+
+    /// Removes and returns all hooks scheduled up to and including the given block, in block order.
+    pub fn take_hooks_up_to(&mut self, block_nonce: u64) -> Vec<BlockHook> {
+        let later_hooks = self
+            .scheduled_hooks
+            .split_off(&block_nonce.saturating_add(1));
+        let due_hooks = core::mem::replace(&mut self.scheduled_hooks, later_hooks);
+        due_hooks.into_values().flatten().collect()
+    }
+
+    /// The block following `current`, produced after the given number of rounds.
+    /// Rounds without a block are simply skipped, as when validators miss their turn.
+    pub fn next_block_info(&self, current: &BlockInfo, num_rounds: u64) -> BlockInfo {
+        assert!(num_rounds > 0, "a new block needs at least one round");
+
+        let block_nonce = current.block_nonce + 1;
+        let block_round = current.block_round + num_rounds;
+        let epochs_crossed =
+            block_round / self.rounds_per_epoch - current.block_round / self.rounds_per_epoch;
+        BlockInfo {
+            block_timestamp: current.block_timestamp + num_rounds * self.round_duration_seconds,
+            block_nonce,
+            block_round,
+            block_epoch: current.block_epoch + epochs_crossed,
+            block_random_seed: random_seed_for_block(block_nonce),
+        }
+    }
+
+    /// Rounds to wait until the first round of the epoch `num_epochs` after the current one.
+    pub fn rounds_until_epoch(&self, current: &BlockInfo, num_epochs: u64) -> u64 {
+        assert!(num_epochs > 0, "must advance at least one epoch");
+
+        let current_epoch_start = current.block_round - current.block_round % self.rounds_per_epoch;
+        current_epoch_start + num_epochs * self.rounds_per_epoch - current.block_round
+    }
+}
+
+/// Only depends on the block nonce, so the same block always gets the same seed, in every test run.
+fn random_seed_for_block(block_nonce: u64) -> Box<[u8; RANDOM_SEED_LEN]> {
+    let mut hasher = Sha256::new();
+    hasher.update(RANDOM_SEED_DOMAIN);
+    hasher.update(block_nonce.to_be_bytes());
+    let first_part: [u8; 32] = hasher.finalize().into();
+
+    let mut hasher = Sha256::new();
+    hasher.update(first_part);
+    let second_part: [u8; 32] = hasher.finalize().into();
+
+    let mut seed = [0u8; RANDOM_SEED_LEN];
+    seed[..32].copy_from_slice(&first_part);
+    seed[32..].copy_from_slice(&second_part[..RANDOM_SEED_LEN - 32]);
+    Box::new(seed)
+}
@@ -13,15 +13,20 @@ use num_traits::Zero;
 use crate::{
     num_bigint,
     testing_framework::raw_converter::bytes_to_hex,
-    tx_execution::{execute_async_call_and_callback, interpret_panic_as_tx_result},
-    tx_mock::{TxCache, TxContext, TxContextStack, TxInput, TxInputDCT, TxResult},
-    world_mock::{is_smart_contract_address, AccountData, AccountDct, DctInstanceMetadata},
+    tx_execution::{execute_result_calls, interpret_panic_as_tx_result},
+    tx_mock::{
+        system_sc_address, TxCache, TxContext, TxContextStack, TxInput, TxInputDCT, TxPanic,
+        TxResult,
+    },
+    world_mock::{
+        is_smart_contract_address, AccountData, AccountDct, DctInstanceMetadata, SystemScTokenData,
+    },
     BlockchainMock, DebugApi,
 };
 
 use super::{
     tx_denali::{ScCallDenali, TxExpectDenali},
-    AddressFactory, DenaliGenerator, ScQueryDenali,
+    AddressFactory, BlockProducer, DenaliGenerator, ScQueryDenali,
 };
 
 pub struct ContractObjWrapper<
@@ -54,6 +59,7 @@ pub struct BlockchainStateWrapper {
     rc_b_mock: Rc<BlockchainMock>,
     address_to_code_path: HashMap<Address, Vec<u8>>,
     denali_generator: DenaliGenerator,
+    block_producer: BlockProducer,
     workspace_path: PathBuf,
 }
 
@@ -68,6 +74,7 @@ impl BlockchainStateWrapper {
             rc_b_mock: Rc::new(BlockchainMock::new()),
             address_to_code_path: HashMap::new(),
             denali_generator: DenaliGenerator::new(),
+            block_producer: BlockProducer::new(),
             workspace_path: current_dir,
         }
     }
@@ -84,6 +91,52 @@ impl BlockchainStateWrapper {
             .write_denali_output(full_path.to_str().unwrap());
     }
 
+    /// Saves the current state of the blockchain, so it can be restored later, any number of times.
+    /// Also recorded in the denali trace, as a `snapshot` step,
+    /// so traces that use snapshots can only be replayed with denali-rs.
+    pub fn snapshot(&mut self) -> String {
+        let b_mock_ref = Rc::get_mut(&mut self.rc_b_mock).unwrap();
+        let id = format!("snapshot-{}", b_mock_ref.snapshots.len() + 1);
+        b_mock_ref.save_named_snapshot(id.as_str());
+
+        self.denali_generator.snapshot(id.as_str());
+        id
+    }
+
+    /// Brings accounts and block info back to how they were when the snapshot was taken.
+    /// Deployed contracts and scheduled block hooks are not affected.
+    pub fn restore(&mut self, snapshot_id: &str) {
+        let b_mock_ref = Rc::get_mut(&mut self.rc_b_mock).unwrap();
+        b_mock_ref.restore_named_snapshot(snapshot_id);
+
+        self.denali_generator.restore_snapshot(snapshot_id);
+    }
+
+    /// An independent copy of the whole world, that can be used to explore a different branch.
+    ///
+    /// The denali trace of the fork starts with the complete current state.
+    /// Snapshots taken before the fork can also be restored in the fork,
+    /// but its trace will then refer to snapshots it does not contain.
+    pub fn fork(&self) -> BlockchainStateWrapper {
+        let mut forked = BlockchainStateWrapper {
+            address_factory: self.address_factory.clone(),
+            rc_b_mock: Rc::new(self.rc_b_mock.fork()),
+            address_to_code_path: self.address_to_code_path.clone(),
+            denali_generator: DenaliGenerator::new(),
+            block_producer: self.block_producer.fork(),
+            workspace_path: self.workspace_path.clone(),
+        };
+
+        let mut addresses: Vec<Address> = forked.rc_b_mock.accounts.keys().cloned().collect();
+        addresses.sort_by(|a, b| a.as_bytes().cmp(b.as_bytes()));
+        for address in &addresses {
+            forked.add_denali_set_account(address);
+        }
+        forked.add_denali_block_info();
+
+        forked
+    }
+
     pub fn check_moax_balance(&self, address: &Address, expected_balance: &num_bigint::BigUint) {
         let actual_balance = match &self.rc_b_mock.accounts.get(address) {
             Some(acc) => acc.moax_balance.clone(),
@@ -325,7 +378,7 @@ impl BlockchainStateWrapper {
 
     pub fn set_moax_balance(&mut self, address: &Address, balance: &num_bigint::BigUint) {
         let b_mock_ref = Rc::get_mut(&mut self.rc_b_mock).unwrap();
-        match b_mock_ref.accounts.get_mut(address) {
+        match b_mock_ref.accounts.get_mut(address).map(Rc::make_mut) {
             Some(acc) => {
                 acc.moax_balance = balance.clone();
 
@@ -346,7 +399,7 @@ impl BlockchainStateWrapper {
         balance: &num_bigint::BigUint,
     ) {
         let b_mock_ref = Rc::get_mut(&mut self.rc_b_mock).unwrap();
-        match b_mock_ref.accounts.get_mut(address) {
+        match b_mock_ref.accounts.get_mut(address).map(Rc::make_mut) {
             Some(acc) => {
                 acc.dct.set_dct_balance(
                     token_id.to_vec(),
@@ -402,7 +455,7 @@ impl BlockchainStateWrapper {
     ) {
         let b_mock_ref = Rc::get_mut(&mut self.rc_b_mock).unwrap();
 
-        match b_mock_ref.accounts.get_mut(address) {
+        match b_mock_ref.accounts.get_mut(address).map(Rc::make_mut) {
             Some(acc) => {
                 acc.dct.set_dct_balance(
                     token_id.to_vec(),
@@ -434,15 +487,17 @@ impl BlockchainStateWrapper {
         roles: &[DctLocalRole],
     ) {
         let b_mock_ref = Rc::get_mut(&mut self.rc_b_mock).unwrap();
-        match b_mock_ref.accounts.get_mut(address) {
+        match b_mock_ref.accounts.get_mut(address).map(Rc::make_mut) {
             Some(acc) => {
                 let mut roles_raw = Vec::new();
                 for role in roles {
                     roles_raw.push(role.as_role_name().to_vec());
                 }
                 acc.dct.set_roles(token_id.to_vec(), roles_raw);
+                b_mock_ref.update_transfer_role_holders(address);
 
                 self.add_denali_set_account(address);
+                self.add_denali_set_account(&system_sc_address());
             },
             None => panic!(
                 "set_dct_local_roles: Account {:?} does not exist",
@@ -451,6 +506,38 @@ impl BlockchainStateWrapper {
         }
     }
 
+    /// Sets the owner of a token that was not issued via the system SC,
+    /// so that it can call the system SC functions on it, e.g. `freeze`.
+    pub fn set_dct_token_owner(&mut self, token_id: &[u8], owner: &Address) {
+        self.update_system_sc_token_data(token_id, |token_data| {
+            token_data.owner = owner.clone();
+        });
+    }
+
+    /// Pauses or unpauses a token, transfers of paused tokens fail.
+    pub fn set_dct_token_paused(&mut self, token_id: &[u8], paused: bool) {
+        self.update_system_sc_token_data(token_id, |token_data| {
+            token_data.paused = paused;
+        });
+    }
+
+    /// Sets a token property by name, e.g. `canFreeze` or `canWipe`.
+    pub fn set_dct_token_property(&mut self, token_id: &[u8], property_name: &[u8], value: bool) {
+        self.update_system_sc_token_data(token_id, |token_data| {
+            token_data.set_property(property_name, value);
+        });
+    }
+
+    fn update_system_sc_token_data<F>(&mut self, token_id: &[u8], f: F)
+    where
+        F: FnOnce(&mut SystemScTokenData),
+    {
+        let b_mock_ref = Rc::get_mut(&mut self.rc_b_mock).unwrap();
+        b_mock_ref.update_system_sc_token_data(token_id, f);
+
+        self.add_denali_set_account(&system_sc_address());
+    }
+
     pub fn set_block_epoch(&mut self, block_epoch: u64) {
         let b_mock_ref = Rc::get_mut(&mut self.rc_b_mock).unwrap();
         b_mock_ref.current_block_info.block_epoch = block_epoch;
@@ -551,6 +638,100 @@ impl BlockchainStateWrapper {
         );
     }
 
+    /// Used to compute the timestamps of the blocks produced by the `advance_*` methods. Defaults to 6 seconds.
+    pub fn set_round_duration_seconds(&mut self, round_duration_seconds: u64) {
+        self.block_producer.round_duration_seconds = round_duration_seconds;
+    }
+
+    /// Used to compute the epochs of the blocks produced by the `advance_*` methods. Defaults to 14400.
+    pub fn set_rounds_per_epoch(&mut self, rounds_per_epoch: u64) {
+        assert!(rounds_per_epoch > 0, "rounds per epoch cannot be zero");
+        self.block_producer.rounds_per_epoch = rounds_per_epoch;
+    }
+
+    /// Produces the next block, one round after the current one.
+    pub fn produce_block(&mut self) {
+        self.advance_blocks(1);
+    }
+
+    /// Produces `num_blocks` consecutive blocks, one per round.
+    ///
+    /// Each time, the current block info becomes the previous block info,
+    /// and the new block gets a timestamp, epoch and random seed consistent with its round and nonce.
+    pub fn advance_blocks(&mut self, num_blocks: u64) {
+        let mut block_info_traced = false;
+        for _ in 0..num_blocks {
+            block_info_traced = self.produce_block_after_rounds(1);
+        }
+        if !block_info_traced {
+            self.add_denali_block_info();
+        }
+    }
+
+    /// Produces a single block, `num_rounds` rounds after the current one.
+    /// The rounds in between produce no blocks.
+    pub fn advance_rounds(&mut self, num_rounds: u64) {
+        if !self.produce_block_after_rounds(num_rounds) {
+            self.add_denali_block_info();
+        }
+    }
+
+    /// Produces a single block, in the first round of the epoch `num_epochs` after the current one.
+    pub fn advance_epochs(&mut self, num_epochs: u64) {
+        let num_rounds = self
+            .block_producer
+            .rounds_until_epoch(&self.rc_b_mock.current_block_info, num_epochs);
+        self.advance_rounds(num_rounds);
+    }
+
+    /// Runs the closure right after the block with the given nonce is produced by one of the `advance_*` methods.
+    /// If these methods skip over it (e.g. after a `set_block_nonce`), the closure runs at the first block after it.
+    pub fn schedule_at_block<F>(&mut self, block_nonce: u64, f: F)
+    where
+        F: FnOnce(&mut BlockchainStateWrapper) + 'static,
+    {
+        let current_nonce = self.rc_b_mock.current_block_info.block_nonce;
+        assert!(
+            block_nonce > current_nonce,
+            "schedule_at_block: block {} already produced, current block is {}",
+            block_nonce,
+            current_nonce
+        );
+
+        self.block_producer.schedule_hook(block_nonce, Box::new(f));
+    }
+
+    /// Returns true if the new block info was already added to the denali trace,
+    /// which happens before running any hooks, so the transactions they perform are traced in the right block.
+    fn produce_block_after_rounds(&mut self, num_rounds: u64) -> bool {
+        let b_mock_ref = Rc::get_mut(&mut self.rc_b_mock).unwrap();
+        let next_block_info = self
+            .block_producer
+            .next_block_info(&b_mock_ref.current_block_info, num_rounds);
+        b_mock_ref.previous_block_info =
+            std::mem::replace(&mut b_mock_ref.current_block_info, next_block_info);
+
+        let hooks = self
+            .block_producer
+            .take_hooks_up_to(self.rc_b_mock.current_block_info.block_nonce);
+        if hooks.is_empty() {
+            return false;
+        }
+
+        self.add_denali_block_info();
+        for hook in hooks {
+            hook(self);
+        }
+        true
+    }
+
+    fn add_denali_block_info(&mut self) {
+        self.denali_generator.set_block_info(
+            &self.rc_b_mock.current_block_info,
+            &self.rc_b_mock.previous_block_info,
+        );
+    }
+
     pub fn add_denali_sc_call(
         &mut self,
         sc_call: ScCallDenali,
@@ -597,7 +778,15 @@ impl BlockchainStateWrapper {
         ContractObjBuilder: 'static + Copy + Fn() -> CB,
         TxFn: FnOnce(CB),
     {
-        self.execute_tx_any(caller, sc_wrapper, moax_payment, Vec::new(), tx_fn)
+        self.execute_tx_any(
+            caller,
+            sc_wrapper,
+            moax_payment,
+            Vec::new(),
+            &[],
+            Vec::new(),
+            tx_fn,
+        )
     }
 
     pub fn execute_dct_transfer<CB, ContractObjBuilder, TxFn>(
@@ -624,6 +813,8 @@ impl BlockchainStateWrapper {
             sc_wrapper,
             &num_bigint::BigUint::zero(),
             dct_transfer,
+            &[],
+            Vec::new(),
             tx_fn,
         )
     }
@@ -644,6 +835,8 @@ impl BlockchainStateWrapper {
             sc_wrapper,
             &num_bigint::BigUint::zero(),
             dct_transfers.to_vec(),
+            &[],
+            Vec::new(),
             tx_fn,
         )
     }
@@ -665,13 +858,57 @@ impl BlockchainStateWrapper {
         )
     }
 
+    /// Calls an endpoint by name, with already serialized arguments, the way a real transaction would.
+    /// Unlike `execute_tx`, the result also contains the values returned by the endpoint.
+    #[allow(clippy::too_many_arguments)]
+    pub fn execute_raw_call<CB, ContractObjBuilder>(
+        &mut self,
+        caller: &Address,
+        sc_wrapper: &ContractObjWrapper<CB, ContractObjBuilder>,
+        moax_payment: &num_bigint::BigUint,
+        dct_payments: &[TxInputDCT],
+        endpoint_name: &str,
+        args: &[Vec<u8>],
+    ) -> TxResult
+    where
+        CB: ContractBase<Api = DebugApi> + CallableContract + 'static,
+        ContractObjBuilder: 'static + Copy + Fn() -> CB,
+    {
+        let mut result_values = Vec::new();
+        let mut tx_result = self.execute_tx_any(
+            caller,
+            sc_wrapper,
+            moax_payment,
+            dct_payments.to_vec(),
+            endpoint_name.as_bytes(),
+            args.to_vec(),
+            |sc| {
+                if !sc.call(endpoint_name.as_bytes()) {
+                    std::panic::panic_any(TxPanic {
+                        status: 1,
+                        message: "invalid function (not found)".to_string(),
+                    });
+                }
+                result_values = DebugApi::new_from_static().into_tx_result().result_values;
+            },
+        );
+        if tx_result.result_status == 0 {
+            tx_result.result_values = result_values;
+        }
+
+        tx_result
+    }
+
     // deduplicates code for execution
+    #[allow(clippy::too_many_arguments)]
     fn execute_tx_any<CB, ContractObjBuilder, TxFn: FnOnce(CB)>(
         &mut self,
         caller: &Address,
         sc_wrapper: &ContractObjWrapper<CB, ContractObjBuilder>,
         moax_payment: &num_bigint::BigUint,
         dct_payments: Vec<TxInputDCT>,
+        func_name: &[u8],
+        args: Vec<Vec<u8>>,
         tx_fn: TxFn,
     ) -> TxResult
     where
@@ -682,6 +919,10 @@ impl BlockchainStateWrapper {
         let tx_cache = TxCache::new(self.rc_b_mock.clone());
         let rust_zero = num_bigint::BigUint::zero();
 
+        if let Err(message) = tx_cache.check_dct_transfers(caller, sc_address, &dct_payments) {
+            return TxResult::from_vm_error(message);
+        }
+
         if moax_payment > &rust_zero {
             tx_cache.subtract_moax_balance(caller, moax_payment);
             tx_cache.increase_moax_balance(sc_address, moax_payment);
@@ -705,7 +946,14 @@ impl BlockchainStateWrapper {
             }
         }
 
-        let tx_input = build_tx_input(caller, sc_address, moax_payment, dct_payments);
+        let tx_input = build_tx_input(
+            caller,
+            sc_address,
+            moax_payment,
+            dct_payments,
+            func_name,
+            args,
+        );
         let tx_context_rc = Rc::new(TxContext::new(tx_input, tx_cache));
         TxContextStack::static_push(tx_context_rc);
 
@@ -728,13 +976,11 @@ impl BlockchainStateWrapper {
             updates.apply(b_mock_ref);
         }
         if is_successful_tx {
-            if let Some(async_data) = &tx_result.result_calls.async_call {
-                let b_mock_ref = Rc::get_mut(&mut self.rc_b_mock).unwrap();
-                b_mock_ref.with_borrowed(|state| {
-                    let (_, _, state) = execute_async_call_and_callback(async_data.clone(), state);
-                    ((), state)
-                });
-            }
+            let b_mock_ref = Rc::get_mut(&mut self.rc_b_mock).unwrap();
+            b_mock_ref.with_borrowed(|state| {
+                let (_, state) = execute_result_calls(tx_result.result_calls.clone(), state);
+                ((), state)
+            });
         }
 
         tx_result
@@ -875,14 +1121,16 @@ fn build_tx_input(
     dest: &Address,
     moax_value: &num_bigint::BigUint,
     dct_values: Vec<TxInputDCT>,
+    func_name: &[u8],
+    args: Vec<Vec<u8>>,
 ) -> TxInput {
     TxInput {
         from: caller.clone(),
         to: dest.clone(),
         moax_value: moax_value.clone(),
         dct_values,
-        func_name: Vec::new(),
-        args: Vec::new(),
+        func_name: func_name.to_vec(),
+        args,
         gas_limit: u64::MAX,
         gas_price: 0,
         tx_hash: H256::zero(),
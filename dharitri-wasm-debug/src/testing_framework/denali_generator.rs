@@ -18,6 +18,8 @@ impl DenaliGenerator {
                 check_gas: None,
                 comment: None,
                 gas_schedule: None,
+                abi: None,
+                tags: Vec::new(),
                 name: None,
                 steps: Vec::new(),
             },
@@ -55,6 +57,7 @@ impl DenaliGenerator {
 
         let step = StepRaw::SetState {
             accounts: accounts_raw,
+            dct_tokens: BTreeMap::new(),
             block_hashes: Vec::new(),
             new_addresses: Vec::new(),
             comment: None,
@@ -70,6 +73,7 @@ impl DenaliGenerator {
 
         let step = StepRaw::SetState {
             accounts: BTreeMap::new(),
+            dct_tokens: BTreeMap::new(),
             block_hashes: Vec::new(),
             new_addresses: Vec::new(),
             comment: None,
@@ -123,4 +127,20 @@ impl DenaliGenerator {
         };
         self.add_step(step);
     }
+
+    pub fn snapshot(&mut self, id: &str) {
+        let step = StepRaw::Snapshot {
+            comment: None,
+            id: id.to_string(),
+        };
+        self.add_step(step);
+    }
+
+    pub fn restore_snapshot(&mut self, id: &str) {
+        let step = StepRaw::RestoreSnapshot {
+            comment: None,
+            id: id.to_string(),
+        };
+        self.add_step(step);
+    }
 }
@@ -0,0 +1,432 @@
+use std::fmt;
+
+use dharitri_wasm::{
+    abi::{ContractAbi, EndpointAbi, EndpointMutabilityAbi},
+    contract_base::{CallableContract, ContractBase},
+    types::heap::Address,
+};
+use num_traits::{ToPrimitive, Zero};
+use rand::Rng;
+use rand_seeder::{SipHasher, SipRng};
+
+use crate::{
+    num_bigint,
+    testing_framework::{BlockchainStateWrapper, ContractObjWrapper, ScCallDenali, TxExpectDenali},
+    tx_mock::{TxInputDCT, TxResult},
+    DebugApi,
+};
+
+use super::{FuzzCall, FuzzValueGenerator};
+
+const DEFAULT_NUM_RUNS: usize = 16;
+const DEFAULT_CALLS_PER_RUN: usize = 32;
+const DEFAULT_MAX_PAYMENT: u64 = 1000;
+
+/// The world a fuzz run starts from. The setup function rebuilds it from scratch for every run,
+/// as well as for every attempt to shrink a failing sequence.
+pub struct FuzzWorld<CB, ContractObjBuilder>
+where
+    CB: ContractBase<Api = DebugApi> + CallableContract + 'static,
+    ContractObjBuilder: 'static + Copy + Fn() -> CB,
+{
+    pub wrapper: BlockchainStateWrapper,
+    pub sc_wrapper: ContractObjWrapper<CB, ContractObjBuilder>,
+    /// The accounts that call the contract, chosen at random for each call.
+    pub callers: Vec<Address>,
+}
+
+type Invariant<CB, ContractObjBuilder> =
+    Box<dyn Fn(&mut FuzzWorld<CB, ContractObjBuilder>) -> bool>;
+
+/// Calls the endpoints of a contract at random, with random arguments, callers and payments,
+/// checking the invariants after each call.
+///
+/// When an invariant breaks, the sequence of calls is shrunk to the minimum that still breaks it,
+/// and optionally saved as a denali scenario, so it can be investigated and kept as a regression test.
+pub struct ContractFuzzer<CB, ContractObjBuilder>
+where
+    CB: ContractBase<Api = DebugApi> + CallableContract + 'static,
+    ContractObjBuilder: 'static + Copy + Fn() -> CB,
+{
+    abi: ContractAbi,
+    setup: Box<dyn Fn() -> FuzzWorld<CB, ContractObjBuilder>>,
+    invariants: Vec<(String, Invariant<CB, ContractObjBuilder>)>,
+    seed: u64,
+    num_runs: usize,
+    calls_per_run: usize,
+    max_payment: u64,
+    token_ids: Vec<Vec<u8>>,
+    included_endpoints: Vec<String>,
+    excluded_endpoints: Vec<String>,
+    denali_output: Option<String>,
+}
+
+/// A minimal sequence of calls that breaks an invariant.
+#[derive(Debug)]
+pub struct FuzzFailure {
+    pub invariant_name: String,
+    pub seed: u64,
+    pub run_index: usize,
+    /// Length of the sequence before shrinking.
+    pub original_len: usize,
+    pub calls: Vec<FuzzCall>,
+}
+
+impl fmt::Display for FuzzFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "invariant `{}` broken in run {} (seed {}), after {} calls, shrunk to {}:",
+            self.invariant_name,
+            self.run_index,
+            self.seed,
+            self.original_len,
+            self.calls.len()
+        )?;
+        for call in &self.calls {
+            writeln!(f, "    {}", call)?;
+        }
+        Ok(())
+    }
+}
+
+impl<CB, ContractObjBuilder> ContractFuzzer<CB, ContractObjBuilder>
+where
+    CB: ContractBase<Api = DebugApi> + CallableContract + 'static,
+    ContractObjBuilder: 'static + Copy + Fn() -> CB,
+{
+    pub fn new<S>(abi: ContractAbi, setup: S) -> Self
+    where
+        S: Fn() -> FuzzWorld<CB, ContractObjBuilder> + 'static,
+    {
+        ContractFuzzer {
+            abi,
+            setup: Box::new(setup),
+            invariants: Vec::new(),
+            seed: 0,
+            num_runs: DEFAULT_NUM_RUNS,
+            calls_per_run: DEFAULT_CALLS_PER_RUN,
+            max_payment: DEFAULT_MAX_PAYMENT,
+            token_ids: Vec::new(),
+            included_endpoints: Vec::new(),
+            excluded_endpoints: Vec::new(),
+            denali_output: None,
+        }
+    }
+
+    /// Checked after the setup and after every call. Returns false if broken.
+    #[must_use]
+    pub fn invariant<F>(mut self, name: &str, f: F) -> Self
+    where
+        F: Fn(&mut FuzzWorld<CB, ContractObjBuilder>) -> bool + 'static,
+    {
+        self.invariants.push((name.to_string(), Box::new(f)));
+        self
+    }
+
+    /// The same seed always generates the same calls.
+    #[must_use]
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    #[must_use]
+    pub fn num_runs(mut self, num_runs: usize) -> Self {
+        self.num_runs = num_runs;
+        self
+    }
+
+    #[must_use]
+    pub fn calls_per_run(mut self, calls_per_run: usize) -> Self {
+        self.calls_per_run = calls_per_run;
+        self
+    }
+
+    /// Payments are also limited by the balance of the caller.
+    #[must_use]
+    pub fn max_payment(mut self, max_payment: u64) -> Self {
+        self.max_payment = max_payment;
+        self
+    }
+
+    /// Fungible token used for payments and for `TokenIdentifier` arguments.
+    #[must_use]
+    pub fn token_id(mut self, token_id: &[u8]) -> Self {
+        self.token_ids.push(token_id.to_vec());
+        self
+    }
+
+    /// If any endpoints are included, only those are called.
+    /// Otherwise, all endpoints except the views are.
+    #[must_use]
+    pub fn include_endpoint(mut self, endpoint_name: &str) -> Self {
+        self.included_endpoints.push(endpoint_name.to_string());
+        self
+    }
+
+    #[must_use]
+    pub fn exclude_endpoint(mut self, endpoint_name: &str) -> Self {
+        self.excluded_endpoints.push(endpoint_name.to_string());
+        self
+    }
+
+    /// File name, in the denali folder of the testing framework, where the shrunk sequence is saved when an invariant breaks.
+    #[must_use]
+    pub fn denali_output(mut self, file_name: &str) -> Self {
+        self.denali_output = Some(file_name.to_string());
+        self
+    }
+
+    /// Panics with the shrunk sequence of calls if any invariant breaks.
+    pub fn run(&self) {
+        if let Some(failure) = self.find_failure() {
+            if let Some(file_name) = &self.denali_output {
+                self.write_denali_scenario(failure.calls.as_slice(), file_name.as_str());
+            }
+            panic!("{}", failure);
+        }
+    }
+
+    pub fn find_failure(&self) -> Option<FuzzFailure> {
+        let endpoints = self.fuzzed_endpoints();
+        assert!(!endpoints.is_empty(), "no endpoints to fuzz");
+
+        let mut rng = SipHasher::from(self.seed).into_rng();
+        for run_index in 0..self.num_runs {
+            let mut world = (self.setup)();
+            let mut calls = Vec::new();
+            let mut opt_broken = self.broken_invariant(&mut world);
+            while opt_broken.is_none() && calls.len() < self.calls_per_run {
+                let endpoint = endpoints[rng.gen_range(0..endpoints.len())];
+                let call = self.random_call(&mut rng, endpoint, &mut world);
+                let _ = execute_call(&mut world, &call);
+                calls.push(call);
+                opt_broken = self.broken_invariant(&mut world);
+            }
+
+            if let Some(invariant_name) = opt_broken {
+                let original_len = calls.len();
+                return Some(FuzzFailure {
+                    calls: self.shrink(calls, invariant_name.as_str()),
+                    invariant_name,
+                    seed: self.seed,
+                    run_index,
+                    original_len,
+                });
+            }
+        }
+        None
+    }
+
+    fn fuzzed_endpoints(&self) -> Vec<&EndpointAbi> {
+        self.abi
+            .endpoints
+            .iter()
+            .filter(|endpoint| {
+                if self.included_endpoints.is_empty() {
+                    matches!(endpoint.mutability, EndpointMutabilityAbi::Mutable)
+                } else {
+                    self.included_endpoints
+                        .iter()
+                        .any(|name| name == endpoint.name)
+                }
+            })
+            .filter(|endpoint| {
+                !self
+                    .excluded_endpoints
+                    .iter()
+                    .any(|name| name == endpoint.name)
+            })
+            .collect()
+    }
+
+    fn broken_invariant(&self, world: &mut FuzzWorld<CB, ContractObjBuilder>) -> Option<String> {
+        self.invariants
+            .iter()
+            .find(|(_, invariant)| !invariant(world))
+            .map(|(name, _)| name.clone())
+    }
+
+    fn random_call(
+        &self,
+        rng: &mut SipRng,
+        endpoint: &EndpointAbi,
+        world: &mut FuzzWorld<CB, ContractObjBuilder>,
+    ) -> FuzzCall {
+        let caller_index = rng.gen_range(0..world.callers.len());
+        let caller = &world.callers[caller_index];
+
+        let mut addresses = world.callers.clone();
+        addresses.push(world.sc_wrapper.address_ref().clone());
+        let value_generator = FuzzValueGenerator {
+            type_descriptions: &self.abi.type_descriptions,
+            addresses: addresses.as_slice(),
+            token_ids: self.token_ids.as_slice(),
+        };
+        let args = value_generator.endpoint_args(rng, endpoint);
+
+        let mut moax_value = num_bigint::BigUint::zero();
+        let mut dct_payments = Vec::new();
+        let payment_options = self.payment_options(endpoint);
+        if !payment_options.is_empty() && rng.gen_bool(0.5) {
+            match payment_options[rng.gen_range(0..payment_options.len())] {
+                None => {
+                    let balance = world.wrapper.get_moax_balance(caller);
+                    moax_value = self.random_amount(rng, &balance);
+                },
+                Some(token_id) => {
+                    let balance = world.wrapper.get_dct_balance(caller, token_id, 0);
+                    dct_payments.push(TxInputDCT {
+                        token_identifier: token_id.to_vec(),
+                        nonce: 0,
+                        value: self.random_amount(rng, &balance),
+                    });
+                },
+            }
+        }
+
+        FuzzCall {
+            caller_index,
+            endpoint_name: endpoint.name.to_string(),
+            args,
+            moax_value,
+            dct_payments,
+        }
+    }
+
+    /// `None` stands for MOAX.
+    fn payment_options(&self, endpoint: &EndpointAbi) -> Vec<Option<&[u8]>> {
+        let accepts = |token: &[u8]| {
+            endpoint
+                .payable_in_tokens
+                .iter()
+                .any(|payable| *payable == "*" || payable.as_bytes() == token)
+        };
+        let mut options = Vec::new();
+        if accepts(b"MOAX") {
+            options.push(None);
+        }
+        for token_id in &self.token_ids {
+            if accepts(token_id.as_slice()) {
+                options.push(Some(token_id.as_slice()));
+            }
+        }
+        options
+    }
+
+    fn random_amount(
+        &self,
+        rng: &mut SipRng,
+        balance: &num_bigint::BigUint,
+    ) -> num_bigint::BigUint {
+        let max = balance.to_u64().unwrap_or(u64::MAX).min(self.max_payment);
+        num_bigint::BigUint::from(rng.gen_range(0..=max))
+    }
+
+    /// Removes calls, then payments, as long as the same invariant still breaks.
+    fn shrink(&self, mut calls: Vec<FuzzCall>, invariant_name: &str) -> Vec<FuzzCall> {
+        loop {
+            let len_before = calls.len();
+            let mut index = calls.len();
+            while index > 0 {
+                index -= 1;
+                let mut candidate = calls.clone();
+                candidate.remove(index);
+                if self.reproduces(candidate.as_slice(), invariant_name) {
+                    calls = candidate;
+                }
+            }
+            if calls.len() == len_before {
+                break;
+            }
+        }
+
+        for index in 0..calls.len() {
+            if calls[index].moax_value.is_zero() && calls[index].dct_payments.is_empty() {
+                continue;
+            }
+            let mut candidate = calls.clone();
+            candidate[index].moax_value = num_bigint::BigUint::zero();
+            candidate[index].dct_payments.clear();
+            if self.reproduces(candidate.as_slice(), invariant_name) {
+                calls = candidate;
+            }
+        }
+        calls
+    }
+
+    fn reproduces(&self, calls: &[FuzzCall], invariant_name: &str) -> bool {
+        let mut world = (self.setup)();
+        for call in calls {
+            if execute_call(&mut world, call).is_none() {
+                return false;
+            }
+            if let Some(broken_name) = self.broken_invariant(&mut world) {
+                return broken_name == invariant_name;
+            }
+        }
+        false
+    }
+
+    /// Replays the calls in a fresh world, recording them, with their actual outcome as expected outcome.
+    fn write_denali_scenario(&self, calls: &[FuzzCall], file_name: &str) {
+        let mut world = (self.setup)();
+        for call in calls {
+            let tx_result = execute_call(&mut world, call)
+                .expect("shrunk sequence of calls no longer affordable");
+
+            let mut sc_call = ScCallDenali::new(
+                &world.callers[call.caller_index],
+                world.sc_wrapper.address_ref(),
+                call.endpoint_name.as_str(),
+            );
+            sc_call.add_moax_value(&call.moax_value);
+            for payment in &call.dct_payments {
+                sc_call.add_dct_transfer(&payment.token_identifier, payment.nonce, &payment.value);
+            }
+            sc_call.arguments = call.args.clone();
+
+            let mut expect = TxExpectDenali::new(tx_result.result_status);
+            expect.out = tx_result.result_values;
+            expect.set_message(tx_result.result_message.as_str());
+            world.wrapper.add_denali_sc_call(sc_call, Some(expect));
+        }
+        world.wrapper.write_denali_output(file_name);
+    }
+}
+
+/// Returns `None` without executing anything if the caller cannot afford the payments,
+/// which can happen when replaying a shrunk sequence.
+fn execute_call<CB, ContractObjBuilder>(
+    world: &mut FuzzWorld<CB, ContractObjBuilder>,
+    call: &FuzzCall,
+) -> Option<TxResult>
+where
+    CB: ContractBase<Api = DebugApi> + CallableContract + 'static,
+    ContractObjBuilder: 'static + Copy + Fn() -> CB,
+{
+    let caller = &world.callers[call.caller_index];
+    if world.wrapper.get_moax_balance(caller) < call.moax_value {
+        return None;
+    }
+    for payment in &call.dct_payments {
+        let balance = world.wrapper.get_dct_balance(
+            caller,
+            payment.token_identifier.as_slice(),
+            payment.nonce,
+        );
+        if balance < payment.value {
+            return None;
+        }
+    }
+
+    Some(world.wrapper.execute_raw_call(
+        caller,
+        &world.sc_wrapper,
+        &call.moax_value,
+        call.dct_payments.as_slice(),
+        call.endpoint_name.as_str(),
+        call.args.as_slice(),
+    ))
+}
@@ -0,0 +1,44 @@
+use std::fmt;
+
+use crate::{num_bigint, tx_mock::TxInputDCT};
+
+/// A randomly generated endpoint call, kept so failing sequences can be replayed and shrunk.
+#[derive(Clone, Debug)]
+pub struct FuzzCall {
+    /// Index in the callers of the fuzz world.
+    pub caller_index: usize,
+    pub endpoint_name: String,
+    pub args: Vec<Vec<u8>>,
+    pub moax_value: num_bigint::BigUint,
+    pub dct_payments: Vec<TxInputDCT>,
+}
+
+impl fmt::Display for FuzzCall {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let args: Vec<String> = self
+            .args
+            .iter()
+            .map(|arg| format!("0x{}", hex::encode(arg)))
+            .collect();
+        write!(
+            f,
+            "caller #{} calls {}({})",
+            self.caller_index,
+            self.endpoint_name,
+            args.join(", ")
+        )?;
+        if self.moax_value != num_bigint::BigUint::default() {
+            write!(f, " paying {} MOAX", self.moax_value)?;
+        }
+        for payment in &self.dct_payments {
+            write!(
+                f,
+                " paying {} {}-{}",
+                payment.value,
+                String::from_utf8_lossy(payment.token_identifier.as_slice()),
+                payment.nonce
+            )?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,285 @@
+use denali::abi::type_name_util::*;
+use dharitri_wasm::{
+    abi::{EndpointAbi, TypeContents, TypeDescriptionContainerImpl},
+    types::heap::Address,
+};
+use rand::Rng;
+use rand_seeder::SipRng;
+
+const MAX_LIST_LEN: usize = 3;
+const MAX_BYTES_LEN: usize = 8;
+const MAX_BIG_NUM_LEN: usize = 16;
+
+/// Generates random serialized arguments, driven by the type descriptions in the contract ABI.
+///
+/// Values are biased towards the edge cases (zero, one, all bits set),
+/// addresses and token identifiers are mostly picked from the ones known to the test.
+pub(crate) struct FuzzValueGenerator<'a> {
+    pub type_descriptions: &'a TypeDescriptionContainerImpl,
+    pub addresses: &'a [Address],
+    pub token_ids: &'a [Vec<u8>],
+}
+
+impl<'a> FuzzValueGenerator<'a> {
+    /// One top-encoded value for each argument, with the multi-values flattened.
+    pub fn endpoint_args(&self, rng: &mut SipRng, endpoint: &EndpointAbi) -> Vec<Vec<u8>> {
+        let mut args = Vec::new();
+        for input in &endpoint.inputs {
+            if !self.multi_value_args(rng, input.type_name.as_str(), &mut args) {
+                // optional arguments can only be left out at the end
+                break;
+            }
+        }
+        args
+    }
+
+    /// Returns false if an optional argument was left out.
+    fn multi_value_args(&self, rng: &mut SipRng, type_name: &str, args: &mut Vec<Vec<u8>>) -> bool {
+        match split_generic_type_name(type_name) {
+            Some(("variadic", type_args)) => {
+                for _ in 0..rng.gen_range(0..=MAX_LIST_LEN) {
+                    self.multi_value_args(rng, type_args[0], args);
+                }
+                true
+            },
+            Some(("counted-variadic", type_args)) => {
+                let count = rng.gen_range(0..=MAX_LIST_LEN);
+                args.push(top_encode_unsigned(&(count as u32).to_be_bytes()));
+                for _ in 0..count {
+                    self.multi_value_args(rng, type_args[0], args);
+                }
+                true
+            },
+            Some(("optional", type_args)) => {
+                if rng.gen_bool(0.5) {
+                    return false;
+                }
+                self.multi_value_args(rng, type_args[0], args)
+            },
+            Some(("multi", type_args)) => {
+                for type_arg in type_args {
+                    self.multi_value_args(rng, type_arg, args);
+                }
+                true
+            },
+            _ => {
+                args.push(self.top_value(rng, type_name));
+                true
+            },
+        }
+    }
+
+    pub fn top_value(&self, rng: &mut SipRng, type_name: &str) -> Vec<u8> {
+        if let Some((width, signed)) = fixed_width_num_type(type_name) {
+            let bytes = random_num_bytes(rng, width);
+            return top_encode_num(bytes.as_slice(), signed);
+        }
+        match type_name {
+            "BigUint" => return self.big_num_top(rng, false),
+            "BigInt" => return self.big_num_top(rng, true),
+            "bool" => {
+                return if rng.gen_bool(0.5) {
+                    vec![1]
+                } else {
+                    Vec::new()
+                }
+            },
+            _ => {},
+        }
+        if is_bytes_type(type_name) {
+            return self.bytes_value(rng, type_name);
+        }
+
+        match split_generic_type_name(type_name) {
+            Some(("Option", type_args)) => {
+                if rng.gen_bool(0.5) {
+                    return Vec::new();
+                }
+                let mut result = vec![1u8];
+                self.nested_value(rng, type_args[0], &mut result);
+                result
+            },
+            Some(("List", type_args)) => {
+                let mut result = Vec::new();
+                for _ in 0..rng.gen_range(0..=MAX_LIST_LEN) {
+                    self.nested_value(rng, type_args[0], &mut result);
+                }
+                result
+            },
+            _ => {
+                if let Some(TypeContents::Enum(variants)) = self.type_contents(type_name) {
+                    let variant = &variants[rng.gen_range(0..variants.len())];
+                    if variant.fields.is_empty() {
+                        return top_encode_unsigned(&[variant.discriminant as u8]);
+                    }
+                }
+                let mut result = Vec::new();
+                self.nested_value(rng, type_name, &mut result);
+                result
+            },
+        }
+    }
+
+    pub fn nested_value(&self, rng: &mut SipRng, type_name: &str, dest: &mut Vec<u8>) {
+        if let Some((width, _)) = fixed_width_num_type(type_name) {
+            dest.extend_from_slice(random_num_bytes(rng, width).as_slice());
+            return;
+        }
+        match type_name {
+            "BigUint" | "BigInt" => {
+                let bytes = self.big_num_top(rng, type_name == "BigInt");
+                push_length_prefixed(dest, bytes.as_slice());
+                return;
+            },
+            "bool" => {
+                dest.push(rng.gen_bool(0.5) as u8);
+                return;
+            },
+            "()" => return,
+            _ => {},
+        }
+        if is_bytes_type(type_name) {
+            let bytes = self.bytes_value(rng, type_name);
+            push_length_prefixed(dest, bytes.as_slice());
+            return;
+        }
+        if is_hash_type(type_name) {
+            dest.extend_from_slice(self.hash_value(rng, type_name).as_slice());
+            return;
+        }
+
+        if let Some((base_name, type_args)) = split_generic_type_name(type_name) {
+            self.nested_generic_value(rng, base_name, type_args.as_slice(), dest);
+            return;
+        }
+
+        match self.type_contents(type_name) {
+            Some(TypeContents::Struct(fields)) => {
+                for field in fields {
+                    self.nested_value(rng, field.field_type.as_str(), dest);
+                }
+            },
+            Some(TypeContents::Enum(variants)) => {
+                let variant = &variants[rng.gen_range(0..variants.len())];
+                dest.push(variant.discriminant as u8);
+                for field in &variant.fields {
+                    self.nested_value(rng, field.field_type.as_str(), dest);
+                }
+            },
+            // unknown types get a few random bytes, which the contract will most likely reject
+            _ => dest.extend_from_slice(random_bytes(rng).as_slice()),
+        }
+    }
+
+    fn nested_generic_value(
+        &self,
+        rng: &mut SipRng,
+        base_name: &str,
+        type_args: &[&str],
+        dest: &mut Vec<u8>,
+    ) {
+        if base_name == "Option" {
+            if rng.gen_bool(0.5) {
+                dest.push(0);
+            } else {
+                dest.push(1);
+                self.nested_value(rng, type_args[0], dest);
+            }
+        } else if base_name == "List" {
+            let len = rng.gen_range(0..=MAX_LIST_LEN);
+            dest.extend_from_slice(&(len as u32).to_be_bytes()[..]);
+            for _ in 0..len {
+                self.nested_value(rng, type_args[0], dest);
+            }
+        } else if let Some(len) = array_length(base_name) {
+            for _ in 0..len {
+                self.nested_value(rng, type_args[0], dest);
+            }
+        } else if base_name == "tuple" {
+            for type_arg in type_args {
+                self.nested_value(rng, type_arg, dest);
+            }
+        } else {
+            dest.extend_from_slice(random_bytes(rng).as_slice());
+        }
+    }
+
+    fn type_contents(&self, type_name: &str) -> Option<&TypeContents> {
+        self.type_descriptions
+            .0
+            .get(type_name)
+            .map(|type_description| &type_description.contents)
+    }
+
+    fn big_num_top(&self, rng: &mut SipRng, signed: bool) -> Vec<u8> {
+        let width = rng.gen_range(1..=MAX_BIG_NUM_LEN);
+        top_encode_num(random_num_bytes(rng, width).as_slice(), signed)
+    }
+
+    fn bytes_value(&self, rng: &mut SipRng, type_name: &str) -> Vec<u8> {
+        if type_name == "TokenIdentifier" && !self.token_ids.is_empty() && rng.gen_bool(0.9) {
+            return self.token_ids[rng.gen_range(0..self.token_ids.len())].clone();
+        }
+        let len = rng.gen_range(0..=MAX_BYTES_LEN);
+        (0..len).map(|_| rng.gen_range(b'a'..=b'z')).collect()
+    }
+
+    fn hash_value(&self, rng: &mut SipRng, type_name: &str) -> Vec<u8> {
+        if type_name == "Address" && !self.addresses.is_empty() && rng.gen_bool(0.9) {
+            return self.addresses[rng.gen_range(0..self.addresses.len())]
+                .as_bytes()
+                .to_vec();
+        }
+        let mut bytes = vec![0u8; 32];
+        rng.fill(&mut bytes[..]);
+        bytes
+    }
+}
+
+/// Big endian, biased towards zero, small numbers and all bits set (maximum, or -1 if signed).
+fn random_num_bytes(rng: &mut SipRng, width: usize) -> Vec<u8> {
+    let mut bytes = vec![0u8; width];
+    match rng.gen_range(0..4) {
+        0 => {},
+        1 => bytes[width - 1] = rng.gen_range(1..=100),
+        2 => bytes.iter_mut().for_each(|b| *b = 0xff),
+        _ => rng.fill(&mut bytes[..]),
+    }
+    bytes
+}
+
+fn random_bytes(rng: &mut SipRng) -> Vec<u8> {
+    let mut bytes = vec![0u8; rng.gen_range(0..=MAX_BYTES_LEN)];
+    rng.fill(&mut bytes[..]);
+    bytes
+}
+
+/// Strips the redundant leading bytes, the way top-encoding does it.
+fn top_encode_num(bytes: &[u8], signed: bool) -> Vec<u8> {
+    if !signed {
+        return top_encode_unsigned(bytes);
+    }
+    let mut start = 0;
+    while start < bytes.len() {
+        let redundant = match bytes[start] {
+            0x00 => start + 1 == bytes.len() || bytes[start + 1] & 0x80 == 0,
+            0xff => start + 1 < bytes.len() && bytes[start + 1] & 0x80 != 0,
+            _ => false,
+        };
+        if !redundant {
+            break;
+        }
+        start += 1;
+    }
+    bytes[start..].to_vec()
+}
+
+fn top_encode_unsigned(bytes: &[u8]) -> Vec<u8> {
+    let first_non_zero = bytes.iter().position(|b| *b != 0).unwrap_or(bytes.len());
+    bytes[first_non_zero..].to_vec()
+}
+
+fn push_length_prefixed(dest: &mut Vec<u8>, bytes: &[u8]) {
+    dest.extend_from_slice(&(bytes.len() as u32).to_be_bytes()[..]);
+    dest.extend_from_slice(bytes);
+}
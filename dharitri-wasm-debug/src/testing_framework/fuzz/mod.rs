@@ -0,0 +1,7 @@
+mod contract_fuzzer;
+mod fuzz_call;
+mod fuzz_value_gen;
+
+pub use contract_fuzzer::*;
+pub use fuzz_call::FuzzCall;
+use fuzz_value_gen::FuzzValueGenerator;
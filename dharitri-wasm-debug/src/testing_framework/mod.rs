@@ -1,12 +1,16 @@
 mod address_factory;
+mod block_producer;
 mod contract_obj_wrapper;
+mod fuzz;
 mod helper_macros;
 mod denali_generator;
 mod raw_converter;
 mod tx_denali;
 
 use address_factory::*;
+use block_producer::*;
 pub use contract_obj_wrapper::*;
+pub use fuzz::*;
 pub use helper_macros::*;
 use denali_generator::*;
 pub use tx_denali::*;
@@ -1,14 +1,13 @@
 use crate::{
-    tx_execution::default_execution,
+    tx_execution::{default_execution, execute_system_sc, is_native_system_sc_call},
     tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult},
 };
 
 use super::{
     change_owner_mock::execute_change_owner, dct_local_burn::execute_local_burn,
     dct_local_mint::execute_local_mint, dct_multi_transfer_mock::execute_dct_multi_transfer,
-    dct_nft_add_quantity_mock::execute_nft_add_quantity,
-    dct_nft_add_uri_mock::execute_nft_add_uri, dct_nft_burn_mock::execute_nft_burn,
-    dct_nft_create_mock::execute_dct_nft_create,
+    dct_nft_add_quantity_mock::execute_nft_add_quantity, dct_nft_add_uri_mock::execute_nft_add_uri,
+    dct_nft_burn_mock::execute_nft_burn, dct_nft_create_mock::execute_dct_nft_create,
     dct_nft_transfer_mock::execute_dct_nft_transfer,
     dct_nft_update_attriutes_mock::execute_dct_nft_update_attriutes,
     dct_transfer_mock::execute_dct_transfer, set_username_mock::execute_set_username,
@@ -85,6 +84,9 @@ pub fn execute_builtin_function_or_default(
         CHANGE_OWNER_BUILTIN_FUNC_NAME => execute_change_owner(tx_input, tx_cache),
         SET_USERNAME_FUNC_NAME => execute_set_username(tx_input, tx_cache),
         UPGRADE_CONTRACT_FUNC_NAME => execute_upgrade_contract(tx_input, tx_cache),
+        _ if is_native_system_sc_call(&tx_input, &tx_cache) => {
+            execute_system_sc(tx_input, tx_cache)
+        },
         _ => default_execution(tx_input, tx_cache),
     }
 }
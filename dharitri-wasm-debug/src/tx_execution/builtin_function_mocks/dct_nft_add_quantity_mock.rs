@@ -48,6 +48,9 @@ pub fn execute_nft_add_quantity(
         result_values: Vec::new(),
         result_logs: vec![dct_nft_create_log],
         result_calls: TxResultCalls::empty(),
+        gas_used: 0,
+        back_transfers: Vec::new(),
+        decode_error_context: None,
     };
 
     (tx_result, tx_cache.into_blockchain_updates())
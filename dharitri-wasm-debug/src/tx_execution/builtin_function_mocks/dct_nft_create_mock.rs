@@ -70,6 +70,9 @@ pub fn execute_dct_nft_create(
         result_values: vec![top_encode_to_vec_u8(&new_nonce).unwrap()],
         result_logs: vec![dct_nft_create_log],
         result_calls: TxResultCalls::empty(),
+        gas_used: 0,
+        back_transfers: Vec::new(),
+        decode_error_context: None,
     };
 
     (tx_result, tx_cache.into_blockchain_updates())
@@ -7,7 +7,7 @@ use crate::{
         async_call_tx_input, async_callback_tx_input, async_promise_tx_input, merge_results,
         AsyncCallTxData, Promise, TxCache, TxContext, TxInput, TxResult, TxResultCalls,
     },
-    world_mock::{AccountData, AccountDct, BlockchainMock},
+    world_mock::{is_system_sc_address, AccountData, AccountDct, BlockchainMock},
 };
 
 use super::{execute_builtin_function_or_default, execute_tx_context};
@@ -35,111 +35,176 @@ pub fn execute_sc_call(tx_input: TxInput, mut state: BlockchainMock) -> (TxResul
     (tx_result, state)
 }
 
-pub fn execute_async_call_and_callback(
-    async_data: AsyncCallTxData,
-    state: BlockchainMock,
-) -> (TxResult, TxResult, BlockchainMock) {
-    if state.accounts.contains_key(&async_data.to) {
-        let async_input = async_call_tx_input(&async_data);
-
-        let (async_result, state) = sc_call_with_async_and_callback(async_input, state);
-
-        let callback_input = async_callback_tx_input(&async_data, &async_result);
-        let (callback_result, state) = execute_sc_call(callback_input, state);
-        assert!(
-            callback_result.result_calls.async_call.is_none(),
-            "successive asyncs currently not supported"
-        );
-        (async_result, callback_result, state)
-    } else {
-        let state_rc = Rc::new(state);
-        let tx_cache = TxCache::new(state_rc.clone());
-        tx_cache.subtract_moax_balance(&async_data.from, &async_data.call_value);
-        tx_cache.insert_account(AccountData {
-            address: async_data.to.clone(),
-            nonce: 0,
-            moax_balance: async_data.call_value,
-            dct: AccountDct::default(),
-            username: Vec::new(),
-            storage: HashMap::new(),
-            contract_path: None,
-            contract_owner: None,
-        });
-        let blockchain_updates = tx_cache.into_blockchain_updates();
-        let mut state = Rc::try_unwrap(state_rc).unwrap();
-        state.commit_updates(blockchain_updates);
+/// Callbacks are not executed via `execute_sc_call`,
+/// since the DCT refunds in their input must only show up in the call value, not be transferred again.
+fn execute_callback(callback_input: TxInput, state: BlockchainMock) -> (TxResult, BlockchainMock) {
+    let state_rc = Rc::new(state);
+    let tx_cache = TxCache::new(state_rc.clone());
+    let tx_context = TxContext::new(callback_input, tx_cache);
+    let (tx_context, tx_result) = execute_tx_context(tx_context);
+    let blockchain_updates = tx_context.into_blockchain_updates();
 
-        (TxResult::empty(), TxResult::empty(), state)
+    let mut state = Rc::try_unwrap(state_rc).unwrap();
+    if tx_result.result_status == 0 {
+        blockchain_updates.apply(&mut state);
     }
+
+    (tx_result, state)
 }
 
-// TODO: refactor
-pub fn sc_call_with_async_and_callback(
-    tx_input: TxInput,
+/// Async calls to addresses that do not exist yet simply create the account and transfer the payments.
+fn transfer_to_new_account(
+    from: &Address,
+    async_data: &AsyncCallTxData,
     state: BlockchainMock,
 ) -> (TxResult, BlockchainMock) {
-    let contract_address = tx_input.to.clone();
-    let (mut tx_result, mut state) = execute_sc_call(tx_input, state);
-    let result_calls = std::mem::replace(&mut tx_result.result_calls, TxResultCalls::empty());
+    let state_rc = Rc::new(state);
+    let tx_cache = TxCache::new(state_rc.clone());
+    let check_result = tx_cache.check_dct_transfers(from, &async_data.to, &async_data.dct_values);
+    let tx_result = match check_result {
+        Ok(()) => {
+            tx_cache.subtract_moax_balance(from, &async_data.call_value);
+            tx_cache.insert_account(AccountData {
+                address: async_data.to.clone(),
+                nonce: 0,
+                moax_balance: async_data.call_value.clone(),
+                dct: AccountDct::default(),
+                username: Vec::new(),
+                storage: HashMap::new(),
+                contract_path: None,
+                contract_owner: None,
+            });
+            transfer_async_call_dct(&tx_cache, from, async_data);
+            TxResult::empty()
+        },
+        Err(message) => TxResult::from_vm_error(message),
+    };
+
+    let blockchain_updates = tx_cache.into_blockchain_updates();
+    let mut state = Rc::try_unwrap(state_rc).unwrap();
     if tx_result.result_status == 0 {
-        if let Some(async_data) = result_calls.async_call {
-            let (async_result, callback_result, new_state) =
-                execute_async_call_and_callback(async_data, state);
-            state = new_state;
+        state.commit_updates(blockchain_updates);
+    }
 
-            tx_result = merge_results(tx_result, async_result);
-            tx_result = merge_results(tx_result, callback_result);
+    (tx_result, state)
+}
 
-            return (tx_result, state);
-        }
+fn transfer_async_call_dct(tx_cache: &TxCache, from: &Address, async_data: &AsyncCallTxData) {
+    for dct_value in async_data.dct_values.iter() {
+        tx_cache.transfer_dct_balance(
+            from,
+            &async_data.to,
+            &dct_value.token_identifier,
+            dct_value.nonce,
+            &dct_value.value,
+        );
     }
+}
+
+/// Executes the async call and all promises issued by a transaction, in order,
+/// together with their callbacks and everything these trigger in turn.
+///
+/// Each call and each callback is committed or rolled back on its own,
+/// a failure further down the call graph does not revert what has already been executed above it.
+pub fn execute_result_calls(
+    result_calls: TxResultCalls,
+    mut state: BlockchainMock,
+) -> (TxResult, BlockchainMock) {
+    let mut tx_result = TxResult::empty();
 
-    for callback in result_calls.promises {
+    if let Some(async_data) = result_calls.async_call {
         let (async_result, callback_result, new_state) =
-            execute_promise_call_and_callback(&contract_address, &callback, state);
+            execute_async_call_and_callback(async_data, state);
         state = new_state;
+        tx_result = merge_results(tx_result, async_result);
+        tx_result = merge_results(tx_result, callback_result);
+    }
 
-        tx_result = merge_results(tx_result, async_result.clone());
-        tx_result = merge_results(tx_result, callback_result.clone());
+    for promise in result_calls.promises {
+        let (async_result, callback_result, new_state) =
+            execute_promise_call_and_callback(&promise.endpoint.from, &promise, state);
+        state = new_state;
+        tx_result = merge_results(tx_result, async_result);
+        tx_result = merge_results(tx_result, callback_result);
     }
 
     (tx_result, state)
 }
 
+/// Runs a call and, if it succeeded, the async calls and promises it issued.
+///
+/// Returns the result of the call itself, then the merged result of the whole subtree.
+/// The former is what the callback gets to see, but its gas covers the entire subtree.
+fn execute_with_result_calls<F>(
+    tx_input: TxInput,
+    state: BlockchainMock,
+    exec_fn: F,
+) -> (TxResult, TxResult, BlockchainMock)
+where
+    F: FnOnce(TxInput, BlockchainMock) -> (TxResult, BlockchainMock),
+{
+    let (mut tx_result, state) = exec_fn(tx_input, state);
+    let result_calls = std::mem::replace(&mut tx_result.result_calls, TxResultCalls::empty());
+    if tx_result.result_status != 0 {
+        return (tx_result.clone(), tx_result, state);
+    }
+
+    let (nested_result, state) = execute_result_calls(result_calls, state);
+    let subtree_result = merge_results(tx_result.clone(), nested_result);
+    tx_result.gas_used = subtree_result.gas_used;
+    (tx_result, subtree_result, state)
+}
+
+/// Returns the result of the async call subtree and that of the callback subtree.
+pub fn execute_async_call_and_callback(
+    async_data: AsyncCallTxData,
+    state: BlockchainMock,
+) -> (TxResult, TxResult, BlockchainMock) {
+    if state.accounts.contains_key(&async_data.to) || is_system_sc_address(&async_data.to) {
+        let async_input = async_call_tx_input(&async_data);
+        let (async_result, async_subtree_result, state) =
+            execute_with_result_calls(async_input, state, execute_sc_call);
+
+        let callback_input = async_callback_tx_input(&async_data, &async_result);
+        let (_, callback_subtree_result, state) =
+            execute_with_result_calls(callback_input, state, execute_callback);
+
+        (async_subtree_result, callback_subtree_result, state)
+    } else {
+        let (tx_result, state) = transfer_to_new_account(&async_data.from, &async_data, state);
+        (tx_result, TxResult::empty(), state)
+    }
+}
+
+/// Executes a transaction together with the entire call graph of async calls and callbacks it triggers.
+pub fn sc_call_with_async_and_callback(
+    tx_input: TxInput,
+    state: BlockchainMock,
+) -> (TxResult, BlockchainMock) {
+    let (_, subtree_result, state) = execute_with_result_calls(tx_input, state, execute_sc_call);
+    (subtree_result, state)
+}
+
+/// Returns the result of the promise call subtree and that of the callback subtree.
 pub fn execute_promise_call_and_callback(
     address: &Address,
     promise: &Promise,
     state: BlockchainMock,
 ) -> (TxResult, TxResult, BlockchainMock) {
-    if state.accounts.contains_key(&promise.endpoint.to) {
+    if state.accounts.contains_key(&promise.endpoint.to)
+        || is_system_sc_address(&promise.endpoint.to)
+    {
         let async_input = async_call_tx_input(&promise.endpoint);
-        let (async_result, state) = sc_call_with_async_and_callback(async_input, state);
+        let (async_result, async_subtree_result, state) =
+            execute_with_result_calls(async_input, state, execute_sc_call);
 
         let callback_input = async_promise_tx_input(address, promise, &async_result);
-        let (callback_result, state) = execute_sc_call(callback_input, state);
-        assert!(
-            callback_result.result_calls.promises.is_empty(),
-            "successive promises currently not supported"
-        );
-        (async_result, callback_result, state)
-    } else {
-        let state_rc = Rc::new(state);
-        let tx_cache = TxCache::new(state_rc.clone());
-        tx_cache.subtract_moax_balance(address, &promise.endpoint.call_value);
-        tx_cache.insert_account(AccountData {
-            address: promise.endpoint.to.clone(),
-            nonce: 0,
-            moax_balance: promise.endpoint.call_value.clone(),
-            dct: AccountDct::default(),
-            username: Vec::new(),
-            storage: HashMap::new(),
-            contract_path: None,
-            contract_owner: None,
-        });
-        let blockchain_updates = tx_cache.into_blockchain_updates();
-        let mut state = Rc::try_unwrap(state_rc).unwrap();
-        state.commit_updates(blockchain_updates);
+        let (_, callback_subtree_result, state) =
+            execute_with_result_calls(callback_input, state, execute_callback);
 
-        (TxResult::empty(), TxResult::empty(), state)
+        (async_subtree_result, callback_subtree_result, state)
+    } else {
+        let (tx_result, state) = transfer_to_new_account(address, &promise.endpoint, state);
+        (tx_result, TxResult::empty(), state)
     }
 }
@@ -34,13 +34,30 @@ fn execute_tx_context_rc(tx_context_rc: Rc<TxContext>) -> (Rc<TxContext>, TxResu
     let contract_instance =
         contract_map.new_contract_instance(contract_identifier.as_slice(), tx_context_ref.clone());
 
+    let blockchain = tx_context_rc.blockchain_ref();
+    blockchain.with_coverage(|coverage| {
+        coverage.enter_contract(contract_map.contract_name(contract_identifier.as_slice()))
+    });
+
     TxContextStack::static_push(tx_context_rc.clone());
-    let tx_result = execute_contract_instance_endpoint(contract_instance, func_name);
+    let mut tx_result = execute_contract_instance_endpoint(contract_instance, func_name);
+
+    blockchain.with_coverage(|coverage| coverage.exit_contract(func_name, &tx_result));
 
     let tx_context_rc = TxContextStack::static_pop();
+    tx_result.gas_used = compute_gas_used(&tx_context_rc, &tx_result);
     (tx_context_rc, tx_result)
 }
 
+/// As in the VM, failed transactions consume all the gas they were given.
+fn compute_gas_used(tx_context: &TxContext, tx_result: &TxResult) -> u64 {
+    if tx_context.gas_schedule().is_some() && tx_result.result_status != 0 {
+        tx_context.input_ref().gas_limit
+    } else {
+        tx_context.gas_used()
+    }
+}
+
 fn get_contract_identifier(tx_context: &TxContext) -> Vec<u8> {
     tx_context
         .tx_cache
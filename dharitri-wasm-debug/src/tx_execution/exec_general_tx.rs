@@ -8,6 +8,12 @@ use crate::{
 use super::execute_tx_context;
 
 pub fn default_execution(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    if let Err(message) =
+        tx_cache.check_dct_transfers(&tx_input.from, &tx_input.to, &tx_input.dct_values)
+    {
+        return (TxResult::from_vm_error(message), BlockchainUpdate::empty());
+    }
+
     let mut tx_context = TxContext::new(tx_input, tx_cache);
 
     tx_context.tx_cache.subtract_moax_balance(
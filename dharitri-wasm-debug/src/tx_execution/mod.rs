@@ -3,9 +3,11 @@ mod exec_call;
 mod exec_contract_endpoint;
 mod exec_create;
 mod exec_general_tx;
+mod system_sc;
 
 pub use builtin_function_mocks::*;
 pub use exec_call::*;
 pub use exec_contract_endpoint::*;
 pub use exec_create::*;
 pub use exec_general_tx::*;
+pub use system_sc::*;
@@ -0,0 +1,7 @@
+mod system_sc_exec;
+mod system_sc_issue;
+mod system_sc_special_roles;
+mod system_sc_token_management;
+mod system_sc_util;
+
+pub use system_sc_exec::*;
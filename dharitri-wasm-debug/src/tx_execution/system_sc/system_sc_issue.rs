@@ -0,0 +1,271 @@
+use dharitri_wasm::types::DctLocalRole;
+use sha3::{Digest, Keccak256};
+
+use crate::{
+    num_bigint::BigUint,
+    tx_mock::{TxCache, TxInput, TxInputDCT, TxResult},
+    world_mock::{
+        DctInstanceMetadata, SystemScTokenData, FUNGIBLE_TOKEN_TYPE, META_TOKEN_TYPE,
+        NON_FUNGIBLE_TOKEN_TYPE, SEMI_FUNGIBLE_TOKEN_TYPE,
+    },
+};
+
+use super::system_sc_util::*;
+
+const MIN_TOKEN_NAME_LENGTH: usize = 3;
+const MAX_TOKEN_NAME_LENGTH: usize = 20;
+const MIN_TICKER_LENGTH: usize = 3;
+const MAX_TICKER_LENGTH: usize = 10;
+const MAX_NUM_DECIMALS: u32 = 18;
+const TICKER_RANDOM_SEQUENCE_LENGTH: usize = 3;
+
+const CAN_UPGRADE: &[u8] = b"canUpgrade";
+const CAN_ADD_SPECIAL_ROLES: &[u8] = b"canAddSpecialRoles";
+
+const KNOWN_PROPERTIES: &[&[u8]] = &[
+    b"canFreeze",
+    b"canWipe",
+    b"canPause",
+    b"canMint",
+    b"canBurn",
+    b"canChangeOwner",
+    CAN_UPGRADE,
+    CAN_ADD_SPECIAL_ROLES,
+    b"canTransferNFTCreateRole",
+    b"canCreateMultiShard",
+];
+
+/// `issue@name@ticker@initialSupply@numDecimals@[property@value]*`
+///
+/// A non-zero initial supply is sent back to the issuer, the callback receives it as call value.
+/// Otherwise, the new token identifier is returned.
+pub fn issue_fungible(tx_input: &TxInput, tx_cache: &TxCache) -> SystemScResult<TxResult> {
+    check_min_num_args(tx_input, 4)?;
+    let initial_supply = BigUint::from_bytes_be(tx_input.args[2].as_slice());
+    let num_decimals = parse_u32(tx_input.args[3].as_slice())?;
+    let token_identifier = register_token(
+        tx_input,
+        tx_cache,
+        FUNGIBLE_TOKEN_TYPE,
+        num_decimals,
+        &tx_input.args[4..],
+    )?;
+
+    let mut tx_result = TxResult::empty();
+    if initial_supply == BigUint::default() {
+        tx_result.result_values.push(token_identifier);
+    } else {
+        tx_cache.increase_dct_balance(
+            &tx_input.from,
+            token_identifier.as_slice(),
+            0,
+            &initial_supply,
+            DctInstanceMetadata::default(),
+        );
+        tx_result.back_transfers.push(TxInputDCT {
+            token_identifier,
+            nonce: 0,
+            value: initial_supply,
+        });
+    }
+    Ok(tx_result)
+}
+
+/// `issueNonFungible@name@ticker@[property@value]*`
+pub fn issue_non_fungible(tx_input: &TxInput, tx_cache: &TxCache) -> SystemScResult<TxResult> {
+    check_min_num_args(tx_input, 2)?;
+    let token_identifier = register_token(
+        tx_input,
+        tx_cache,
+        NON_FUNGIBLE_TOKEN_TYPE,
+        0,
+        &tx_input.args[2..],
+    )?;
+    Ok(token_identifier_result(token_identifier))
+}
+
+/// `issueSemiFungible@name@ticker@[property@value]*`
+pub fn issue_semi_fungible(tx_input: &TxInput, tx_cache: &TxCache) -> SystemScResult<TxResult> {
+    check_min_num_args(tx_input, 2)?;
+    let token_identifier = register_token(
+        tx_input,
+        tx_cache,
+        SEMI_FUNGIBLE_TOKEN_TYPE,
+        0,
+        &tx_input.args[2..],
+    )?;
+    Ok(token_identifier_result(token_identifier))
+}
+
+/// `registerMetaDCT@name@ticker@numDecimals@[property@value]*`
+pub fn register_meta_dct(tx_input: &TxInput, tx_cache: &TxCache) -> SystemScResult<TxResult> {
+    check_min_num_args(tx_input, 3)?;
+    let num_decimals = parse_u32(tx_input.args[2].as_slice())?;
+    let token_identifier = register_token(
+        tx_input,
+        tx_cache,
+        META_TOKEN_TYPE,
+        num_decimals,
+        &tx_input.args[3..],
+    )?;
+    Ok(token_identifier_result(token_identifier))
+}
+
+/// `registerAndSetAllRoles@name@ticker@tokenType@numDecimals`
+///
+/// Grants the issuer all the roles that make sense for the token type.
+pub fn register_and_set_all_roles(
+    tx_input: &TxInput,
+    tx_cache: &TxCache,
+) -> SystemScResult<TxResult> {
+    check_num_args(tx_input, 4)?;
+    let token_type = match tx_input.args[2].as_slice() {
+        b"FNG" => FUNGIBLE_TOKEN_TYPE,
+        b"NFT" => NON_FUNGIBLE_TOKEN_TYPE,
+        b"SFT" => SEMI_FUNGIBLE_TOKEN_TYPE,
+        b"META" => META_TOKEN_TYPE,
+        _ => return Err("invalid argument".to_string()),
+    };
+    let num_decimals = parse_u32(tx_input.args[3].as_slice())?;
+    let token_identifier = register_token(tx_input, tx_cache, token_type, num_decimals, &[])?;
+
+    let all_roles: Vec<Vec<u8>> = token_type_roles(token_type)
+        .iter()
+        .map(|role| role.as_role_name().to_vec())
+        .collect();
+    tx_cache.with_account_mut(&tx_input.from, |account| {
+        account.dct.set_roles(token_identifier.clone(), all_roles);
+    });
+
+    Ok(token_identifier_result(token_identifier))
+}
+
+/// The roles that can be set for a token type, apart from the transfer role, which is valid for all.
+pub fn token_type_roles(token_type: &[u8]) -> &'static [DctLocalRole] {
+    match token_type {
+        FUNGIBLE_TOKEN_TYPE => &[DctLocalRole::Mint, DctLocalRole::Burn],
+        NON_FUNGIBLE_TOKEN_TYPE => &[
+            DctLocalRole::NftCreate,
+            DctLocalRole::NftBurn,
+            DctLocalRole::NftUpdateAttributes,
+            DctLocalRole::NftAddUri,
+        ],
+        _ => &[
+            DctLocalRole::NftCreate,
+            DctLocalRole::NftBurn,
+            DctLocalRole::NftAddQuantity,
+        ],
+    }
+}
+
+fn token_identifier_result(token_identifier: Vec<u8>) -> TxResult {
+    let mut tx_result = TxResult::empty();
+    tx_result.result_values.push(token_identifier);
+    tx_result
+}
+
+/// Validates the name and ticker (first 2 arguments), then stores the new token, owned by the caller.
+fn register_token(
+    tx_input: &TxInput,
+    tx_cache: &TxCache,
+    token_type: &[u8],
+    num_decimals: u32,
+    property_args: &[Vec<u8>],
+) -> SystemScResult<Vec<u8>> {
+    let name = tx_input.args[0].clone();
+    let ticker = tx_input.args[1].clone();
+    check_token_name(name.as_slice())?;
+    check_ticker(ticker.as_slice())?;
+    if num_decimals > MAX_NUM_DECIMALS {
+        return Err("invalid number of decimals".to_string());
+    }
+
+    let mut token_data = SystemScTokenData {
+        owner: tx_input.from.clone(),
+        token_type: token_type.to_vec(),
+        name,
+        ticker,
+        num_decimals,
+        paused: false,
+        properties: vec![CAN_UPGRADE.to_vec(), CAN_ADD_SPECIAL_ROLES.to_vec()],
+        transfer_role_holders: Vec::new(),
+    };
+    apply_property_args(&mut token_data, property_args)?;
+
+    let token_identifier = generate_token_identifier(tx_input, tx_cache);
+    tx_cache.set_system_sc_token_data(token_identifier.as_slice(), &token_data);
+    Ok(token_identifier)
+}
+
+fn check_token_name(name: &[u8]) -> SystemScResult<()> {
+    if name.len() < MIN_TOKEN_NAME_LENGTH
+        || name.len() > MAX_TOKEN_NAME_LENGTH
+        || !name.iter().all(u8::is_ascii_alphanumeric)
+    {
+        return Err("invalid token name".to_string());
+    }
+    Ok(())
+}
+
+fn check_ticker(ticker: &[u8]) -> SystemScResult<()> {
+    if ticker.len() < MIN_TICKER_LENGTH
+        || ticker.len() > MAX_TICKER_LENGTH
+        || !ticker
+            .iter()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+    {
+        return Err("invalid ticker name".to_string());
+    }
+    Ok(())
+}
+
+fn apply_property_args(
+    token_data: &mut SystemScTokenData,
+    property_args: &[Vec<u8>],
+) -> SystemScResult<()> {
+    if property_args.len() % 2 != 0 {
+        return Err("invalid number of arguments".to_string());
+    }
+    for pair in property_args.chunks(2) {
+        let property_name = pair[0].as_slice();
+        if !KNOWN_PROPERTIES.contains(&property_name) {
+            return Err("invalid argument".to_string());
+        }
+        token_data.set_property(property_name, parse_bool(pair[1].as_slice())?);
+    }
+    Ok(())
+}
+
+/// `TICKER-` followed by 6 hex characters, derived from the caller and the current block random seed.
+/// Deterministic, so tests can know the identifier in advance.
+/// In the unlikely case of a collision, the hash input is extended with an attempt counter.
+fn generate_token_identifier(tx_input: &TxInput, tx_cache: &TxCache) -> Vec<u8> {
+    let ticker = tx_input.args[1].as_slice();
+    let random_seed = &tx_cache
+        .blockchain_ref()
+        .current_block_info
+        .block_random_seed;
+    let mut attempt = 0u32;
+    loop {
+        let mut hasher = Keccak256::new();
+        hasher.update(tx_input.from.as_bytes());
+        hasher.update(&random_seed[..]);
+        if attempt > 0 {
+            hasher.update(&attempt.to_be_bytes()[..]);
+        }
+        let hash = hasher.finalize();
+
+        let mut token_identifier = ticker.to_vec();
+        token_identifier.push(b'-');
+        token_identifier
+            .extend_from_slice(hex::encode(&hash[..TICKER_RANDOM_SEQUENCE_LENGTH]).as_bytes());
+
+        if tx_cache
+            .get_system_sc_token_data(token_identifier.as_slice())
+            .is_none()
+        {
+            return token_identifier;
+        }
+        attempt += 1;
+    }
+}
@@ -0,0 +1,144 @@
+use dharitri_wasm::types::DctLocalRole;
+
+use crate::{
+    tx_mock::{TxCache, TxInput, TxResult},
+    world_mock::SystemScTokenData,
+};
+
+use super::{system_sc_issue::token_type_roles, system_sc_util::*};
+
+/// `setSpecialRole@tokenIdentifier@address@[role]*`
+pub fn set_special_role(tx_input: &TxInput, tx_cache: &TxCache) -> SystemScResult<TxResult> {
+    check_min_num_args(tx_input, 3)?;
+    let token_data = load_owned_token(tx_input, tx_cache)?;
+    check_token_property(
+        &token_data,
+        b"canAddSpecialRoles",
+        "cannot add special roles",
+    )?;
+    let address = parse_address(tx_input.args[1].as_slice())?;
+    check_account_exists(tx_cache, &address)?;
+    let new_roles = parse_roles(&token_data, &tx_input.args[2..])?;
+
+    let token_identifier = tx_input.args[0].clone();
+    if contains_transfer_role(&new_roles) && !token_data.transfer_role_holders.contains(&address) {
+        let mut token_data = token_data;
+        token_data.transfer_role_holders.push(address.clone());
+        tx_cache.set_system_sc_token_data(token_identifier.as_slice(), &token_data);
+    }
+
+    tx_cache.with_account_mut(&address, |account| {
+        let mut roles = account.dct.get_roles(token_identifier.as_slice());
+        for role in new_roles {
+            if !roles.contains(&role) {
+                roles.push(role);
+            }
+        }
+        account.dct.set_roles(token_identifier, roles);
+    });
+
+    Ok(TxResult::empty())
+}
+
+/// `unSetSpecialRole@tokenIdentifier@address@[role]*`
+pub fn unset_special_role(tx_input: &TxInput, tx_cache: &TxCache) -> SystemScResult<TxResult> {
+    check_min_num_args(tx_input, 3)?;
+    let mut token_data = load_owned_token(tx_input, tx_cache)?;
+    let address = parse_address(tx_input.args[1].as_slice())?;
+    check_account_exists(tx_cache, &address)?;
+    let removed_roles = parse_roles(&token_data, &tx_input.args[2..])?;
+
+    let token_identifier = tx_input.args[0].clone();
+    if contains_transfer_role(&removed_roles) {
+        token_data
+            .transfer_role_holders
+            .retain(|holder| holder != &address);
+        tx_cache.set_system_sc_token_data(token_identifier.as_slice(), &token_data);
+    }
+
+    tx_cache.with_account_mut(&address, |account| {
+        let mut roles = account.dct.get_roles(token_identifier.as_slice());
+        roles.retain(|role| !removed_roles.contains(role));
+        account.dct.set_roles(token_identifier, roles);
+    });
+
+    Ok(TxResult::empty())
+}
+
+/// `transferNFTCreateRole@tokenIdentifier@oldCreator@newCreator`
+///
+/// The last created nonce moves together with the role, so the new creator continues the sequence.
+pub fn transfer_nft_create_role(
+    tx_input: &TxInput,
+    tx_cache: &TxCache,
+) -> SystemScResult<TxResult> {
+    check_num_args(tx_input, 3)?;
+    let token_data = load_owned_token(tx_input, tx_cache)?;
+    check_token_property(
+        &token_data,
+        b"canTransferNFTCreateRole",
+        "NFT create role transfer is not allowed",
+    )?;
+    let old_creator = parse_address(tx_input.args[1].as_slice())?;
+    let new_creator = parse_address(tx_input.args[2].as_slice())?;
+    check_account_exists(tx_cache, &old_creator)?;
+    check_account_exists(tx_cache, &new_creator)?;
+
+    let token_identifier = tx_input.args[0].clone();
+    let nft_create_role = DctLocalRole::NftCreate.as_role_name().to_vec();
+    let last_nonce = tx_cache.with_account_mut(&old_creator, |account| {
+        let mut roles = account.dct.get_roles(token_identifier.as_slice());
+        if !roles.contains(&nft_create_role) {
+            return Err("old creator does not have the NFT create role".to_string());
+        }
+        roles.retain(|role| role != &nft_create_role);
+        account.dct.set_roles(token_identifier.clone(), roles);
+        Ok(account
+            .dct
+            .get_by_identifier_or_default(token_identifier.as_slice())
+            .last_nonce)
+    })?;
+
+    tx_cache.with_account_mut(&new_creator, |account| {
+        let mut roles = account.dct.get_roles(token_identifier.as_slice());
+        if !roles.contains(&nft_create_role) {
+            roles.push(nft_create_role);
+        }
+        account.dct.set_roles(token_identifier.clone(), roles);
+        if let Some(dct_data) = account
+            .dct
+            .get_mut_by_identifier(token_identifier.as_slice())
+        {
+            dct_data.last_nonce = last_nonce;
+        }
+    });
+
+    Ok(TxResult::empty())
+}
+
+fn contains_transfer_role(roles: &[Vec<u8>]) -> bool {
+    roles
+        .iter()
+        .any(|role| role.as_slice() == DctLocalRole::Transfer.as_role_name())
+}
+
+fn parse_roles(
+    token_data: &SystemScTokenData,
+    role_args: &[Vec<u8>],
+) -> SystemScResult<Vec<Vec<u8>>> {
+    let allowed_roles = token_type_roles(token_data.token_type.as_slice());
+    role_args
+        .iter()
+        .map(|role_name| {
+            let is_allowed = role_name.as_slice() == DctLocalRole::Transfer.as_role_name()
+                || allowed_roles
+                    .iter()
+                    .any(|role| role.as_role_name() == role_name.as_slice());
+            if is_allowed {
+                Ok(role_name.clone())
+            } else {
+                Err("invalid argument".to_string())
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,129 @@
+use crate::{
+    num_bigint::BigUint,
+    tx_mock::{TxCache, TxInput, TxInputDCT, TxResult},
+    world_mock::{DctInstanceMetadata, FUNGIBLE_TOKEN_TYPE},
+};
+
+use super::system_sc_util::*;
+
+/// `pause@tokenIdentifier`
+pub fn pause(tx_input: &TxInput, tx_cache: &TxCache) -> SystemScResult<TxResult> {
+    set_paused(tx_input, tx_cache, true)
+}
+
+/// `unPause@tokenIdentifier`
+pub fn unpause(tx_input: &TxInput, tx_cache: &TxCache) -> SystemScResult<TxResult> {
+    set_paused(tx_input, tx_cache, false)
+}
+
+fn set_paused(tx_input: &TxInput, tx_cache: &TxCache, paused: bool) -> SystemScResult<TxResult> {
+    check_num_args(tx_input, 1)?;
+    let mut token_data = load_owned_token(tx_input, tx_cache)?;
+    check_token_property(&token_data, b"canPause", "cannot pause/un-pause")?;
+    if token_data.paused == paused {
+        return Err(if paused {
+            "cannot pause an already paused contract".to_string()
+        } else {
+            "cannot unPause an already un-paused contract".to_string()
+        });
+    }
+
+    token_data.paused = paused;
+    tx_cache.set_system_sc_token_data(tx_input.args[0].as_slice(), &token_data);
+    Ok(TxResult::empty())
+}
+
+/// `freeze@tokenIdentifier@address`
+pub fn freeze(tx_input: &TxInput, tx_cache: &TxCache) -> SystemScResult<TxResult> {
+    set_frozen(tx_input, tx_cache, true)
+}
+
+/// `unFreeze@tokenIdentifier@address`
+pub fn unfreeze(tx_input: &TxInput, tx_cache: &TxCache) -> SystemScResult<TxResult> {
+    set_frozen(tx_input, tx_cache, false)
+}
+
+fn set_frozen(tx_input: &TxInput, tx_cache: &TxCache, frozen: bool) -> SystemScResult<TxResult> {
+    check_num_args(tx_input, 2)?;
+    let token_data = load_owned_token(tx_input, tx_cache)?;
+    check_token_property(&token_data, b"canFreeze", "cannot freeze")?;
+    let address = parse_address(tx_input.args[1].as_slice())?;
+    check_account_exists(tx_cache, &address)?;
+
+    tx_cache.with_account_mut(&address, |account| {
+        account.dct.set_frozen(tx_input.args[0].clone(), frozen);
+    });
+    Ok(TxResult::empty())
+}
+
+/// `wipe@tokenIdentifier@address`
+///
+/// Only frozen accounts can be wiped.
+pub fn wipe(tx_input: &TxInput, tx_cache: &TxCache) -> SystemScResult<TxResult> {
+    check_num_args(tx_input, 2)?;
+    let token_data = load_owned_token(tx_input, tx_cache)?;
+    check_token_property(&token_data, b"canWipe", "cannot wipe")?;
+    let address = parse_address(tx_input.args[1].as_slice())?;
+    check_account_exists(tx_cache, &address)?;
+
+    let token_identifier = tx_input.args[0].as_slice();
+    tx_cache.with_account_mut(&address, |account| {
+        if !account
+            .dct
+            .get_by_identifier_or_default(token_identifier)
+            .frozen
+        {
+            return Err(
+                "cannot wipe because the account is not frozen for this dct token".to_string(),
+            );
+        }
+        account.dct.wipe(token_identifier);
+        Ok(())
+    })?;
+    Ok(TxResult::empty())
+}
+
+/// `transferOwnership@tokenIdentifier@newOwner`
+pub fn transfer_ownership(tx_input: &TxInput, tx_cache: &TxCache) -> SystemScResult<TxResult> {
+    check_num_args(tx_input, 2)?;
+    let mut token_data = load_owned_token(tx_input, tx_cache)?;
+    check_token_property(
+        &token_data,
+        b"canChangeOwner",
+        "cannot change owner of the token",
+    )?;
+
+    token_data.owner = parse_address(tx_input.args[1].as_slice())?;
+    tx_cache.set_system_sc_token_data(tx_input.args[0].as_slice(), &token_data);
+    Ok(TxResult::empty())
+}
+
+/// `mint@tokenIdentifier@amount`
+///
+/// The new tokens are sent to the owner, when called asynchronously the callback receives them as call value.
+pub fn mint(tx_input: &TxInput, tx_cache: &TxCache) -> SystemScResult<TxResult> {
+    check_num_args(tx_input, 2)?;
+    let token_data = load_owned_token(tx_input, tx_cache)?;
+    if token_data.token_type != FUNGIBLE_TOKEN_TYPE {
+        return Err("invalid argument".to_string());
+    }
+    check_token_property(&token_data, b"canMint", "token is not mintable")?;
+
+    let token_identifier = tx_input.args[0].clone();
+    let amount = BigUint::from_bytes_be(tx_input.args[1].as_slice());
+    tx_cache.increase_dct_balance(
+        &tx_input.from,
+        token_identifier.as_slice(),
+        0,
+        &amount,
+        DctInstanceMetadata::default(),
+    );
+
+    let mut tx_result = TxResult::empty();
+    tx_result.back_transfers.push(TxInputDCT {
+        token_identifier,
+        nonce: 0,
+        value: amount,
+    });
+    Ok(tx_result)
+}
@@ -0,0 +1,79 @@
+use dharitri_wasm::types::heap::Address;
+
+use crate::{
+    tx_mock::{TxCache, TxInput},
+    world_mock::SystemScTokenData,
+};
+
+pub type SystemScResult<T> = Result<T, String>;
+
+pub const TRUE_BYTES: &[u8] = b"true";
+pub const FALSE_BYTES: &[u8] = b"false";
+
+pub fn check_num_args(tx_input: &TxInput, expected_num_args: usize) -> SystemScResult<()> {
+    if tx_input.args.len() != expected_num_args {
+        return Err("invalid number of arguments".to_string());
+    }
+    Ok(())
+}
+
+pub fn check_min_num_args(tx_input: &TxInput, min_num_args: usize) -> SystemScResult<()> {
+    if tx_input.args.len() < min_num_args {
+        return Err("invalid number of arguments".to_string());
+    }
+    Ok(())
+}
+
+pub fn parse_address(arg: &[u8]) -> SystemScResult<Address> {
+    if arg.len() != 32 {
+        return Err("invalid address argument".to_string());
+    }
+    Ok(Address::from_slice(arg))
+}
+
+pub fn parse_u32(arg: &[u8]) -> SystemScResult<u32> {
+    if arg.len() > 4 {
+        return Err("invalid argument".to_string());
+    }
+    Ok(arg.iter().fold(0u32, |acc, byte| (acc << 8) | *byte as u32))
+}
+
+pub fn parse_bool(arg: &[u8]) -> SystemScResult<bool> {
+    match arg {
+        TRUE_BYTES => Ok(true),
+        FALSE_BYTES => Ok(false),
+        _ => Err("invalid argument".to_string()),
+    }
+}
+
+pub fn check_account_exists(tx_cache: &TxCache, address: &Address) -> SystemScResult<()> {
+    if !tx_cache.account_exists(address) {
+        return Err("destination account does not exist".to_string());
+    }
+    Ok(())
+}
+
+/// Loads the token given as first argument and checks that the caller owns it.
+pub fn load_owned_token(
+    tx_input: &TxInput,
+    tx_cache: &TxCache,
+) -> SystemScResult<SystemScTokenData> {
+    let token_data = tx_cache
+        .get_system_sc_token_data(tx_input.args[0].as_slice())
+        .ok_or_else(|| "no ticker with given name".to_string())?;
+    if token_data.owner != tx_input.from {
+        return Err("can be called by owner only".to_string());
+    }
+    Ok(token_data)
+}
+
+pub fn check_token_property(
+    token_data: &SystemScTokenData,
+    property_name: &[u8],
+    err_msg: &str,
+) -> SystemScResult<()> {
+    if !token_data.has_property(property_name) {
+        return Err(err_msg.to_string());
+    }
+    Ok(())
+}
@@ -5,10 +5,13 @@ mod tx_async_call_data;
 mod tx_cache;
 mod tx_cache_balance_util;
 mod tx_cache_source;
+mod tx_cache_system_sc_util;
+mod tx_cache_transfer_util;
 mod tx_callback_selector;
 mod tx_context;
 mod tx_context_ref;
 mod tx_context_stack;
+mod tx_elliptic_curve;
 mod tx_input;
 mod tx_input_util;
 mod tx_log;
@@ -21,10 +24,13 @@ pub use blockchain_rng::*;
 pub use tx_async_call_data::*;
 pub use tx_cache::*;
 pub use tx_cache_source::*;
+pub use tx_cache_system_sc_util::system_sc_address;
+pub use tx_cache_transfer_util::*;
 pub use tx_callback_selector::*;
 pub use tx_context::*;
 pub use tx_context_ref::*;
 pub use tx_context_stack::*;
+pub use tx_elliptic_curve::*;
 pub use tx_input::*;
 pub use tx_input_util::*;
 pub use tx_log::*;
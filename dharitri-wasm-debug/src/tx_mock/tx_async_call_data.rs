@@ -1,5 +1,6 @@
-use crate::tx_mock::{TxInput, TxResult};
+use crate::tx_mock::{TxInput, TxInputDCT, TxResult};
 use dharitri_wasm::{
+    api::{DCT_MULTI_TRANSFER_FUNC_NAME, DCT_NFT_TRANSFER_FUNC_NAME, DCT_TRANSFER_FUNC_NAME},
     dharitri_codec::*,
     types::heap::{Address, H256},
 };
@@ -10,30 +11,161 @@ use alloc::vec::Vec;
 
 use super::Promise;
 
+/// The destination, endpoint and arguments are those of the actual call,
+/// DCT payments are kept separately, in `dct_values`.
 #[derive(Debug, Clone)]
 pub struct AsyncCallTxData {
     pub from: Address,
     pub to: Address,
     pub call_value: BigUint,
+    pub dct_values: Vec<TxInputDCT>,
     pub endpoint_name: Vec<u8>,
     pub arguments: Vec<Vec<u8>>,
+    pub gas_limit: u64,
     pub tx_hash: H256,
 }
 
+impl AsyncCallTxData {
+    /// Contracts send DCT via async call by calling the transfer built-in functions.
+    /// This unpacks such calls, so that the payments can be tracked and refunded.
+    /// Calls to other functions are left unchanged.
+    ///
+    /// The arguments come from the contract, so malformed transfers are reported as errors,
+    /// with the same messages as the built-in functions.
+    pub fn unpack_dct_transfer(&mut self) -> Result<(), String> {
+        let mut args = core::mem::take(&mut self.arguments).into_iter();
+        match self.endpoint_name.as_slice() {
+            DCT_TRANSFER_FUNC_NAME => {
+                if args.len() < 2 {
+                    return Err("DCTTransfer too few arguments".to_string());
+                }
+                self.dct_values.push(TxInputDCT {
+                    token_identifier: next_arg(&mut args),
+                    nonce: 0,
+                    value: BigUint::from_bytes_be(next_arg(&mut args).as_slice()),
+                });
+            },
+            DCT_NFT_TRANSFER_FUNC_NAME => {
+                if args.len() < 4 {
+                    return Err("DCTNFTTransfer too few arguments".to_string());
+                }
+                self.dct_values.push(TxInputDCT {
+                    token_identifier: next_arg(&mut args),
+                    nonce: decode_arg(&mut args, "DCTNFTTransfer")?,
+                    value: BigUint::from_bytes_be(next_arg(&mut args).as_slice()),
+                });
+                self.to = decode_arg(&mut args, "DCTNFTTransfer")?;
+            },
+            DCT_MULTI_TRANSFER_FUNC_NAME => {
+                if args.len() < 2 {
+                    return Err("MultiDCTNFTTransfer too few arguments".to_string());
+                }
+                self.to = decode_arg(&mut args, "MultiDCTNFTTransfer")?;
+                let num_payments: usize = decode_arg(&mut args, "MultiDCTNFTTransfer")?;
+                if args.len() < num_payments.saturating_mul(3) {
+                    return Err("MultiDCTNFTTransfer too few arguments".to_string());
+                }
+                for _ in 0..num_payments {
+                    self.dct_values.push(TxInputDCT {
+                        token_identifier: next_arg(&mut args),
+                        nonce: decode_arg(&mut args, "MultiDCTNFTTransfer")?,
+                        value: BigUint::from_bytes_be(next_arg(&mut args).as_slice()),
+                    });
+                }
+            },
+            _ => {
+                self.arguments = args.collect();
+                return Ok(());
+            },
+        }
+        self.endpoint_name = args.next().unwrap_or_default();
+        self.arguments = args.collect();
+        Ok(())
+    }
+
+    /// The built-in function call that performs the DCT transfers, followed by the actual call.
+    /// Mirrors `unpack_dct_transfer`.
+    fn packed_dct_transfer(&self) -> (Address, Vec<u8>, Vec<Vec<u8>>) {
+        let mut args = Vec::new();
+        let (to, func_name) = match self.dct_values.as_slice() {
+            [] => {
+                return (
+                    self.to.clone(),
+                    self.endpoint_name.clone(),
+                    self.arguments.clone(),
+                )
+            },
+            [payment] if payment.nonce == 0 => {
+                args.push(payment.token_identifier.clone());
+                args.push(payment.value.to_bytes_be());
+                (self.to.clone(), DCT_TRANSFER_FUNC_NAME)
+            },
+            [payment] => {
+                args.push(payment.token_identifier.clone());
+                args.push(top_encode_to_vec_u8(&payment.nonce).unwrap());
+                args.push(payment.value.to_bytes_be());
+                args.push(self.to.to_vec());
+                (self.from.clone(), DCT_NFT_TRANSFER_FUNC_NAME)
+            },
+            payments => {
+                args.push(self.to.to_vec());
+                args.push(top_encode_to_vec_u8(&payments.len()).unwrap());
+                for payment in payments {
+                    args.push(payment.token_identifier.clone());
+                    args.push(top_encode_to_vec_u8(&payment.nonce).unwrap());
+                    args.push(payment.value.to_bytes_be());
+                }
+                (self.from.clone(), DCT_MULTI_TRANSFER_FUNC_NAME)
+            },
+        };
+        if !self.endpoint_name.is_empty() {
+            args.push(self.endpoint_name.clone());
+            args.extend_from_slice(self.arguments.as_slice());
+        }
+        (to, func_name.to_vec(), args)
+    }
+}
+
+/// Only called after the argument count was checked.
+fn next_arg(args: &mut alloc::vec::IntoIter<Vec<u8>>) -> Vec<u8> {
+    args.next().unwrap()
+}
+
+fn decode_arg<T: TopDecode>(
+    args: &mut alloc::vec::IntoIter<Vec<u8>>,
+    func_name: &str,
+) -> Result<T, String> {
+    T::top_decode(next_arg(args))
+        .map_err(|err| format!("{} invalid argument: {}", func_name, err.message_str()))
+}
+
 pub fn async_call_tx_input(async_data: &AsyncCallTxData) -> TxInput {
+    let (to, func_name, args) = async_data.packed_dct_transfer();
     TxInput {
         from: async_data.from.clone(),
-        to: async_data.to.clone(),
+        to,
         moax_value: async_data.call_value.clone(),
         dct_values: Vec::new(),
-        func_name: async_data.endpoint_name.clone(),
-        args: async_data.arguments.clone(),
-        gas_limit: 1000,
+        func_name,
+        args,
+        gas_limit: async_data.gas_limit,
         gas_price: 0,
         tx_hash: async_data.tx_hash.clone(),
     }
 }
 
+/// On success, the callback receives whatever DCT the callee sent back.
+///
+/// Failed async calls are reverted entirely, so the DCT payments never left the caller.
+/// They are nevertheless shown as refunded in the callback call value, same as in the VM.
+fn callback_dct_values(dct_values: &[TxInputDCT], async_result: &TxResult) -> Vec<TxInputDCT> {
+    if async_result.result_status == 0 {
+        async_result.back_transfers.clone()
+    } else {
+        dct_values.to_vec()
+    }
+}
+
 pub fn async_callback_tx_input(async_data: &AsyncCallTxData, async_result: &TxResult) -> TxInput {
     let mut args: Vec<Vec<u8>> = Vec::new();
     let serialized_bytes = top_encode_to_vec_u8(&async_result.result_status).unwrap();
@@ -47,10 +179,10 @@ pub fn async_callback_tx_input(async_data: &AsyncCallTxData, async_result: &TxRe
         from: async_data.to.clone(),
         to: async_data.from.clone(),
         moax_value: 0u32.into(),
-        dct_values: Vec::new(),
+        dct_values: callback_dct_values(async_data.dct_values.as_slice(), async_result),
         func_name: b"callBack".to_vec(),
         args,
-        gas_limit: 1000,
+        gas_limit: async_data.gas_limit.saturating_sub(async_result.gas_used),
         gas_price: 0,
         tx_hash: async_data.tx_hash.clone(),
     }
@@ -76,22 +208,29 @@ pub fn async_promise_tx_input(
         from: promise.endpoint.from.clone(),
         to: address.clone(),
         moax_value: 0u32.into(),
-        dct_values: Vec::new(),
+        dct_values: callback_dct_values(promise.endpoint.dct_values.as_slice(), async_result),
         func_name: callback,
         args,
-        gas_limit: 1000,
+        gas_limit: promise.extra_gas_for_callback
+            + promise
+                .endpoint
+                .gas_limit
+                .saturating_sub(async_result.gas_used),
         gas_price: 0,
         tx_hash: promise.endpoint.tx_hash.clone(),
     }
 }
 
 pub fn merge_results(mut original: TxResult, mut new: TxResult) -> TxResult {
+    let gas_used = original.gas_used.saturating_add(new.gas_used);
     if original.result_status == 0 {
         original.result_values.append(&mut new.result_values);
         original.result_logs.append(&mut new.result_logs);
         original.result_message = new.result_message;
+        original.gas_used = gas_used;
         original
     } else {
+        new.gas_used = gas_used;
         new
     }
 }
@@ -48,6 +48,11 @@ impl TxCache {
         }
     }
 
+    pub fn account_exists(&self, address: &Address) -> bool {
+        self.load_account_if_necessary(address);
+        self.accounts.borrow().contains_key(address)
+    }
+
     pub fn with_account<R, F>(&self, address: &Address, f: F) -> R
     where
         F: FnOnce(&AccountData) -> R,
@@ -123,6 +128,10 @@ impl BlockchainUpdate {
     }
 
     pub fn apply(self, blockchain: &mut BlockchainMock) {
-        blockchain.accounts.extend(self.accounts.into_iter());
+        blockchain.accounts.extend(
+            self.accounts
+                .into_iter()
+                .map(|(address, account)| (address, Rc::new(account))),
+        );
     }
 }
@@ -12,7 +12,11 @@ pub trait TxCacheSource {
 
 impl TxCacheSource for TxCache {
     fn load_account(&self, address: &Address) -> Option<AccountData> {
-        Some(self.with_account(address, AccountData::clone))
+        if self.account_exists(address) {
+            Some(self.with_account(address, AccountData::clone))
+        } else {
+            None
+        }
     }
 
     fn blockchain_ref(&self) -> &BlockchainMock {
@@ -22,7 +26,9 @@ impl TxCacheSource for TxCache {
 
 impl TxCacheSource for BlockchainMock {
     fn load_account(&self, address: &Address) -> Option<AccountData> {
-        self.accounts.get(address).map(AccountData::clone)
+        self.accounts
+            .get(address)
+            .map(|account| account.as_ref().clone())
     }
 
     fn blockchain_ref(&self) -> &BlockchainMock {
@@ -0,0 +1,45 @@
+use dharitri_wasm::{dct::DCT_SYSTEM_SC_ADDRESS_ARRAY, types::heap::Address};
+
+use crate::world_mock::{AccountData, SystemScTokenData};
+
+use super::TxCache;
+
+pub fn system_sc_address() -> Address {
+    Address::from(DCT_SYSTEM_SC_ADDRESS_ARRAY)
+}
+
+impl TxCache {
+    /// The system SC account is only created once something needs to be stored in it.
+    pub fn ensure_system_sc_account(&self) {
+        let address = system_sc_address();
+        if !self.account_exists(&address) {
+            self.insert_account(AccountData::new(address, 0u32.into()));
+        }
+    }
+
+    pub fn get_system_sc_token_data(&self, token_identifier: &[u8]) -> Option<SystemScTokenData> {
+        let address = system_sc_address();
+        if !self.account_exists(&address) {
+            return None;
+        }
+        self.with_account(&address, |account| {
+            account
+                .storage
+                .get(token_identifier)
+                .map(|value| SystemScTokenData::from_storage_value(value.as_slice()))
+        })
+    }
+
+    pub fn set_system_sc_token_data(
+        &self,
+        token_identifier: &[u8],
+        token_data: &SystemScTokenData,
+    ) {
+        self.ensure_system_sc_account();
+        self.with_account_mut(&system_sc_address(), |account| {
+            account
+                .storage
+                .insert(token_identifier.to_vec(), token_data.to_storage_value());
+        });
+    }
+}
@@ -0,0 +1,76 @@
+use dharitri_wasm::types::{heap::Address, DctLocalRole};
+
+use super::{TxCache, TxInputDCT};
+
+pub const DCT_TOKEN_PAUSED_ERR_MSG: &str = "dct token is paused";
+pub const DCT_FROZEN_FOR_ACCOUNT_ERR_MSG: &str = "account is frozen for this dct token";
+pub const DCT_LIMITED_TRANSFER_ERR_MSG: &str = "action is not allowed";
+
+impl TxCache {
+    /// Checks the token-level rules that mainnet applies to all DCT transfers:
+    /// - paused tokens cannot be transferred at all;
+    /// - frozen accounts can neither send, nor receive the token;
+    /// - tokens with limited transfer can only be sent from or to an address with the transfer role.
+    ///
+    /// The token-level settings are kept by the DCT system SC.
+    /// Tokens that only exist in the initial state get them from `setState` or the testing framework,
+    /// their transfer roles make them limited transfer.
+    pub fn check_dct_transfers(
+        &self,
+        from: &Address,
+        to: &Address,
+        dct_values: &[TxInputDCT],
+    ) -> Result<(), String> {
+        for dct_value in dct_values {
+            self.check_dct_transfer(from, to, dct_value.token_identifier.as_slice())?;
+        }
+        Ok(())
+    }
+
+    pub fn check_dct_transfer(
+        &self,
+        from: &Address,
+        to: &Address,
+        token_identifier: &[u8],
+    ) -> Result<(), String> {
+        if let Some(token_data) = self.get_system_sc_token_data(token_identifier) {
+            if token_data.paused {
+                return Err(DCT_TOKEN_PAUSED_ERR_MSG.to_string());
+            }
+            if token_data.is_limited_transfer()
+                && !self.has_transfer_role(from, token_identifier)
+                && !self.has_transfer_role(to, token_identifier)
+            {
+                return Err(DCT_LIMITED_TRANSFER_ERR_MSG.to_string());
+            }
+        }
+
+        if self.is_frozen(from, token_identifier) || self.is_frozen(to, token_identifier) {
+            return Err(DCT_FROZEN_FOR_ACCOUNT_ERR_MSG.to_string());
+        }
+
+        Ok(())
+    }
+
+    fn is_frozen(&self, address: &Address, token_identifier: &[u8]) -> bool {
+        self.account_exists(address)
+            && self.with_account(address, |account| {
+                account
+                    .dct
+                    .get_by_identifier(token_identifier)
+                    .map_or(false, |dct_data| dct_data.frozen)
+            })
+    }
+
+    fn has_transfer_role(&self, address: &Address, token_identifier: &[u8]) -> bool {
+        let transfer_role = DctLocalRole::Transfer.as_role_name();
+        self.account_exists(address)
+            && self.with_account(address, |account| {
+                account
+                    .dct
+                    .get_roles(token_identifier)
+                    .iter()
+                    .any(|role| role.as_slice() == transfer_role)
+            })
+    }
+}
@@ -1,9 +1,9 @@
 use crate::{
     num_bigint::BigUint,
-    world_mock::{AccountData, AccountDct, BlockchainMock},
+    world_mock::{AccountData, AccountDct, BlockchainMock, GasSchedule},
 };
 use alloc::vec::Vec;
-use core::cell::RefCell;
+use core::cell::{Cell, RefCell};
 use dharitri_wasm::types::{heap::Address, LockableStaticBuffer};
 use num_traits::Zero;
 use std::{
@@ -13,7 +13,8 @@ use std::{
 };
 
 use super::{
-    BlockchainRng, BlockchainUpdate, TxCache, TxInput, TxManagedTypes, TxResult, TxStaticVars,
+    BlockchainRng, BlockchainUpdate, TxCache, TxInput, TxManagedTypes, TxPanic, TxResult,
+    TxStaticVars,
 };
 
 #[derive(Debug)]
@@ -26,11 +27,13 @@ pub struct TxContext {
     pub tx_result_cell: RefCell<TxResult>,
     pub b_rng: RefCell<BlockchainRng>,
     pub printed_messages: RefCell<Vec<String>>,
+    pub gas_left: Cell<u64>,
 }
 
 impl TxContext {
     pub fn new(tx_input: TxInput, tx_cache: TxCache) -> Self {
         let b_rng = RefCell::new(BlockchainRng::new(&tx_input, &tx_cache));
+        let gas_left = Cell::new(tx_input.gas_limit);
         TxContext {
             tx_input_box: Box::new(tx_input),
             tx_cache: Rc::new(tx_cache),
@@ -40,6 +43,7 @@ impl TxContext {
             tx_result_cell: RefCell::new(TxResult::empty()),
             b_rng,
             printed_messages: RefCell::new(Vec::new()),
+            gas_left,
         }
     }
 
@@ -70,6 +74,7 @@ impl TxContext {
         };
 
         let b_rng = RefCell::new(BlockchainRng::new(&tx_input, &tx_cache));
+        let gas_left = Cell::new(tx_input.gas_limit);
         TxContext {
             tx_input_box: Box::new(tx_input),
             tx_cache: Rc::new(tx_cache),
@@ -79,6 +84,7 @@ impl TxContext {
             tx_result_cell: RefCell::new(TxResult::empty()),
             b_rng,
             printed_messages: RefCell::new(Vec::new()),
+            gas_left,
         }
     }
 
@@ -146,6 +152,46 @@ impl TxContext {
         self.b_rng.borrow_mut()
     }
 
+    pub fn gas_schedule(&self) -> Option<&GasSchedule> {
+        self.blockchain_ref().gas_schedule.as_ref()
+    }
+
+    pub fn gas_left(&self) -> u64 {
+        self.gas_left.get()
+    }
+
+    /// Gas consumed so far. Always zero when the blockchain mock has no gas schedule.
+    pub fn gas_used(&self) -> u64 {
+        self.input_ref().gas_limit - self.gas_left()
+    }
+
+    /// Charges the cost computed from the gas schedule, if there is one.
+    /// Kills the execution with an out of gas error (status 5) if there is not enough gas left.
+    pub fn use_gas<F>(&self, cost_fn: F)
+    where
+        F: FnOnce(&GasSchedule) -> u64,
+    {
+        if let Some(gas_schedule) = self.gas_schedule() {
+            self.use_gas_amount(cost_fn(gas_schedule));
+        }
+    }
+
+    /// Same as `use_gas`, but for amounts already known, e.g. gas spent by a nested call.
+    pub fn use_gas_amount(&self, amount: u64) {
+        if self.gas_schedule().is_none() {
+            return;
+        }
+        let gas_left = self.gas_left();
+        if amount > gas_left {
+            self.gas_left.set(0);
+            std::panic::panic_any(TxPanic {
+                status: 5,
+                message: "not enough gas".to_string(),
+            });
+        }
+        self.gas_left.set(gas_left - amount);
+    }
+
     pub fn create_new_contract(
         &self,
         new_address: &Address,
@@ -0,0 +1,237 @@
+use crate::num_bigint::BigUint;
+use num_traits::{One, Zero};
+
+/// Affine point coordinates. As in the VM, `(0, 0)` stands for the point at infinity.
+pub type EllipticCurvePoint = (BigUint, BigUint);
+
+/// A NIST prime curve, `y^2 = x^3 - 3x + b` over the field of order `p`.
+#[derive(Clone, Debug)]
+pub struct EllipticCurveData {
+    pub field_order: BigUint,
+    pub base_point_order: BigUint,
+    pub eq_constant: BigUint,
+    pub x_base_point: BigUint,
+    pub y_base_point: BigUint,
+    pub size_of_field: u32,
+}
+
+fn from_hex(hex: &str) -> BigUint {
+    BigUint::parse_bytes(hex.as_bytes(), 16).unwrap()
+}
+
+impl EllipticCurveData {
+    fn from_hex_params(p: &str, n: &str, b: &str, gx: &str, gy: &str, size_of_field: u32) -> Self {
+        EllipticCurveData {
+            field_order: from_hex(p),
+            base_point_order: from_hex(n),
+            eq_constant: from_hex(b),
+            x_base_point: from_hex(gx),
+            y_base_point: from_hex(gy),
+            size_of_field,
+        }
+    }
+
+    /// Only `p224`, `p256`, `p384` and `p521` are supported, same as in the VM.
+    pub fn from_name(name: &[u8]) -> Option<Self> {
+        match name {
+            b"p224" => Some(Self::from_hex_params(
+                "ffffffffffffffffffffffffffffffff000000000000000000000001",
+                "ffffffffffffffffffffffffffff16a2e0b8f03e13dd29455c5c2a3d",
+                "b4050a850c04b3abf54132565044b0b7d7bfd8ba270b39432355ffb4",
+                "b70e0cbd6bb4bf7f321390b94a03c1d356c21122343280d6115c1d21",
+                "bd376388b5f723fb4c22dfe6cd4375a05a07476444d5819985007e34",
+                224,
+            )),
+            b"p256" => Some(Self::from_hex_params(
+                "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff",
+                "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551",
+                "5ac635d8aa3a93e7b3ebbd55769886bc651d06b0cc53b0f63bce3c3e27d2604b",
+                "6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296",
+                "4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5",
+                256,
+            )),
+            b"p384" => Some(Self::from_hex_params(
+                "fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffeffffffff0000000000000000ffffffff",
+                "ffffffffffffffffffffffffffffffffffffffffffffffffc7634d81f4372ddf581a0db248b0a77aecec196accc52973",
+                "b3312fa7e23ee7e4988e056be3f82d19181d9c6efe8141120314088f5013875ac656398d8a2ed19d2a85c8edd3ec2aef",
+                "aa87ca22be8b05378eb1c71ef320ad746e1d3b628ba79b9859f741e082542a385502f25dbf55296c3a545e3872760ab7",
+                "3617de4a96262c6f5d9e98bf9292dc29f8f41dbd289a147ce9da3113b5f0b8c00a60b1ce1d7e819d7a431d7c90ea0e5f",
+                384,
+            )),
+            b"p521" => Some(Self::from_hex_params(
+                "1ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+                "1fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffa51868783bf2f966b7fcc0148f709a5d03bb5c9b8899c47aebb6fb71e91386409",
+                "51953eb9618e1c9a1f929a21a0b68540eea2da725b99b315f3b8b489918ef109e156193951ec7e937b1652c0bd3bb1bf073573df883d2c34f1ef451fd46b503f00",
+                "c6858e06b70404e9cd9e3ecb662395b4429c648139053fb521f828af606b4d3dbaa14b5e77efe75928fe1dc127a2ffa8de3348b3c1856a429bf97e7e31c2e5bd66",
+                "11839296a789a3bc0045c8a5fb42c7d1bd998f54449579b446817afbd17273e662c97ee72995ef42640c550b9013fad0761353c7086a272c24088be94769fd16650",
+                521,
+            )),
+            _ => None,
+        }
+    }
+
+    /// Length in bytes of a coordinate, as well as of a private key.
+    pub fn byte_length(&self) -> usize {
+        (self.size_of_field as usize + 7) / 8
+    }
+
+    pub fn base_point(&self) -> EllipticCurvePoint {
+        (self.x_base_point.clone(), self.y_base_point.clone())
+    }
+
+    /// `x^3 - 3x + b`
+    fn curve_equation_rhs(&self, x: &BigUint) -> BigUint {
+        let p = &self.field_order;
+        let x_cubed = x * x % p * x % p;
+        let three_x = x * 3u32 % p;
+        (x_cubed + p - three_x + &self.eq_constant) % p
+    }
+
+    pub fn is_on_curve(&self, point: &EllipticCurvePoint) -> bool {
+        let (x, y) = point;
+        let p = &self.field_order;
+        if x >= p || y >= p {
+            return false;
+        }
+        y * y % p == self.curve_equation_rhs(x)
+    }
+
+    fn sub_mod(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        let p = &self.field_order;
+        (a + p - b % p) % p
+    }
+
+    fn inv_mod(&self, a: &BigUint) -> BigUint {
+        let p = &self.field_order;
+        a.modpow(&(p - 2u32), p)
+    }
+
+    pub fn add(
+        &self,
+        first: &EllipticCurvePoint,
+        second: &EllipticCurvePoint,
+    ) -> EllipticCurvePoint {
+        if is_infinity(first) {
+            return second.clone();
+        }
+        if is_infinity(second) {
+            return first.clone();
+        }
+        let p = &self.field_order;
+        let (x1, y1) = first;
+        let (x2, y2) = second;
+        if x1 == x2 {
+            if (y1 + y2) % p == BigUint::zero() {
+                return infinity();
+            }
+            return self.double(first);
+        }
+
+        let slope = self.sub_mod(y2, y1) * self.inv_mod(&self.sub_mod(x2, x1)) % p;
+        self.point_from_slope(&slope, first, x2)
+    }
+
+    pub fn double(&self, point: &EllipticCurvePoint) -> EllipticCurvePoint {
+        let p = &self.field_order;
+        let (x, y) = point;
+        if is_infinity(point) || y.is_zero() {
+            return infinity();
+        }
+
+        let numerator = self.sub_mod(&(x * x * 3u32), &BigUint::from(3u32));
+        let slope = numerator * self.inv_mod(&(y * 2u32 % p)) % p;
+        self.point_from_slope(&slope, point, x)
+    }
+
+    /// The third intersection of the line through `point` with the given slope, mirrored.
+    fn point_from_slope(
+        &self,
+        slope: &BigUint,
+        point: &EllipticCurvePoint,
+        other_x: &BigUint,
+    ) -> EllipticCurvePoint {
+        let p = &self.field_order;
+        let (x1, y1) = point;
+        let x3 = self.sub_mod(&self.sub_mod(&(slope * slope), x1), other_x);
+        let y3 = self.sub_mod(&(slope * self.sub_mod(x1, &x3)), y1);
+        (x3 % p, y3)
+    }
+
+    /// The scalar is a big endian unsigned integer, of any length.
+    pub fn scalar_mult(&self, point: &EllipticCurvePoint, scalar: &[u8]) -> EllipticCurvePoint {
+        let mut result = infinity();
+        for byte in scalar {
+            for bit_index in (0..8).rev() {
+                result = self.double(&result);
+                if (byte >> bit_index) & 1 == 1 {
+                    result = self.add(&result, point);
+                }
+            }
+        }
+        result
+    }
+
+    /// Recovers the y coordinate with the given parity, if `x` is the coordinate of a curve point.
+    pub fn decompress(&self, x: &BigUint, y_is_odd: bool) -> Option<EllipticCurvePoint> {
+        let p = &self.field_order;
+        if x >= p {
+            return None;
+        }
+        let mut y = mod_sqrt(&self.curve_equation_rhs(x), p)?;
+        if y.bit(0) != y_is_odd {
+            y = self.sub_mod(&BigUint::zero(), &y);
+        }
+        Some((x.clone(), y))
+    }
+}
+
+pub fn infinity() -> EllipticCurvePoint {
+    (BigUint::zero(), BigUint::zero())
+}
+
+fn is_infinity(point: &EllipticCurvePoint) -> bool {
+    point.0.is_zero() && point.1.is_zero()
+}
+
+/// Tonelli-Shanks square root, modulo an odd prime.
+fn mod_sqrt(a: &BigUint, p: &BigUint) -> Option<BigUint> {
+    let a = a % p;
+    if a.is_zero() {
+        return Some(a);
+    }
+    let p_minus_one = p - 1u32;
+    if a.modpow(&(&p_minus_one >> 1), p) != BigUint::one() {
+        return None;
+    }
+
+    let mut s = 0u32;
+    let mut q = p_minus_one.clone();
+    while !q.bit(0) {
+        q >>= 1;
+        s += 1;
+    }
+
+    let mut z = BigUint::from(2u32);
+    while z.modpow(&(&p_minus_one >> 1), p) != p_minus_one {
+        z += 1u32;
+    }
+
+    let mut m = s;
+    let mut c = z.modpow(&q, p);
+    let mut t = a.modpow(&q, p);
+    let mut r = a.modpow(&((&q + 1u32) >> 1), p);
+    while !t.is_one() {
+        let mut i = 0u32;
+        let mut t_pow = t.clone();
+        while !t_pow.is_one() {
+            t_pow = &t_pow * &t_pow % p;
+            i += 1;
+        }
+        let b = c.modpow(&(BigUint::one() << (m - i - 1)), p);
+        m = i;
+        c = &b * &b % p;
+        t = t * &c % p;
+        r = r * b % p;
+    }
+    Some(r)
+}
@@ -1,3 +1,4 @@
+use super::EllipticCurveData;
 use crate::num_bigint::BigInt;
 use dharitri_wasm::api::{const_handles, Handle};
 use std::collections::HashMap;
@@ -50,6 +51,7 @@ impl<V> HandleMap<V> {
 pub struct TxManagedTypes {
     pub(crate) big_int_map: HandleMap<BigInt>,
     pub(crate) managed_buffer_map: HandleMap<ManagedBufferImpl>,
+    pub(crate) elliptic_curve_map: HandleMap<EllipticCurveData>,
 }
 
 impl TxManagedTypes {
@@ -57,6 +59,7 @@ impl TxManagedTypes {
         TxManagedTypes {
             big_int_map: HandleMap::new(),
             managed_buffer_map: HandleMap::new(),
+            elliptic_curve_map: HandleMap::new(),
         }
     }
 }
@@ -1,8 +1,9 @@
 use alloc::vec::Vec;
+use dharitri_wasm::dharitri_codec::decode_context::{take_last_error_context, DecodeErrorContext};
 
 use std::fmt;
 
-use super::{TxLog, TxPanic, TxResultCalls};
+use super::{TxInputDCT, TxLog, TxPanic, TxResultCalls};
 
 #[derive(Clone, Default, Debug)]
 #[must_use]
@@ -12,6 +13,13 @@ pub struct TxResult {
     pub result_values: Vec<Vec<u8>>,
     pub result_logs: Vec<TxLog>,
     pub result_calls: TxResultCalls,
+    /// Only measured when the blockchain mock has a gas schedule.
+    pub gas_used: u64,
+    /// DCT sent back to the caller of an async call, the callback receives them as call value.
+    pub back_transfers: Vec<TxInputDCT>,
+    /// Where decoding failed, if the error comes from the codec, e.g. `Order.items[3].price, byte 57`.
+    /// Kept out of the result message, so that it stays the same as the one from the VM.
+    pub decode_error_context: Option<DecodeErrorContext>,
 }
 
 impl TxResult {
@@ -22,6 +30,9 @@ impl TxResult {
             result_values: Vec::new(),
             result_logs: Vec::new(),
             result_calls: TxResultCalls::empty(),
+            gas_used: 0,
+            back_transfers: Vec::new(),
+            decode_error_context: None,
         }
     }
 
@@ -36,6 +47,9 @@ impl TxResult {
             result_values: Vec::new(),
             result_logs: Vec::new(),
             result_calls: TxResultCalls::empty(),
+            gas_used: 0,
+            back_transfers: Vec::new(),
+            decode_error_context: take_last_error_context(),
         }
     }
 
@@ -47,6 +61,9 @@ impl TxResult {
             result_values: Vec::new(),
             result_logs: Vec::new(),
             result_calls: TxResultCalls::empty(),
+            gas_used: 0,
+            back_transfers: Vec::new(),
+            decode_error_context: None,
         }
     }
 
@@ -61,6 +78,9 @@ impl TxResult {
             result_values: Vec::new(),
             result_logs: Vec::new(),
             result_calls: TxResultCalls::empty(),
+            gas_used: 0,
+            back_transfers: Vec::new(),
+            decode_error_context: None,
         }
     }
 
@@ -78,31 +98,44 @@ impl TxResult {
         }
     }
 
+    /// Points to the value that failed to decode, if any, to be appended to the result message.
+    /// Only meant for test failure messages.
+    pub fn decode_error_context_suffix(&self) -> String {
+        if let Some(context) = &self.decode_error_context {
+            format!(" (at {})", context)
+        } else {
+            String::new()
+        }
+    }
+
     pub fn assert_ok(&self) {
         assert!(
             self.result_status == 0,
-            "Tx success expected, but failed. Status: {}, message: \"{}\"",
+            "Tx success expected, but failed. Status: {}, message: \"{}\"{}",
             self.result_status,
-            self.result_message.as_str()
+            self.result_message.as_str(),
+            self.decode_error_context_suffix()
         );
     }
 
     pub fn assert_error(&self, expected_status: u64, expected_message: &str) {
         assert!(
             self.result_message.as_str() == expected_message,
-            "Tx error message mismatch. Want status {}, message \"{}\". Have status {}, message \"{}\"",
+            "Tx error message mismatch. Want status {}, message \"{}\". Have status {}, message \"{}\"{}",
             expected_status,
             expected_message,
             self.result_status,
-            self.result_message.as_str()
+            self.result_message.as_str(),
+            self.decode_error_context_suffix()
         );
         assert!(
             self.result_status == expected_status,
-            "Tx error status mismatch. Want status {}, message \"{}\". Have status {}, message \"{}\"",
+            "Tx error status mismatch. Want status {}, message \"{}\". Have status {}, message \"{}\"{}",
             expected_status,
             expected_message,
             self.result_status,
-            self.result_message.as_str()
+            self.result_message.as_str(),
+            self.decode_error_context_suffix()
         );
     }
 
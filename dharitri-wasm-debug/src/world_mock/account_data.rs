@@ -21,6 +21,22 @@ pub struct AccountData {
     pub contract_owner: Option<Address>,
 }
 
+impl AccountData {
+    /// An account with only an MOAX balance, no DCT, storage or code.
+    pub fn new(address: Address, moax_balance: BigUint) -> Self {
+        AccountData {
+            address,
+            nonce: 0,
+            moax_balance,
+            dct: AccountDct::default(),
+            storage: HashMap::new(),
+            username: Vec::new(),
+            contract_path: None,
+            contract_owner: None,
+        }
+    }
+}
+
 impl fmt::Display for AccountData {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut storage_buf = String::new();
@@ -1,4 +1,6 @@
-use crate::{num_bigint::BigUint, tx_mock::BlockchainUpdate, ContractMap};
+use crate::{
+    coverage::CoverageRecorder, num_bigint::BigUint, tx_mock::BlockchainUpdate, ContractMap,
+};
 use dharitri_wasm::types::heap::Address;
 use denali::{
     interpret_trait::{InterpreterContext, IntoRaw},
@@ -7,23 +9,29 @@ use denali::{
 };
 use num_traits::Zero;
 use std::{
+    cell::RefCell,
     collections::HashMap,
     path::{Path, PathBuf},
+    rc::Rc,
 };
 
-use super::{AccountData, BlockInfo};
+use super::{AccountData, BlockInfo, BlockchainSnapshot, GasSchedule};
 
 const DHARITRI_REWARD_KEY: &[u8] = b"DHARITRIreward";
 
 #[derive(Debug)]
 pub struct BlockchainMock {
-    pub accounts: HashMap<Address, AccountData>,
+    /// Accounts are shared with the snapshots and only copied when modified.
+    pub accounts: HashMap<Address, Rc<AccountData>>,
     pub new_addresses: HashMap<(Address, u64), Address>,
     pub previous_block_info: BlockInfo,
     pub current_block_info: BlockInfo,
     pub contract_map: ContractMap,
     pub current_dir: PathBuf,
     pub denali_trace: Scenario,
+    pub gas_schedule: Option<GasSchedule>,
+    pub coverage: Option<RefCell<CoverageRecorder>>,
+    pub snapshots: HashMap<String, BlockchainSnapshot>,
 }
 
 impl BlockchainMock {
@@ -36,6 +44,9 @@ impl BlockchainMock {
             contract_map: ContractMap::default(),
             current_dir: std::env::current_dir().unwrap(),
             denali_trace: Scenario::default(),
+            gas_schedule: None,
+            coverage: None,
+            snapshots: HashMap::new(),
         }
     }
 }
@@ -64,23 +75,24 @@ impl BlockchainMock {
         updates.apply(self);
     }
 
-    pub fn increase_account_nonce(&mut self, address: &Address) {
+    /// Unshares the account from any snapshot before it is modified.
+    fn account_mut(&mut self, address: &Address) -> &mut AccountData {
         let account = self.accounts.get_mut(address).unwrap_or_else(|| {
             panic!(
                 "Account not found: {}",
                 &std::str::from_utf8(address.as_ref()).unwrap()
             )
         });
+        Rc::make_mut(account)
+    }
+
+    pub fn increase_account_nonce(&mut self, address: &Address) {
+        let account = self.account_mut(address);
         account.nonce += 1;
     }
 
     pub fn subtract_tx_gas(&mut self, address: &Address, gas_limit: u64, gas_price: u64) {
-        let account = self.accounts.get_mut(address).unwrap_or_else(|| {
-            panic!(
-                "Account not found: {}",
-                &std::str::from_utf8(address.as_ref()).unwrap()
-            )
-        });
+        let account = self.account_mut(address);
         let gas_cost = BigUint::from(gas_limit) * BigUint::from(gas_price);
         assert!(
             account.moax_balance >= gas_cost,
@@ -90,12 +102,7 @@ impl BlockchainMock {
     }
 
     pub fn increase_validator_reward(&mut self, address: &Address, amount: &BigUint) {
-        let account = self.accounts.get_mut(address).unwrap_or_else(|| {
-            panic!(
-                "Account not found: {}",
-                &std::str::from_utf8(address.as_ref()).unwrap()
-            )
-        });
+        let account = self.account_mut(address);
         account.moax_balance += amount;
         let mut storage_v_rew =
             if let Some(old_storage_value) = account.storage.get(DHARITRI_REWARD_KEY) {
@@ -1,7 +1,7 @@
 use alloc::vec::Vec;
-use dharitri_wasm::types::heap::Address;
+use dharitri_wasm::{dct::DCT_SYSTEM_SC_ADDRESS_ARRAY, types::heap::Address};
 
-use std::fmt::Write;
+use std::{fmt::Write, rc::Rc};
 
 use crate::address_hex;
 
@@ -13,7 +13,11 @@ use super::BlockchainMock;
 
 impl BlockchainMock {
     pub fn add_account(&mut self, acct: AccountData) {
-        self.accounts.insert(acct.address.clone(), acct);
+        let address = acct.address.clone();
+        self.accounts.insert(address.clone(), Rc::new(acct));
+        if !is_system_sc_address(&address) {
+            self.update_transfer_role_holders(&address);
+        }
     }
 
     pub fn validate_and_add_account(&mut self, acct: AccountData) {
@@ -46,6 +50,11 @@ impl BlockchainMock {
     }
 
     pub fn validate_account(&self, account: &AccountData) {
+        if is_system_sc_address(&account.address) {
+            // the DCT system SC is built into the mock, it only has code when replaced by a contract
+            return;
+        }
+
         let is_sc = is_smart_contract_address(&account.address);
         let has_code = self.check_account_has_code(account);
 
@@ -76,3 +85,7 @@ pub fn is_smart_contract_address(address: &Address) -> bool {
         .take(SC_ADDRESS_NUM_LEADING_ZEROS.into())
         .all(|item| item == &0u8)
 }
+
+pub fn is_system_sc_address(address: &Address) -> bool {
+    address.as_array() == &DCT_SYSTEM_SC_ADDRESS_ARRAY
+}
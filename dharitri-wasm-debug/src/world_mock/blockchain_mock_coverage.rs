@@ -0,0 +1,54 @@
+use dharitri_wasm::abi::ContractAbi;
+use std::cell::RefCell;
+
+use crate::coverage::{write_coverage_report, CoverageRecorder};
+
+use super::BlockchainMock;
+
+impl BlockchainMock {
+    /// Starts recording endpoint calls, user errors and storage keys accessed.
+    ///
+    /// The report directory is relative to the current dir.
+    /// The Rust denali runner writes the report there after each scenario,
+    /// cumulated with all other scenarios run by the same test process.
+    pub fn enable_coverage(&mut self, relative_report_dir: &str) {
+        let mut report_dir = self.current_dir.clone();
+        report_dir.push(relative_report_dir);
+        self.coverage = Some(RefCell::new(CoverageRecorder::new(report_dir)));
+    }
+
+    /// Lists all endpoints of the contract in the report, so the ones not covered by any scenario can be spotted.
+    pub fn register_coverage_abi(&mut self, contract_expression: &str, abi: &ContractAbi) {
+        let coverage = self
+            .coverage
+            .as_mut()
+            .expect("coverage not enabled, call enable_coverage first");
+        let endpoint_names = abi
+            .constructors
+            .iter()
+            .map(|_| "init")
+            .chain(abi.endpoints.iter().map(|endpoint| endpoint.name));
+        coverage
+            .get_mut()
+            .report
+            .add_known_endpoints(contract_expression, endpoint_names);
+    }
+
+    pub fn with_coverage<F>(&self, f: F)
+    where
+        F: FnOnce(&mut CoverageRecorder),
+    {
+        if let Some(coverage) = &self.coverage {
+            f(&mut coverage.borrow_mut());
+        }
+    }
+
+    /// Adds the coverage recorded so far to the report files, then starts again from scratch.
+    pub fn write_coverage_report(&mut self) {
+        if let Some(coverage) = &mut self.coverage {
+            let coverage = coverage.get_mut();
+            let report = core::mem::take(&mut coverage.report);
+            write_coverage_report(coverage.report_dir.as_path(), &report);
+        }
+    }
+}
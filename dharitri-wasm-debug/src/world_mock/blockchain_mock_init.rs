@@ -5,7 +5,7 @@ use denali::{interpret_trait::InterpreterContext, value_interpreter::interpret_s
 
 use crate::DebugApi;
 
-use super::BlockchainMock;
+use super::{BlockchainMock, GasSchedule};
 
 fn is_target(path_buf: &Path) -> bool {
     path_buf.file_name().unwrap() == "target"
@@ -36,6 +36,18 @@ impl BlockchainMock {
         self.current_dir = path;
     }
 
+    /// Enables gas metering: from now on, VM API calls are charged according to the schedule.
+    pub fn set_gas_schedule(&mut self, gas_schedule: GasSchedule) {
+        self.gas_schedule = Some(gas_schedule);
+    }
+
+    /// Loads a gas schedule TOML file, with the path relative to the current dir.
+    pub fn load_gas_schedule(&mut self, relative_path: &str) {
+        let mut path = self.current_dir.clone();
+        path.push(relative_path);
+        self.set_gas_schedule(GasSchedule::load_from_file(path));
+    }
+
     pub fn register_contract_obj(
         &mut self,
         expression: &str,
@@ -43,6 +55,8 @@ impl BlockchainMock {
     ) {
         let contract_bytes = interpret_string(expression, &self.interpreter_context());
         // panic!("{}", String::from_utf8(contract_bytes).unwrap());
+        self.contract_map
+            .register_contract_name(contract_bytes.clone(), expression);
         self.contract_map
             .register_contract(contract_bytes, new_contract_obj);
     }
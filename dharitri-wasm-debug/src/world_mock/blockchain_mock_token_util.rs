@@ -0,0 +1,78 @@
+use dharitri_wasm::types::{heap::Address, DctLocalRole};
+use std::rc::Rc;
+
+use crate::tx_mock::system_sc_address;
+
+use super::{AccountData, BlockchainMock, SystemScTokenData};
+
+impl BlockchainMock {
+    pub fn get_system_sc_token_data(&self, token_identifier: &[u8]) -> Option<SystemScTokenData> {
+        self.accounts
+            .get(&system_sc_address())
+            .and_then(|account| account.storage.get(token_identifier))
+            .map(|value| SystemScTokenData::from_storage_value(value.as_slice()))
+    }
+
+    /// Changes the token-level settings kept by the system SC.
+    ///
+    /// Also works for tokens that were not issued via the system SC, e.g. set up in `setState`,
+    /// their settings start out with no owner and no properties.
+    pub fn update_system_sc_token_data<F>(&mut self, token_identifier: &[u8], f: F)
+    where
+        F: FnOnce(&mut SystemScTokenData),
+    {
+        let mut token_data = self
+            .get_system_sc_token_data(token_identifier)
+            .unwrap_or_else(SystemScTokenData::not_issued);
+        f(&mut token_data);
+
+        let address = system_sc_address();
+        let account = self
+            .accounts
+            .entry(address.clone())
+            .or_insert_with(|| Rc::new(AccountData::new(address, 0u32.into())));
+        Rc::make_mut(account)
+            .storage
+            .insert(token_identifier.to_vec(), token_data.to_storage_value());
+    }
+
+    /// Keeps the limited transfer state of the tokens in sync with the transfer roles of the account,
+    /// for roles that were not given via the system SC, e.g. in `setState`.
+    pub fn update_transfer_role_holders(&mut self, address: &Address) {
+        let transfer_role = DctLocalRole::Transfer.as_role_name();
+        let token_roles: Vec<(Vec<u8>, bool)> = match self.accounts.get(address) {
+            Some(account) => account
+                .dct
+                .iter()
+                .map(|(token_identifier, dct_data)| {
+                    let has_transfer_role = dct_data
+                        .get_roles()
+                        .iter()
+                        .any(|role| role.as_slice() == transfer_role);
+                    (token_identifier.clone(), has_transfer_role)
+                })
+                .collect(),
+            None => return,
+        };
+
+        for (token_identifier, has_transfer_role) in token_roles {
+            let is_holder = self
+                .get_system_sc_token_data(token_identifier.as_slice())
+                .map_or(false, |token_data| {
+                    token_data.transfer_role_holders.contains(address)
+                });
+            if has_transfer_role == is_holder {
+                continue;
+            }
+            self.update_system_sc_token_data(token_identifier.as_slice(), |token_data| {
+                if has_transfer_role {
+                    token_data.transfer_role_holders.push(address.clone());
+                } else {
+                    token_data
+                        .transfer_role_holders
+                        .retain(|holder| holder != address);
+                }
+            });
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use denali::model::Scenario;
+use dharitri_wasm::types::heap::Address;
+use std::{collections::HashMap, rc::Rc};
+
+use super::{AccountData, BlockInfo, BlockchainMock};
+
+/// A copy of everything that transactions can change: accounts, new address predictions and block info.
+/// Registered contracts, the gas schedule and the other settings of the mock are not part of it.
+///
+/// Accounts are shared with the mock, so taking or restoring a snapshot does not copy their contents.
+#[derive(Clone, Debug)]
+pub struct BlockchainSnapshot {
+    accounts: HashMap<Address, Rc<AccountData>>,
+    new_addresses: HashMap<(Address, u64), Address>,
+    previous_block_info: BlockInfo,
+    current_block_info: BlockInfo,
+}
+
+impl BlockchainMock {
+    pub fn take_snapshot(&self) -> BlockchainSnapshot {
+        BlockchainSnapshot {
+            accounts: self.accounts.clone(),
+            new_addresses: self.new_addresses.clone(),
+            previous_block_info: self.previous_block_info.clone(),
+            current_block_info: self.current_block_info.clone(),
+        }
+    }
+
+    pub fn restore_snapshot(&mut self, snapshot: &BlockchainSnapshot) {
+        self.accounts = snapshot.accounts.clone();
+        self.new_addresses = snapshot.new_addresses.clone();
+        self.previous_block_info = snapshot.previous_block_info.clone();
+        self.current_block_info = snapshot.current_block_info.clone();
+    }
+
+    /// Saves the current state under the given id, replacing any previous snapshot with the same id.
+    pub fn save_named_snapshot(&mut self, id: &str) {
+        let snapshot = self.take_snapshot();
+        self.snapshots.insert(id.to_string(), snapshot);
+    }
+
+    /// The snapshot is kept, so the same state can be restored several times.
+    pub fn restore_named_snapshot(&mut self, id: &str) {
+        let snapshot = self
+            .snapshots
+            .remove(id)
+            .unwrap_or_else(|| panic!("snapshot not found: {}", id));
+        self.restore_snapshot(&snapshot);
+        self.snapshots.insert(id.to_string(), snapshot);
+    }
+
+    /// An independent copy of the mock, with the same state, contracts and settings.
+    /// The copy starts with an empty denali trace and does not record coverage.
+    pub fn fork(&self) -> BlockchainMock {
+        BlockchainMock {
+            accounts: self.accounts.clone(),
+            new_addresses: self.new_addresses.clone(),
+            previous_block_info: self.previous_block_info.clone(),
+            current_block_info: self.current_block_info.clone(),
+            contract_map: self.contract_map.clone(),
+            current_dir: self.current_dir.clone(),
+            denali_trace: Scenario::default(),
+            gas_schedule: self.gas_schedule.clone(),
+            coverage: None,
+            snapshots: self.snapshots.clone(),
+        }
+    }
+}
@@ -57,6 +57,18 @@ impl AccountDct {
         dct_data.roles = DctRoles::new(roles);
     }
 
+    pub fn set_frozen(&mut self, token_identifier: Vec<u8>, frozen: bool) {
+        let dct_data = self.0.entry(token_identifier).or_default();
+        dct_data.frozen = frozen;
+    }
+
+    /// Removes all instances of a token, but keeps the roles and the frozen flag.
+    pub fn wipe(&mut self, token_identifier: &[u8]) {
+        if let Some(dct_data) = self.0.get_mut(token_identifier) {
+            dct_data.instances = DctInstances::new();
+        }
+    }
+
     /// Will provide a clone.
     pub fn get_by_identifier_or_default(&self, identifier: &[u8]) -> DctData {
         if let Some(value) = self.0.get(identifier) {
@@ -0,0 +1,93 @@
+use serde::Deserialize;
+use std::path::Path;
+
+/// Gas costs charged by the mock when executing VM API calls.
+///
+/// Mirrors the layout of the VM gas schedule TOML files, so those can be loaded directly.
+/// Sections and costs the mock does not use are ignored, missing ones default to zero.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct GasSchedule {
+    pub base_operation_cost: BaseOperationCost,
+    #[serde(rename = "DharitriAPICost")]
+    pub dharitri_api_cost: DharitriApiCost,
+    #[serde(rename = "BigIntAPICost")]
+    pub big_int_api_cost: BigIntApiCost,
+    #[serde(rename = "ManagedBufferAPICost")]
+    pub managed_buffer_api_cost: ManagedBufferApiCost,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct BaseOperationCost {
+    pub store_per_byte: u64,
+    pub data_copy_per_byte: u64,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct DharitriApiCost {
+    pub get_gas_left: u64,
+    pub storage_load: u64,
+    pub storage_store: u64,
+    pub transfer_value: u64,
+    pub async_call_step: u64,
+    pub execute_on_dest_context: u64,
+    pub create_contract: u64,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct BigIntApiCost {
+    pub big_int_new: u64,
+    pub big_int_get_bytes: u64,
+    pub big_int_set_bytes: u64,
+    pub big_int_add: u64,
+    pub big_int_sub: u64,
+    pub big_int_mul: u64,
+    pub big_int_t_div: u64,
+    pub big_int_t_mod: u64,
+    pub big_int_abs: u64,
+    pub big_int_neg: u64,
+    pub big_int_sign: u64,
+    pub big_int_cmp: u64,
+    pub big_int_sqrt: u64,
+    pub big_int_pow: u64,
+    pub big_int_log: u64,
+    pub big_int_and: u64,
+    pub big_int_or: u64,
+    pub big_int_xor: u64,
+    pub big_int_shr: u64,
+    pub big_int_shl: u64,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct ManagedBufferApiCost {
+    pub m_buffer_new: u64,
+    pub m_buffer_new_from_bytes: u64,
+    pub m_buffer_get_length: u64,
+    pub m_buffer_get_byte_slice: u64,
+    pub m_buffer_copy_byte_slice: u64,
+    pub m_buffer_set_byte_slice: u64,
+    pub m_buffer_append: u64,
+    pub m_buffer_append_bytes: u64,
+    pub m_buffer_set_random: u64,
+}
+
+impl GasSchedule {
+    pub fn from_toml_str(toml_str: &str) -> Self {
+        toml::from_str(toml_str).unwrap_or_else(|err| panic!("error parsing gas schedule: {}", err))
+    }
+
+    pub fn load_from_file<P: AsRef<Path>>(path: P) -> Self {
+        let toml_str = std::fs::read_to_string(path.as_ref()).unwrap_or_else(|err| {
+            panic!(
+                "error reading gas schedule file {}: {}",
+                path.as_ref().display(),
+                err
+            )
+        });
+        Self::from_toml_str(toml_str.as_str())
+    }
+}
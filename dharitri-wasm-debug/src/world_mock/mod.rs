@@ -2,21 +2,29 @@ mod account_data;
 mod block_info;
 mod blockchain_mock;
 mod blockchain_mock_account_util;
+mod blockchain_mock_coverage;
 mod blockchain_mock_init;
+mod blockchain_mock_token_util;
+mod blockchain_snapshot;
 mod blockchain_tx_info;
 mod dct_data;
 mod dct_instance;
 mod dct_instance_metadata;
 mod dct_instances;
 mod dct_roles;
+mod gas_schedule;
+mod system_sc_token_data;
 
 pub use account_data::*;
 pub use block_info::*;
 pub use blockchain_mock::*;
-pub use blockchain_mock_account_util::is_smart_contract_address;
+pub use blockchain_mock_account_util::{is_smart_contract_address, is_system_sc_address};
+pub use blockchain_snapshot::BlockchainSnapshot;
 pub use blockchain_tx_info::*;
 pub use dct_data::*;
 pub use dct_instance::*;
 pub use dct_instance_metadata::*;
 pub use dct_instances::*;
 pub use dct_roles::*;
+pub use gas_schedule::*;
+pub use system_sc_token_data::*;
@@ -0,0 +1,111 @@
+use dharitri_wasm::{
+    dharitri_codec::{top_encode_to_vec_u8_or_panic, TopDecode},
+    types::heap::Address,
+};
+
+pub const FUNGIBLE_TOKEN_TYPE: &[u8] = b"FungibleDCT";
+pub const NON_FUNGIBLE_TOKEN_TYPE: &[u8] = b"NonFungibleDCT";
+pub const SEMI_FUNGIBLE_TOKEN_TYPE: &[u8] = b"SemiFungibleDCT";
+pub const META_TOKEN_TYPE: &[u8] = b"MetaDCT";
+
+/// What the DCT system SC knows about an issued token.
+///
+/// Kept in the storage of the system SC account, under the token identifier,
+/// so it gets committed and rolled back together with the rest of the transaction.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SystemScTokenData {
+    pub owner: Address,
+    pub token_type: Vec<u8>,
+    pub name: Vec<u8>,
+    pub ticker: Vec<u8>,
+    pub num_decimals: u32,
+    pub paused: bool,
+    /// Names of the properties set to `true`, e.g. `canFreeze`.
+    pub properties: Vec<Vec<u8>>,
+    /// Addresses that hold the transfer role.
+    /// As long as there are any, the token can only be transferred to or from one of them.
+    pub transfer_role_holders: Vec<Address>,
+}
+
+type SystemScTokenDataTuple = (
+    Address,
+    Vec<u8>,
+    Vec<u8>,
+    Vec<u8>,
+    u32,
+    bool,
+    Vec<Vec<u8>>,
+    Vec<Address>,
+);
+
+impl SystemScTokenData {
+    /// Settings of tokens that only exist in the initial state, e.g. set up in `setState`.
+    pub fn not_issued() -> Self {
+        SystemScTokenData {
+            owner: Address::zero(),
+            token_type: Vec::new(),
+            name: Vec::new(),
+            ticker: Vec::new(),
+            num_decimals: 0,
+            paused: false,
+            properties: Vec::new(),
+            transfer_role_holders: Vec::new(),
+        }
+    }
+
+    pub fn has_property(&self, property_name: &[u8]) -> bool {
+        self.properties
+            .iter()
+            .any(|property| property.as_slice() == property_name)
+    }
+
+    pub fn set_property(&mut self, property_name: &[u8], value: bool) {
+        self.properties
+            .retain(|property| property.as_slice() != property_name);
+        if value {
+            self.properties.push(property_name.to_vec());
+        }
+    }
+
+    pub fn is_limited_transfer(&self) -> bool {
+        !self.transfer_role_holders.is_empty()
+    }
+
+    pub fn to_storage_value(&self) -> Vec<u8> {
+        let tuple: SystemScTokenDataTuple = (
+            self.owner.clone(),
+            self.token_type.clone(),
+            self.name.clone(),
+            self.ticker.clone(),
+            self.num_decimals,
+            self.paused,
+            self.properties.clone(),
+            self.transfer_role_holders.clone(),
+        );
+        top_encode_to_vec_u8_or_panic(&tuple)
+    }
+
+    pub fn from_storage_value(value: &[u8]) -> Self {
+        let (
+            owner,
+            token_type,
+            name,
+            ticker,
+            num_decimals,
+            paused,
+            properties,
+            transfer_role_holders,
+        ) = SystemScTokenDataTuple::top_decode(value)
+            .unwrap_or_else(|err| panic!("invalid system SC token data: {:?}", err));
+        SystemScTokenData {
+            owner,
+            token_type,
+            name,
+            ticker,
+            num_decimals,
+            paused,
+            properties,
+            transfer_role_holders,
+        }
+    }
+}
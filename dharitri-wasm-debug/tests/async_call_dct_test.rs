@@ -0,0 +1,198 @@
+use dharitri_wasm::{
+    api::{DCT_MULTI_TRANSFER_FUNC_NAME, DCT_NFT_TRANSFER_FUNC_NAME, DCT_TRANSFER_FUNC_NAME},
+    dharitri_codec::top_encode_to_vec_u8,
+    types::heap::{Address, H256},
+};
+use dharitri_wasm_debug::{
+    num_bigint::BigUint,
+    tx_mock::{async_call_tx_input, AsyncCallTxData},
+};
+
+fn sc_address() -> Address {
+    Address::from(&[b's'; 32])
+}
+
+fn dest_address() -> Address {
+    Address::from(&[b'd'; 32])
+}
+
+fn raw_async_call(to: Address, endpoint_name: &[u8], arguments: Vec<Vec<u8>>) -> AsyncCallTxData {
+    AsyncCallTxData {
+        from: sc_address(),
+        to,
+        call_value: BigUint::from(0u32),
+        dct_values: Vec::new(),
+        endpoint_name: endpoint_name.to_vec(),
+        arguments,
+        gas_limit: 1000,
+        tx_hash: H256::zero(),
+    }
+}
+
+/// Unpacking and then building the tx input again should yield the original built-in function call.
+fn check_round_trip(raw: AsyncCallTxData) -> AsyncCallTxData {
+    let mut unpacked = raw.clone();
+    unpacked.unpack_dct_transfer().unwrap();
+
+    let tx_input = async_call_tx_input(&unpacked);
+    assert_eq!(tx_input.to, raw.to);
+    assert_eq!(tx_input.func_name, raw.endpoint_name);
+    assert_eq!(tx_input.args, raw.arguments);
+    assert!(tx_input.dct_values.is_empty());
+
+    unpacked
+}
+
+#[test]
+fn test_unpack_dct_transfer() {
+    let unpacked = check_round_trip(raw_async_call(
+        dest_address(),
+        DCT_TRANSFER_FUNC_NAME,
+        vec![
+            b"TOKEN-123456".to_vec(),
+            vec![3, 232],
+            b"accept_funds".to_vec(),
+            b"arg".to_vec(),
+        ],
+    ));
+
+    assert_eq!(unpacked.to, dest_address());
+    assert_eq!(unpacked.endpoint_name, b"accept_funds".to_vec());
+    assert_eq!(unpacked.arguments, vec![b"arg".to_vec()]);
+    assert_eq!(unpacked.dct_values.len(), 1);
+    assert_eq!(unpacked.dct_values[0].token_identifier, b"TOKEN-123456");
+    assert_eq!(unpacked.dct_values[0].nonce, 0);
+    assert_eq!(unpacked.dct_values[0].value, BigUint::from(1000u32));
+}
+
+#[test]
+fn test_unpack_dct_nft_transfer() {
+    let unpacked = check_round_trip(raw_async_call(
+        sc_address(),
+        DCT_NFT_TRANSFER_FUNC_NAME,
+        vec![
+            b"NFT-123456".to_vec(),
+            top_encode_to_vec_u8(&5u64).unwrap(),
+            vec![1],
+            dest_address().to_vec(),
+        ],
+    ));
+
+    assert_eq!(unpacked.to, dest_address());
+    assert!(unpacked.endpoint_name.is_empty());
+    assert!(unpacked.arguments.is_empty());
+    assert_eq!(unpacked.dct_values.len(), 1);
+    assert_eq!(unpacked.dct_values[0].nonce, 5);
+}
+
+#[test]
+fn test_unpack_dct_multi_transfer() {
+    let unpacked = check_round_trip(raw_async_call(
+        sc_address(),
+        DCT_MULTI_TRANSFER_FUNC_NAME,
+        vec![
+            dest_address().to_vec(),
+            top_encode_to_vec_u8(&2usize).unwrap(),
+            b"TOKEN-123456".to_vec(),
+            Vec::new(),
+            vec![100],
+            b"NFT-123456".to_vec(),
+            vec![2],
+            vec![1],
+            b"accept_funds".to_vec(),
+        ],
+    ));
+
+    assert_eq!(unpacked.to, dest_address());
+    assert_eq!(unpacked.endpoint_name, b"accept_funds".to_vec());
+    assert_eq!(unpacked.dct_values.len(), 2);
+    assert_eq!(unpacked.dct_values[0].value, BigUint::from(100u32));
+    assert_eq!(unpacked.dct_values[1].token_identifier, b"NFT-123456");
+    assert_eq!(unpacked.dct_values[1].nonce, 2);
+}
+
+#[test]
+fn test_unpack_regular_call() {
+    let raw = raw_async_call(dest_address(), b"endpoint", vec![b"arg".to_vec()]);
+    let unpacked = check_round_trip(raw);
+    assert!(unpacked.dct_values.is_empty());
+}
+
+fn unpack_error(raw: AsyncCallTxData) -> String {
+    let mut unpacked = raw;
+    unpacked.unpack_dct_transfer().unwrap_err()
+}
+
+#[test]
+fn test_unpack_dct_transfer_too_few_arguments() {
+    assert_eq!(
+        unpack_error(raw_async_call(
+            dest_address(),
+            DCT_TRANSFER_FUNC_NAME,
+            vec![b"TOKEN-123456".to_vec()],
+        )),
+        "DCTTransfer too few arguments"
+    );
+    assert_eq!(
+        unpack_error(raw_async_call(
+            sc_address(),
+            DCT_NFT_TRANSFER_FUNC_NAME,
+            vec![b"NFT-123456".to_vec(), vec![5], vec![1]],
+        )),
+        "DCTNFTTransfer too few arguments"
+    );
+    assert_eq!(
+        unpack_error(raw_async_call(
+            sc_address(),
+            DCT_MULTI_TRANSFER_FUNC_NAME,
+            vec![dest_address().to_vec()],
+        )),
+        "MultiDCTNFTTransfer too few arguments"
+    );
+}
+
+#[test]
+fn test_unpack_dct_nft_transfer_bad_nonce() {
+    assert_eq!(
+        unpack_error(raw_async_call(
+            sc_address(),
+            DCT_NFT_TRANSFER_FUNC_NAME,
+            vec![
+                b"NFT-123456".to_vec(),
+                vec![1; 9],
+                vec![1],
+                dest_address().to_vec(),
+            ],
+        )),
+        "DCTNFTTransfer invalid argument: input too long"
+    );
+}
+
+#[test]
+fn test_unpack_dct_multi_transfer_bad_payment_count() {
+    // 2 payments announced, only 1 provided
+    assert_eq!(
+        unpack_error(raw_async_call(
+            sc_address(),
+            DCT_MULTI_TRANSFER_FUNC_NAME,
+            vec![
+                dest_address().to_vec(),
+                top_encode_to_vec_u8(&2usize).unwrap(),
+                b"TOKEN-123456".to_vec(),
+                Vec::new(),
+                vec![100],
+            ],
+        )),
+        "MultiDCTNFTTransfer too few arguments"
+    );
+
+    // payment count that does not fit in a usize
+    assert_eq!(
+        unpack_error(raw_async_call(
+            sc_address(),
+            DCT_MULTI_TRANSFER_FUNC_NAME,
+            vec![dest_address().to_vec(), vec![1; 9]],
+        )),
+        "MultiDCTNFTTransfer invalid argument: input too long"
+    );
+}
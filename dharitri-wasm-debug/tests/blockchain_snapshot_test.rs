@@ -0,0 +1,32 @@
+use std::rc::Rc;
+
+use dharitri_wasm::types::heap::Address;
+use dharitri_wasm_debug::{num_bigint::BigUint, world_mock::AccountData, BlockchainMock};
+
+fn user_address() -> Address {
+    Address::from([b'u'; 32])
+}
+
+fn other_address() -> Address {
+    Address::from([b'o'; 32])
+}
+
+#[test]
+fn snapshot_shares_accounts_test() {
+    let mut world = BlockchainMock::new();
+    world.add_account(AccountData::new(user_address(), BigUint::from(1000u32)));
+    world.add_account(AccountData::new(other_address(), BigUint::from(1000u32)));
+
+    world.save_named_snapshot("before");
+    assert_eq!(Rc::strong_count(&world.accounts[&user_address()]), 2);
+
+    // only the modified account is copied
+    world.increase_account_nonce(&user_address());
+    assert_eq!(Rc::strong_count(&world.accounts[&user_address()]), 1);
+    assert_eq!(Rc::strong_count(&world.accounts[&other_address()]), 2);
+    assert_eq!(world.accounts[&user_address()].nonce, 1);
+
+    world.restore_named_snapshot("before");
+    assert_eq!(world.accounts[&user_address()].nonce, 0);
+    assert_eq!(Rc::strong_count(&world.accounts[&user_address()]), 2);
+}
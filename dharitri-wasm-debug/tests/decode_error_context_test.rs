@@ -0,0 +1,73 @@
+use dharitri_wasm::{
+    api::{ErrorApi, ErrorApiImpl},
+    dharitri_codec::TopDecode,
+    storage::{storage_get, storage_set, StorageKey},
+    types::{ManagedBuffer, ManagedType},
+};
+use dharitri_wasm_debug::{tx_execution::interpret_panic_as_tx_result, DebugApi};
+
+dharitri_wasm::derive_imports!();
+
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Clone, Debug)]
+pub struct Item {
+    pub id: u16,
+    pub price: u64,
+}
+
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Clone, Debug)]
+pub struct Order {
+    pub buyer: Vec<u8>,
+    pub items: Vec<Item>,
+}
+
+#[test]
+fn storage_decode_error_context_test() {
+    let _ = DebugApi::dummy();
+    let key = StorageKey::<DebugApi>::new(&b"order"[..]);
+
+    // items claims 2 entries, but the second one is cut short
+    #[rustfmt::skip]
+    let bytes: &[u8] = &[
+        /* buyer */ 0, 0, 0, 1, b'a',
+        /* items */ 0, 0, 0, 2,
+        /* items[0] */ 0, 1, 0, 0, 0, 0, 0, 0, 0, 100,
+        /* items[1].id */ 0, 2,
+        /* items[1].price */ 0, 0, 0,
+    ];
+    storage_set(key.as_ref(), &ManagedBuffer::<DebugApi>::new_from_bytes(bytes));
+
+    let panic_any = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        let _: Order = storage_get(key.as_ref());
+    }))
+    .unwrap_err();
+    let tx_result = interpret_panic_as_tx_result(panic_any);
+    tx_result.assert_user_error("storage decode error: input too short");
+
+    let context = tx_result.decode_error_context.unwrap();
+    assert_eq!(context.path, "Order.items[1].price");
+    assert_eq!(context.byte_offset, 21);
+}
+
+#[test]
+fn no_decode_error_context_test() {
+    let _ = DebugApi::dummy();
+    let key = StorageKey::<DebugApi>::new(&b"order"[..]);
+
+    // an earlier decode failure, handled by the caller
+    let _ = Order::top_decode(&[0u8, 0, 0, 5][..]);
+
+    // the storage decode itself succeeds, the failure comes later
+    let order = Order {
+        buyer: b"a".to_vec(),
+        items: Vec::new(),
+    };
+    storage_set(key.as_ref(), &order);
+    let panic_any = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        let _: Order = storage_get(key.as_ref());
+        DebugApi::error_api_impl().signal_error(b"no items");
+    }))
+    .unwrap_err();
+    let tx_result = interpret_panic_as_tx_result(panic_any);
+    tx_result.assert_user_error("no items");
+    assert!(tx_result.decode_error_context.is_none());
+}
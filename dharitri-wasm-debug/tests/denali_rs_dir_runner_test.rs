@@ -0,0 +1,92 @@
+use dharitri_wasm_debug::*;
+
+fn world() -> BlockchainMock {
+    let mut blockchain = BlockchainMock::new();
+    blockchain.set_current_dir_from_workspace("dharitri-wasm-debug");
+    blockchain
+}
+
+const DIR: &str = "tests/denali/dir-runner";
+
+fn run_paths(report: &DenaliRunReport) -> Vec<&str> {
+    report
+        .results
+        .iter()
+        .map(|result| result.path.as_str())
+        .collect()
+}
+
+#[test]
+fn dir_runner_exclude_test() {
+    let report = DenaliRsDirRunner::new(DIR, world)
+        .exclude("*-fail.scen.json")
+        .run();
+    report.assert_all_passed();
+    assert_eq!(
+        run_paths(&report),
+        vec![
+            "tests/denali/dir-runner/nested/set-check-nested-ok.scen.json",
+            "tests/denali/dir-runner/set-check-ok.scen.json",
+        ]
+    );
+    assert_eq!(report.num_filtered_out, 1);
+}
+
+#[test]
+fn dir_runner_include_test() {
+    let report = DenaliRsDirRunner::new(DIR, world)
+        .include("*/nested/*")
+        .num_threads(1)
+        .run();
+    report.assert_all_passed();
+    assert_eq!(
+        run_paths(&report),
+        vec!["tests/denali/dir-runner/nested/set-check-nested-ok.scen.json"]
+    );
+}
+
+#[test]
+fn dir_runner_tags_test() {
+    let report = DenaliRsDirRunner::new(DIR, world)
+        .include_tag("quick")
+        .exclude_tag("fails")
+        .run();
+    report.assert_all_passed();
+    assert_eq!(
+        run_paths(&report),
+        vec!["tests/denali/dir-runner/set-check-ok.scen.json"]
+    );
+}
+
+#[test]
+fn dir_runner_failure_test() {
+    let report = DenaliRsDirRunner::new(DIR, world).run();
+    assert_eq!(report.results.len(), 3);
+    assert_eq!(report.num_failed(), 1);
+
+    let failed = report
+        .results
+        .iter()
+        .find(|result| !result.is_ok())
+        .unwrap();
+    assert_eq!(
+        failed.path,
+        "tests/denali/dir-runner/set-check-fail.scen.json"
+    );
+    assert!(report.to_string().contains("FAILED"));
+}
+
+#[test]
+#[should_panic(expected = "1 of 3 denali scenarios failed")]
+fn dir_runner_assert_all_passed_test() {
+    denali_rs_dir(DIR, world);
+}
+
+#[test]
+fn glob_match_test() {
+    assert!(glob_match("*", "denali/a.scen.json"));
+    assert!(glob_match("denali/*.scen.json", "denali/sub/a.scen.json"));
+    assert!(glob_match("*ping-?.scen.json", "denali/ping-1.scen.json"));
+    assert!(!glob_match("*ping-?.scen.json", "denali/ping-12.scen.json"));
+    assert!(!glob_match("denali/*", "other/a.scen.json"));
+}
@@ -0,0 +1,53 @@
+use dharitri_wasm::types::{BigUint, EllipticCurve};
+use dharitri_wasm_debug::DebugApi;
+
+#[test]
+fn test_ec_compressed_round_trip() {
+    let _ = DebugApi::dummy();
+
+    for bitsize in [224, 256, 384, 521] {
+        let ec = EllipticCurve::<DebugApi>::from_bitsize(bitsize).unwrap();
+        let (_, _, _, x_base, y_base, _) = ec.get_values();
+        let (x, y) = ec.scalar_base_mult(&[0x12, 0x34, 0x56]);
+        assert!(ec.is_on_curve(x.clone(), y.clone()));
+
+        let compressed = ec.marshal_compressed(x.clone(), y.clone());
+        assert_eq!(compressed.len(), 1 + ec.get_priv_key_byte_length() as usize);
+        let (x_decoded, y_decoded) = ec.unmarshal_compressed(compressed.as_slice());
+        assert_eq!(x_decoded, x);
+        assert_eq!(y_decoded, y);
+
+        let uncompressed = ec.marshal(x_base.clone(), y_base.clone());
+        let (x_decoded, y_decoded) = ec.unmarshal(uncompressed.as_slice());
+        assert_eq!(x_decoded, x_base);
+        assert_eq!(y_decoded, y_base);
+    }
+}
+
+#[test]
+fn test_ec_arithmetic() {
+    let _ = DebugApi::dummy();
+    let ec = EllipticCurve::<DebugApi>::from_bitsize(256).unwrap();
+    let (_, _, _, x_base, y_base, _) = ec.get_values();
+
+    let (x_double, y_double) = ec.double(x_base.clone(), y_base.clone());
+    let (x_triple, y_triple) = ec.add(x_double, y_double, x_base.clone(), y_base.clone());
+    let (x_expected, y_expected) = ec.scalar_mult(x_base, y_base, &[3]);
+    assert_eq!(x_triple, x_expected);
+    assert_eq!(y_triple, y_expected);
+}
+
+#[test]
+fn test_ec_generate_key() {
+    let _ = DebugApi::dummy();
+    let ec = EllipticCurve::<DebugApi>::from_bitsize(384).unwrap();
+
+    let (x_pub, y_pub, private_key) = ec.generate_key();
+    assert_eq!(private_key.len(), 48);
+    assert!(ec.is_on_curve(x_pub.clone(), y_pub.clone()));
+
+    let (x_expected, y_expected) = ec.scalar_base_mult(private_key.as_slice());
+    assert_eq!(x_pub, x_expected);
+    assert_eq!(y_pub, y_expected);
+    assert!(x_pub != BigUint::zero());
+}
@@ -221,3 +221,17 @@ fn transfer_dct_rs() {
 fn validator_reward_rs() {
     dharitri_wasm_debug::denali_rs("tests/denali/validatorReward.scen.json", world());
 }
+
+#[test]
+fn snapshot_restore_rs() {
+    dharitri_wasm_debug::denali_rs("tests/denali/snapshot-restore.scen.json", world());
+}
+
+#[test]
+#[should_panic(expected = "snapshot not found: other")]
+fn snapshot_restore_unknown_rs() {
+    dharitri_wasm_debug::denali_rs(
+        "tests/denali/snapshot-restore-unknown.err.json",
+        world(),
+    );
+}
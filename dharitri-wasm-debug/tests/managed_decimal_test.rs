@@ -0,0 +1,232 @@
+use dharitri_wasm::{
+    abi::TypeAbi,
+    formatter::{FormatBuffer, SCDisplay},
+    types::{
+        BigInt, BigUint, ManagedBuffer, ManagedBufferCachedBuilder, ManagedDecimal,
+        ManagedDecimalSigned, Rounding, Sign,
+    },
+};
+use dharitri_wasm_debug::{check_managed_top_encode_decode, DebugApi};
+
+fn decimal<const DECIMALS: usize>(raw_units: u64) -> ManagedDecimal<DebugApi, DECIMALS> {
+    ManagedDecimal::from_raw_units(BigUint::from(raw_units))
+}
+
+fn signed<const DECIMALS: usize>(raw_units: i64) -> ManagedDecimalSigned<DebugApi, DECIMALS> {
+    ManagedDecimalSigned::from_raw_units(BigInt::from(raw_units))
+}
+
+fn display<T: SCDisplay>(value: &T) -> ManagedBuffer<DebugApi> {
+    let mut builder = ManagedBufferCachedBuilder::<DebugApi>::new_from_slice(&[]);
+    builder.append_display(value);
+    builder.into_managed_buffer()
+}
+
+#[test]
+fn test_managed_decimal_arithmetic() {
+    let _ = DebugApi::dummy();
+
+    let one_and_a_half = decimal::<2>(150);
+    let two = ManagedDecimal::<DebugApi, 2>::from_integer(BigUint::from(2u32));
+    assert_eq!(two.raw_units(), &BigUint::from(200u32));
+    assert_eq!(two.trunc(), BigUint::from(2u32));
+
+    assert_eq!(&one_and_a_half + &two, decimal(350));
+    assert_eq!(&two - &one_and_a_half, decimal(50));
+    assert_eq!(&one_and_a_half * &two, decimal(300));
+    assert_eq!(&one_and_a_half / &two, decimal(75));
+
+    let mut value = one_and_a_half.clone();
+    value += &two;
+    value *= decimal(200);
+    assert_eq!(value, decimal(700));
+
+    assert!(one_and_a_half < two);
+    assert_eq!(one_and_a_half.checked_sub(&two), None);
+    assert_eq!(two.checked_sub(&one_and_a_half), Some(decimal(50)));
+}
+
+#[test]
+fn test_managed_decimal_rounding() {
+    let _ = DebugApi::dummy();
+
+    let one = decimal::<2>(100);
+    let three = decimal::<2>(300);
+    assert_eq!(one.checked_div(&three, Rounding::Down), Some(decimal(33)));
+    assert_eq!(one.checked_div(&three, Rounding::Up), Some(decimal(34)));
+    assert_eq!(one.checked_div(&three, Rounding::HalfUp), Some(decimal(33)));
+    assert_eq!(
+        decimal::<2>(200).checked_div(&three, Rounding::HalfUp),
+        Some(decimal(67))
+    );
+    assert_eq!(one.checked_div(&decimal(0), Rounding::Down), None);
+
+    let a = decimal::<2>(125);
+    let b = decimal::<2>(10);
+    assert_eq!(a.mul_with_rounding(&b, Rounding::Down), decimal(12));
+    assert_eq!(a.mul_with_rounding(&b, Rounding::Up), decimal(13));
+    assert_eq!(a.mul_with_rounding(&b, Rounding::HalfUp), decimal(13));
+}
+
+#[test]
+fn test_managed_decimal_rescale() {
+    let _ = DebugApi::dummy();
+
+    let value = decimal::<4>(12_345);
+    assert_eq!(value.rescale::<6>(Rounding::Down), decimal::<6>(1_234_500));
+    assert_eq!(value.rescale::<4>(Rounding::Down), value);
+    assert_eq!(value.rescale::<2>(Rounding::Down), decimal::<2>(123));
+    assert_eq!(value.rescale::<2>(Rounding::Up), decimal::<2>(124));
+    assert_eq!(value.rescale::<3>(Rounding::HalfUp), decimal::<3>(1_235));
+    assert_eq!(value.rescale::<0>(Rounding::HalfUp), decimal::<0>(1));
+}
+
+#[test]
+fn test_managed_decimal_display() {
+    let _ = DebugApi::dummy();
+
+    assert_eq!(
+        display(&decimal::<4>(12_345)),
+        ManagedBuffer::from(&b"1.2345"[..])
+    );
+    assert_eq!(
+        display(&decimal::<4>(25_000)),
+        ManagedBuffer::from(&b"2.5000"[..])
+    );
+    assert_eq!(
+        display(&decimal::<4>(5)),
+        ManagedBuffer::from(&b"0.0005"[..])
+    );
+    assert_eq!(
+        display(&decimal::<4>(0)),
+        ManagedBuffer::from(&b"0.0000"[..])
+    );
+    assert_eq!(display(&decimal::<0>(42)), ManagedBuffer::from(&b"42"[..]));
+}
+
+#[test]
+fn test_managed_decimal_serialization() {
+    let api = DebugApi::dummy();
+
+    check_managed_top_encode_decode(api.clone(), decimal::<18>(5), &[5u8]);
+    check_managed_top_encode_decode(
+        api,
+        vec![decimal::<2>(5), decimal::<2>(6)],
+        &[0, 0, 0, 1, 5, 0, 0, 0, 1, 6],
+    );
+}
+
+#[test]
+fn test_managed_decimal_signed_arithmetic() {
+    let _ = DebugApi::dummy();
+
+    let minus_one_and_a_half = signed::<2>(-150);
+    let two = ManagedDecimalSigned::<DebugApi, 2>::from_integer(BigInt::from(2i64));
+    assert_eq!(two.raw_units(), &BigInt::from(200i64));
+    assert_eq!(minus_one_and_a_half.trunc(), BigInt::from(-1i64));
+    assert_eq!(minus_one_and_a_half.sign(), Sign::Minus);
+    assert_eq!(minus_one_and_a_half.magnitude(), decimal(150));
+
+    assert_eq!(&minus_one_and_a_half + &two, signed(50));
+    assert_eq!(&minus_one_and_a_half - &two, signed(-350));
+    assert_eq!(&minus_one_and_a_half * &two, signed(-300));
+    assert_eq!(&minus_one_and_a_half / &two, signed(-75));
+    assert_eq!(&minus_one_and_a_half / &signed(-50), signed(300));
+    assert_eq!(-minus_one_and_a_half.clone(), signed(150));
+
+    let mut value = minus_one_and_a_half.clone();
+    value -= &two;
+    value *= signed(-200);
+    assert_eq!(value, signed(700));
+
+    assert!(minus_one_and_a_half < signed(-100));
+    assert!(minus_one_and_a_half < two);
+    assert_eq!(minus_one_and_a_half.clone().into_unsigned(), None);
+    assert_eq!(two.clone().into_unsigned(), Some(decimal(200)));
+    assert_eq!(
+        ManagedDecimalSigned::from_parts(Sign::Minus, decimal::<2>(150)),
+        minus_one_and_a_half
+    );
+    assert_eq!(ManagedDecimalSigned::from(decimal::<2>(200)), two);
+}
+
+#[test]
+fn test_managed_decimal_signed_rounding() {
+    let _ = DebugApi::dummy();
+
+    // rounding applies to the absolute value
+    let minus_one = signed::<2>(-100);
+    let three = signed::<2>(300);
+    assert_eq!(
+        minus_one.checked_div(&three, Rounding::Down),
+        Some(signed(-33))
+    );
+    assert_eq!(
+        minus_one.checked_div(&three, Rounding::Up),
+        Some(signed(-34))
+    );
+    assert_eq!(
+        minus_one.checked_div(&three, Rounding::HalfUp),
+        Some(signed(-33))
+    );
+    assert_eq!(
+        signed::<2>(200).checked_div(&-three.clone(), Rounding::HalfUp),
+        Some(signed(-67))
+    );
+    assert_eq!(minus_one.checked_div(&signed(0), Rounding::Down), None);
+
+    let a = signed::<2>(-125);
+    let b = signed::<2>(10);
+    assert_eq!(a.mul_with_rounding(&b, Rounding::Down), signed(-12));
+    assert_eq!(a.mul_with_rounding(&b, Rounding::Up), signed(-13));
+    assert_eq!(a.mul_with_rounding(&b, Rounding::HalfUp), signed(-13));
+    assert_eq!(a.mul_with_rounding(&-b, Rounding::Down), signed(12));
+}
+
+#[test]
+fn test_managed_decimal_signed_rescale() {
+    let _ = DebugApi::dummy();
+
+    let value = signed::<4>(-12_345);
+    assert_eq!(value.rescale::<6>(Rounding::Down), signed::<6>(-1_234_500));
+    assert_eq!(value.rescale::<2>(Rounding::Down), signed::<2>(-123));
+    assert_eq!(value.rescale::<2>(Rounding::Up), signed::<2>(-124));
+    assert_eq!(value.rescale::<3>(Rounding::HalfUp), signed::<3>(-1_235));
+    assert_eq!(value.rescale::<0>(Rounding::HalfUp), signed::<0>(-1));
+}
+
+#[test]
+fn test_managed_decimal_signed_display() {
+    let _ = DebugApi::dummy();
+
+    assert_eq!(
+        display(&signed::<4>(-12_345)),
+        ManagedBuffer::from(&b"-1.2345"[..])
+    );
+    assert_eq!(
+        display(&signed::<4>(-5)),
+        ManagedBuffer::from(&b"-0.0005"[..])
+    );
+    assert_eq!(
+        display(&signed::<4>(25_000)),
+        ManagedBuffer::from(&b"2.5000"[..])
+    );
+    assert_eq!(
+        display(&signed::<4>(0)),
+        ManagedBuffer::from(&b"0.0000"[..])
+    );
+    assert_eq!(display(&signed::<0>(-42)), ManagedBuffer::from(&b"-42"[..]));
+}
+
+#[test]
+fn test_managed_decimal_signed_serialization() {
+    let api = DebugApi::dummy();
+
+    check_managed_top_encode_decode(api.clone(), signed::<18>(-5), &[0xfb]);
+    check_managed_top_encode_decode(
+        api,
+        vec![signed::<2>(-5), signed::<2>(6)],
+        &[0, 0, 0, 1, 0xfb, 0, 0, 0, 1, 6],
+    );
+    assert_eq!(ManagedDecimalSigned::<DebugApi, 2>::type_name(), "BigInt");
+}
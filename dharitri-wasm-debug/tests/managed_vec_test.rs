@@ -173,3 +173,163 @@ fn test_managed_vec_get_mut() {
     assert_eq!(*managed_vec.get(0), 200u32);
     assert_eq!(*managed_vec.get(1), 300u32);
 }
+
+fn managed_vec_of_u32(items: &[u32]) -> ManagedVec<DebugApi, u32> {
+    let mut managed_vec = ManagedVec::new();
+    for item in items {
+        managed_vec.push(*item);
+    }
+    managed_vec
+}
+
+#[test]
+fn test_managed_vec_sort() {
+    let _ = DebugApi::dummy();
+
+    let unsorted = [5u32, 3, 8, 1, 9, 2, 7, 3, 0];
+    let mut expected = unsorted.to_vec();
+    expected.sort_unstable();
+
+    let mut managed_vec = managed_vec_of_u32(&unsorted);
+    assert!(!managed_vec.is_sorted());
+    managed_vec.sort();
+    assert!(managed_vec.is_sorted());
+    assert_eq!(managed_vec.into_vec(), expected);
+
+    let mut managed_vec = managed_vec_of_u32(&unsorted);
+    managed_vec.sort_unstable();
+    assert_eq!(managed_vec.into_vec(), expected);
+
+    let mut managed_vec = managed_vec_of_u32(&unsorted);
+    managed_vec.sort_by(|a, b| b.cmp(a));
+    expected.reverse();
+    assert_eq!(managed_vec.into_vec(), expected);
+
+    let mut empty = ManagedVec::<DebugApi, u32>::new();
+    empty.sort();
+    empty.sort_unstable();
+    assert!(empty.is_sorted());
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn test_managed_vec_sort_by_key_stable() {
+    let _ = DebugApi::dummy();
+
+    // only the tens digit is compared, the units show the original order
+    let mut managed_vec = managed_vec_of_u32(&[31, 12, 33, 14, 25, 11, 36]);
+    managed_vec.sort_by_key(|item| item / 10);
+    assert_eq!(managed_vec.into_vec(), vec![12, 14, 11, 25, 31, 33, 36]);
+}
+
+#[test]
+fn test_managed_vec_sort_biguint() {
+    let _ = DebugApi::dummy();
+
+    let mut managed_vec = ManagedVec::<DebugApi, BigUint<DebugApi>>::new();
+    for i in [50u64, 10, 40, 20, 30] {
+        managed_vec.push(BigUint::from(i));
+    }
+    let mut unstable_sorted = managed_vec.clone();
+
+    managed_vec.sort();
+    let numbers: Vec<u64> = managed_vec
+        .iter()
+        .map(|biguint| biguint.to_u64().unwrap())
+        .collect();
+    assert_eq!(numbers, vec![10, 20, 30, 40, 50]);
+
+    unstable_sorted.sort_unstable();
+    assert_eq!(unstable_sorted, managed_vec);
+
+    assert_eq!(managed_vec.binary_search(&BigUint::from(40u64)), Ok(3));
+    assert_eq!(managed_vec.binary_search(&BigUint::from(35u64)), Err(3));
+}
+
+#[test]
+fn test_managed_vec_binary_search() {
+    let _ = DebugApi::dummy();
+
+    let managed_vec = managed_vec_of_u32(&[1, 3, 5, 7, 9]);
+    assert_eq!(managed_vec.binary_search(&1), Ok(0));
+    assert_eq!(managed_vec.binary_search(&7), Ok(3));
+    assert_eq!(managed_vec.binary_search(&9), Ok(4));
+    assert_eq!(managed_vec.binary_search(&0), Err(0));
+    assert_eq!(managed_vec.binary_search(&4), Err(2));
+    assert_eq!(managed_vec.binary_search(&10), Err(5));
+    assert_eq!(managed_vec.binary_search_by(|probe| probe.cmp(&5)), Ok(2));
+
+    let empty = ManagedVec::<DebugApi, u32>::new();
+    assert_eq!(empty.binary_search(&1), Err(0));
+}
+
+#[test]
+fn test_managed_vec_dedup() {
+    let _ = DebugApi::dummy();
+
+    let mut managed_vec = managed_vec_of_u32(&[1, 1, 2, 3, 3, 3, 1, 4, 4]);
+    managed_vec.dedup();
+    assert_eq!(managed_vec.into_vec(), vec![1, 2, 3, 1, 4]);
+
+    let mut managed_vec = managed_vec_of_u32(&[2, 2, 2]);
+    managed_vec.dedup();
+    assert_eq!(managed_vec.into_vec(), vec![2]);
+
+    let mut empty = ManagedVec::<DebugApi, u32>::new();
+    empty.dedup();
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn test_managed_vec_retain() {
+    let _ = DebugApi::dummy();
+
+    let mut managed_vec = managed_vec_of_u32(&[1, 2, 3, 4, 5, 6, 7]);
+    managed_vec.retain(|item| item % 2 == 1);
+    assert_eq!(managed_vec.into_vec(), vec![1, 3, 5, 7]);
+
+    let mut managed_vec = ManagedVec::<DebugApi, BigUint<DebugApi>>::new();
+    for i in 1u64..=5u64 {
+        managed_vec.push(BigUint::from(i));
+    }
+    managed_vec.retain(|item| *item > 3u32);
+    assert_eq!(managed_vec.len(), 2);
+    assert_eq!(*managed_vec.get(0), 4u32);
+    assert_eq!(*managed_vec.get(1), 5u32);
+
+    managed_vec.retain(|_| false);
+    assert!(managed_vec.is_empty());
+}
+
+#[test]
+fn test_managed_vec_insert() {
+    let _ = DebugApi::dummy();
+
+    let mut managed_vec = managed_vec_of_u32(&[2, 4]);
+    managed_vec.insert(0, 1);
+    managed_vec.insert(2, 3);
+    managed_vec.insert(4, 5);
+    assert_eq!(managed_vec.into_vec(), vec![1, 2, 3, 4, 5]);
+}
+
+#[test]
+#[should_panic]
+fn test_managed_vec_insert_out_of_range() {
+    let _ = DebugApi::dummy();
+
+    let mut managed_vec = managed_vec_of_u32(&[1, 2]);
+    managed_vec.insert(3, 4);
+}
+
+#[test]
+fn test_managed_vec_reverse() {
+    let _ = DebugApi::dummy();
+
+    let mut managed_vec = managed_vec_of_u32(&[1, 2, 3, 4, 5]);
+    managed_vec.reverse();
+    assert_eq!(managed_vec.into_vec(), vec![5, 4, 3, 2, 1]);
+
+    let mut managed_vec = managed_vec_of_u32(&[1, 2, 3, 4]);
+    managed_vec.reverse();
+    assert_eq!(managed_vec.into_vec(), vec![4, 3, 2, 1]);
+}
@@ -0,0 +1,594 @@
+use dharitri_wasm::types::heap::{Address, H256};
+use dharitri_wasm_debug::{
+    denali::model::{Account, DctToken, SetStateStep},
+    num_bigint::BigUint,
+    tx_execution::execute_sc_call,
+    tx_mock::{system_sc_address, TxInput, TxResult},
+    world_mock::AccountData,
+    BlockchainMock,
+};
+
+fn owner_address() -> Address {
+    Address::from([b'o'; 32])
+}
+
+fn other_address() -> Address {
+    Address::from([b'x'; 32])
+}
+
+fn new_world() -> BlockchainMock {
+    let mut world = BlockchainMock::new();
+    world.add_account(AccountData::new(owner_address(), BigUint::from(1000u32)));
+    world.add_account(AccountData::new(other_address(), BigUint::from(1000u32)));
+    world
+}
+
+fn call_system_sc(
+    world: BlockchainMock,
+    from: Address,
+    moax_value: u32,
+    func_name: &[u8],
+    args: Vec<Vec<u8>>,
+) -> (TxResult, BlockchainMock) {
+    let tx_input = TxInput {
+        from,
+        to: system_sc_address(),
+        moax_value: BigUint::from(moax_value),
+        dct_values: Vec::new(),
+        func_name: func_name.to_vec(),
+        args,
+        gas_limit: 0,
+        gas_price: 0,
+        tx_hash: H256::zero(),
+    };
+    execute_sc_call(tx_input, world)
+}
+
+fn issue_fungible(world: BlockchainMock, initial_supply: u32) -> (Vec<u8>, BlockchainMock) {
+    let (tx_result, world) = call_system_sc(
+        world,
+        owner_address(),
+        50,
+        b"issue",
+        vec![
+            b"TestToken".to_vec(),
+            b"TEST".to_vec(),
+            BigUint::from(initial_supply).to_bytes_be(),
+            vec![18],
+            b"canFreeze".to_vec(),
+            b"true".to_vec(),
+            b"canWipe".to_vec(),
+            b"true".to_vec(),
+            b"canPause".to_vec(),
+            b"true".to_vec(),
+        ],
+    );
+    tx_result.assert_ok();
+    let token_identifier = if initial_supply == 0 {
+        tx_result.result_values[0].clone()
+    } else {
+        tx_result.back_transfers[0].token_identifier.clone()
+    };
+    (token_identifier, world)
+}
+
+fn transfer_dct(
+    world: BlockchainMock,
+    from: Address,
+    to: Address,
+    token_identifier: &[u8],
+    amount: u32,
+) -> (TxResult, BlockchainMock) {
+    let tx_input = TxInput {
+        from: from.clone(),
+        to: from,
+        moax_value: BigUint::from(0u32),
+        dct_values: Vec::new(),
+        func_name: b"MultiDCTNFTTransfer".to_vec(),
+        args: vec![
+            to.to_vec(),
+            vec![1],
+            token_identifier.to_vec(),
+            Vec::new(),
+            BigUint::from(amount).to_bytes_be(),
+        ],
+        gas_limit: 0,
+        gas_price: 0,
+        tx_hash: H256::zero(),
+    };
+    execute_sc_call(tx_input, world)
+}
+
+fn balance(world: &BlockchainMock, address: &Address, token_identifier: &[u8]) -> BigUint {
+    world.accounts[address]
+        .dct
+        .get_dct_balance(token_identifier, 0)
+}
+
+#[test]
+fn test_issue_fungible() {
+    let (token_identifier, world) = issue_fungible(new_world(), 1000);
+
+    assert_eq!(&token_identifier[..5], b"TEST-");
+    assert_eq!(token_identifier.len(), 11);
+    assert_eq!(
+        balance(&world, &owner_address(), &token_identifier),
+        BigUint::from(1000u32)
+    );
+    assert_eq!(
+        world.accounts[&system_sc_address()].moax_balance,
+        BigUint::from(50u32)
+    );
+
+    // same issuer, same ticker, still a new identifier
+    let (second_identifier, _) = issue_fungible(world, 0);
+    assert_ne!(token_identifier, second_identifier);
+}
+
+#[test]
+fn test_issue_invalid_ticker() {
+    let (tx_result, world) = call_system_sc(
+        new_world(),
+        owner_address(),
+        50,
+        b"issueNonFungible",
+        vec![b"TestToken".to_vec(), b"test".to_vec()],
+    );
+    tx_result.assert_user_error("invalid ticker name");
+    assert!(!world.accounts.contains_key(&system_sc_address()));
+    assert_eq!(
+        world.accounts[&owner_address()].moax_balance,
+        BigUint::from(1000u32)
+    );
+}
+
+#[test]
+fn test_special_roles() {
+    let (tx_result, world) = call_system_sc(
+        new_world(),
+        owner_address(),
+        50,
+        b"issueNonFungible",
+        vec![b"TestNFT".to_vec(), b"NFTEST".to_vec()],
+    );
+    let token_identifier = tx_result.result_values[0].clone();
+
+    let (tx_result, world) = call_system_sc(
+        world,
+        owner_address(),
+        0,
+        b"setSpecialRole",
+        vec![
+            token_identifier.clone(),
+            other_address().to_vec(),
+            b"DCTRoleNFTCreate".to_vec(),
+            b"DCTRoleNFTBurn".to_vec(),
+        ],
+    );
+    tx_result.assert_ok();
+    assert_eq!(
+        world.accounts[&other_address()]
+            .dct
+            .get_roles(&token_identifier),
+        vec![b"DCTRoleNFTCreate".to_vec(), b"DCTRoleNFTBurn".to_vec()]
+    );
+
+    let (tx_result, world) = call_system_sc(
+        world,
+        owner_address(),
+        0,
+        b"setSpecialRole",
+        vec![
+            token_identifier.clone(),
+            other_address().to_vec(),
+            b"DCTRoleLocalMint".to_vec(),
+        ],
+    );
+    tx_result.assert_user_error("invalid argument");
+
+    let (tx_result, world) = call_system_sc(
+        world,
+        other_address(),
+        0,
+        b"unSetSpecialRole",
+        vec![
+            token_identifier.clone(),
+            other_address().to_vec(),
+            b"DCTRoleNFTBurn".to_vec(),
+        ],
+    );
+    tx_result.assert_user_error("can be called by owner only");
+
+    let (tx_result, world) = call_system_sc(
+        world,
+        owner_address(),
+        0,
+        b"unSetSpecialRole",
+        vec![
+            token_identifier.clone(),
+            other_address().to_vec(),
+            b"DCTRoleNFTBurn".to_vec(),
+        ],
+    );
+    tx_result.assert_ok();
+    assert_eq!(
+        world.accounts[&other_address()]
+            .dct
+            .get_roles(&token_identifier),
+        vec![b"DCTRoleNFTCreate".to_vec()]
+    );
+}
+
+#[test]
+fn test_freeze_wipe() {
+    let (token_identifier, world) = issue_fungible(new_world(), 1000);
+
+    let (tx_result, world) = call_system_sc(
+        world,
+        owner_address(),
+        0,
+        b"wipe",
+        vec![token_identifier.clone(), owner_address().to_vec()],
+    );
+    tx_result.assert_user_error("cannot wipe because the account is not frozen for this dct token");
+
+    let (tx_result, world) = call_system_sc(
+        world,
+        owner_address(),
+        0,
+        b"freeze",
+        vec![token_identifier.clone(), owner_address().to_vec()],
+    );
+    tx_result.assert_ok();
+    assert!(
+        world.accounts[&owner_address()]
+            .dct
+            .get_by_identifier_or_default(&token_identifier)
+            .frozen
+    );
+
+    let (tx_result, world) = call_system_sc(
+        world,
+        owner_address(),
+        0,
+        b"wipe",
+        vec![token_identifier.clone(), owner_address().to_vec()],
+    );
+    tx_result.assert_ok();
+    assert_eq!(
+        balance(&world, &owner_address(), &token_identifier),
+        BigUint::from(0u32)
+    );
+}
+
+#[test]
+fn test_pause_and_transfer_ownership() {
+    let (token_identifier, world) = issue_fungible(new_world(), 0);
+
+    let (tx_result, world) = call_system_sc(
+        world,
+        owner_address(),
+        0,
+        b"pause",
+        vec![token_identifier.clone()],
+    );
+    tx_result.assert_ok();
+
+    let (tx_result, world) = call_system_sc(
+        world,
+        owner_address(),
+        0,
+        b"pause",
+        vec![token_identifier.clone()],
+    );
+    tx_result.assert_user_error("cannot pause an already paused contract");
+
+    let (tx_result, world) = call_system_sc(
+        world,
+        owner_address(),
+        0,
+        b"transferOwnership",
+        vec![token_identifier.clone(), other_address().to_vec()],
+    );
+    tx_result.assert_user_error("cannot change owner of the token");
+
+    let (tx_result, _) = call_system_sc(
+        world,
+        other_address(),
+        0,
+        b"unPause",
+        vec![token_identifier],
+    );
+    tx_result.assert_user_error("can be called by owner only");
+}
+
+#[test]
+fn test_transfer_frozen() {
+    let (token_identifier, world) = issue_fungible(new_world(), 1000);
+    let (tx_result, world) = transfer_dct(
+        world,
+        owner_address(),
+        other_address(),
+        &token_identifier,
+        100,
+    );
+    tx_result.assert_ok();
+
+    let (_, world) = call_system_sc(
+        world,
+        owner_address(),
+        0,
+        b"freeze",
+        vec![token_identifier.clone(), other_address().to_vec()],
+    );
+
+    // frozen accounts can neither receive, nor send
+    let (tx_result, world) = transfer_dct(
+        world,
+        owner_address(),
+        other_address(),
+        &token_identifier,
+        100,
+    );
+    tx_result.assert_error(10, "account is frozen for this dct token");
+    let (tx_result, world) = transfer_dct(
+        world,
+        other_address(),
+        owner_address(),
+        &token_identifier,
+        100,
+    );
+    tx_result.assert_error(10, "account is frozen for this dct token");
+    assert_eq!(
+        balance(&world, &other_address(), &token_identifier),
+        BigUint::from(100u32)
+    );
+
+    let (_, world) = call_system_sc(
+        world,
+        owner_address(),
+        0,
+        b"unFreeze",
+        vec![token_identifier.clone(), other_address().to_vec()],
+    );
+    let (tx_result, _) = transfer_dct(
+        world,
+        other_address(),
+        owner_address(),
+        &token_identifier,
+        100,
+    );
+    tx_result.assert_ok();
+}
+
+#[test]
+fn test_transfer_paused() {
+    let (token_identifier, world) = issue_fungible(new_world(), 1000);
+    let (_, world) = call_system_sc(
+        world,
+        owner_address(),
+        0,
+        b"pause",
+        vec![token_identifier.clone()],
+    );
+
+    let (tx_result, world) = transfer_dct(
+        world,
+        owner_address(),
+        other_address(),
+        &token_identifier,
+        100,
+    );
+    tx_result.assert_error(10, "dct token is paused");
+
+    let (_, world) = call_system_sc(
+        world,
+        owner_address(),
+        0,
+        b"unPause",
+        vec![token_identifier.clone()],
+    );
+    let (tx_result, world) = transfer_dct(
+        world,
+        owner_address(),
+        other_address(),
+        &token_identifier,
+        100,
+    );
+    tx_result.assert_ok();
+    assert_eq!(
+        balance(&world, &other_address(), &token_identifier),
+        BigUint::from(100u32)
+    );
+}
+
+#[test]
+fn test_limited_transfer() {
+    let third_address = Address::from([b't'; 32]);
+    let (token_identifier, mut world) = issue_fungible(new_world(), 1000);
+    world.add_account(AccountData::new(
+        third_address.clone(),
+        BigUint::from(1000u32),
+    ));
+
+    let (tx_result, world) = call_system_sc(
+        world,
+        owner_address(),
+        0,
+        b"setSpecialRole",
+        vec![
+            token_identifier.clone(),
+            other_address().to_vec(),
+            b"DCTRoleTransfer".to_vec(),
+        ],
+    );
+    tx_result.assert_ok();
+
+    // only transfers from or to the role holder are allowed
+    let (tx_result, world) = transfer_dct(
+        world,
+        owner_address(),
+        third_address.clone(),
+        &token_identifier,
+        100,
+    );
+    tx_result.assert_error(10, "action is not allowed");
+    let (tx_result, world) = transfer_dct(
+        world,
+        owner_address(),
+        other_address(),
+        &token_identifier,
+        100,
+    );
+    tx_result.assert_ok();
+    let (tx_result, world) = transfer_dct(
+        world,
+        other_address(),
+        third_address.clone(),
+        &token_identifier,
+        50,
+    );
+    tx_result.assert_ok();
+
+    // the token is no longer limited once nobody holds the role
+    let (tx_result, world) = call_system_sc(
+        world,
+        owner_address(),
+        0,
+        b"unSetSpecialRole",
+        vec![
+            token_identifier.clone(),
+            other_address().to_vec(),
+            b"DCTRoleTransfer".to_vec(),
+        ],
+    );
+    tx_result.assert_ok();
+    let (tx_result, world) = transfer_dct(
+        world,
+        owner_address(),
+        third_address.clone(),
+        &token_identifier,
+        100,
+    );
+    tx_result.assert_ok();
+    assert_eq!(
+        balance(&world, &third_address, &token_identifier),
+        BigUint::from(150u32)
+    );
+}
+
+fn set_state_world(set_state_step: SetStateStep) -> BlockchainMock {
+    let mut world = BlockchainMock::new();
+    world.denali_set_state(set_state_step);
+    world
+}
+
+#[test]
+fn test_set_state_paused() {
+    let world = set_state_world(
+        SetStateStep::new()
+            .put_account(
+                &owner_address(),
+                Account::new().dct_balance("str:COOL-123456", 1000u64),
+            )
+            .put_account(&other_address(), Account::new())
+            .put_dct_token("str:COOL-123456", DctToken::new().paused(1u64)),
+    );
+
+    let (tx_result, mut world) =
+        transfer_dct(world, owner_address(), other_address(), b"COOL-123456", 100);
+    tx_result.assert_error(10, "dct token is paused");
+
+    world.denali_set_state(
+        SetStateStep::new().put_dct_token("str:COOL-123456", DctToken::new().paused(0u64)),
+    );
+    let (tx_result, world) =
+        transfer_dct(world, owner_address(), other_address(), b"COOL-123456", 100);
+    tx_result.assert_ok();
+    assert_eq!(
+        balance(&world, &other_address(), b"COOL-123456"),
+        BigUint::from(100u32)
+    );
+}
+
+#[test]
+fn test_set_state_owner_can_freeze() {
+    let world = set_state_world(
+        SetStateStep::new()
+            .put_account(
+                &owner_address(),
+                Account::new().dct_balance("str:COOL-123456", 1000u64),
+            )
+            .put_account(&other_address(), Account::new())
+            .put_dct_token(
+                "str:COOL-123456",
+                DctToken::new().owner(&owner_address()).can_freeze(1u64),
+            ),
+    );
+
+    let (tx_result, world) = call_system_sc(
+        world,
+        owner_address(),
+        0,
+        b"freeze",
+        vec![b"COOL-123456".to_vec(), owner_address().to_vec()],
+    );
+    tx_result.assert_ok();
+    let (tx_result, _) = transfer_dct(world, owner_address(), other_address(), b"COOL-123456", 100);
+    tx_result.assert_error(10, "account is frozen for this dct token");
+}
+
+#[test]
+fn test_set_state_limited_transfer() {
+    let third_address = Address::from([b't'; 32]);
+    let world = set_state_world(
+        SetStateStep::new()
+            .put_account(
+                &owner_address(),
+                Account::new().dct_balance("str:COOL-123456", 1000u64),
+            )
+            .put_account(
+                &other_address(),
+                Account::new()
+                    .dct_balance("str:COOL-123456", 1000u64)
+                    .dct_roles("str:COOL-123456", vec!["DCTRoleTransfer".to_string()]),
+            )
+            .put_account(&third_address, Account::new()),
+    );
+
+    // the transfer role from the initial state makes the token limited transfer
+    let (tx_result, world) = transfer_dct(
+        world,
+        owner_address(),
+        third_address.clone(),
+        &b"COOL-123456"[..],
+        100,
+    );
+    tx_result.assert_error(10, "action is not allowed");
+    let (tx_result, mut world) = transfer_dct(
+        world,
+        other_address(),
+        third_address.clone(),
+        &b"COOL-123456"[..],
+        100,
+    );
+    tx_result.assert_ok();
+
+    // overwriting the account without the role lifts the restriction
+    world.denali_set_state(SetStateStep::new().put_account(
+        &other_address(),
+        Account::new().dct_balance("str:COOL-123456", 900u64),
+    ));
+    let (tx_result, world) = transfer_dct(
+        world,
+        owner_address(),
+        third_address.clone(),
+        &b"COOL-123456"[..],
+        100,
+    );
+    tx_result.assert_ok();
+    assert_eq!(
+        balance(&world, &third_address, b"COOL-123456"),
+        BigUint::from(200u32)
+    );
+}
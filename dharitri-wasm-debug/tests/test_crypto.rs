@@ -46,3 +46,53 @@ fn test_verify_ed25519_invalid_args() {
     let success = ctx.verify_ed25519(&pub_bytes, &msg_bytes, &sig_bytes);
     assert!(!success);
 }
+
+#[test]
+fn test_verify_bls_basic() {
+    let public_key: &[u8] = b"b5823f6e564251cc03ce7bad3da83e72576e92795d3500bba1acb30ec9a94dce87bb8aa794d67b2d61d15c33f28f6c0c23ba1dfcbf21e8f8b46286ff871afabac925303ddcaddce6254fcff6d3155797db40b3d3b5865e8fc0bd770b3d79b381";
+    let signature: &[u8] = b"af32a2ddf341c08d1eb7232f05dc34e4454155e676b58c40fddf9a036562ac2c01533d2d557cb49d73aa9d7a89744696";
+
+    let pub_bytes: Vec<u8> = FromHex::from_hex(public_key).unwrap();
+    let sig_bytes: Vec<u8> = FromHex::from_hex(signature).unwrap();
+
+    let ctx = DebugApi::dummy();
+    assert!(ctx.verify_bls(&pub_bytes, b"message to be signed", &sig_bytes));
+    assert!(!ctx.verify_bls(&pub_bytes, b"message to be signed!", &sig_bytes));
+    assert!(!ctx.verify_bls(&pub_bytes, b"message to be signed", &sig_bytes[1..]));
+    assert!(!ctx.verify_bls(&[0u8; 96], b"message to be signed", &sig_bytes));
+}
+
+#[test]
+fn test_verify_secp256k1_basic() {
+    let public_key: &[u8] = b"02a673638cb9587cb68ea08dbef685c6f2d2a751a8b3c6f2a7e9a4999e6e4bfaf5";
+    let signature: &[u8] = b"3045022100fab29dbcb623e7e96f44665f312b89ffdb9e4d2e2f53353236fbbcf57b2f49bf02203d6f9c63df8be43121042970a549a2d3baeca093fd37b726ddcc0924638409f5";
+
+    let pub_bytes: Vec<u8> = FromHex::from_hex(public_key).unwrap();
+    let sig_bytes: Vec<u8> = FromHex::from_hex(signature).unwrap();
+
+    let ctx = DebugApi::dummy();
+    assert!(ctx.verify_secp256k1(&pub_bytes, b"message to sign", &sig_bytes));
+    assert!(!ctx.verify_secp256k1(&pub_bytes, b"message to sign!", &sig_bytes));
+    assert!(!ctx.verify_secp256k1(&pub_bytes[1..], b"message to sign", &sig_bytes));
+}
+
+#[test]
+fn test_encode_secp256k1_der_signature() {
+    let ctx = DebugApi::dummy();
+
+    let r = [0x7fu8; 32];
+    let s: Vec<u8> = FromHex::from_hex(b"0000ff").unwrap();
+    let der = ctx.encode_secp256k1_der_signature(&r[..], &s);
+
+    let mut expected = vec![0x30, 38, 0x02, 32];
+    expected.extend_from_slice(&r[..]);
+    expected.extend_from_slice(&[0x02, 2, 0x00, 0xff]);
+    assert_eq!(der.as_slice(), expected.as_slice());
+}
+
+#[test]
+fn test_ripemd160() {
+    let ctx = DebugApi::dummy();
+    let expected: Vec<u8> = FromHex::from_hex(b"79f901da2609f020adadbf2e5f68a16c8c3f7d57").unwrap();
+    assert_eq!(&ctx.ripemd160(&[1, 2, 3])[..], expected.as_slice());
+}
@@ -0,0 +1,18 @@
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+use dharitri_wasm::abi::TypeAbi;
+
+#[test]
+fn type_abi_128_bits_test() {
+    assert_eq!(u128::type_name(), "u128");
+    assert_eq!(i128::type_name(), "i128");
+}
+
+#[test]
+fn type_abi_collections_test() {
+    // the names reflect the encoding, which is the same as for the equivalent lists
+    assert_eq!(<VecDeque<u32>>::type_name(), "List<u32>");
+    assert_eq!(<VecDeque<u8>>::type_name(), "bytes");
+    assert_eq!(<BTreeSet<i128>>::type_name(), "List<i128>");
+    assert_eq!(<BTreeMap<u32, u128>>::type_name(), "List<tuple<u32,u128>>");
+}
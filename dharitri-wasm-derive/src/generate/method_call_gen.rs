@@ -1,6 +1,6 @@
 use super::{
     convert_to_owned_type::*, method_gen::generate_arg_call_name, only_owner_gen::*,
-    payable_gen::*, util::*,
+    only_role_gen::*, payable_gen::*, util::*,
 };
 use crate::{
     generate::snippets,
@@ -90,6 +90,7 @@ pub fn generate_call_method_body(m: &Method) -> proc_macro2::TokenStream {
     let api_static_init = snippets::call_method_api_static_init();
     let payable_snippet = generate_payable_snippet(m);
     let only_owner_snippet = generate_only_owner_snippet(m);
+    let only_role_snippet = generate_only_role_snippet(m);
     let arg_load = generate_call_method_arg_load(m);
 
     let call = generate_call_to_method_expr(m);
@@ -99,6 +100,7 @@ pub fn generate_call_method_body(m: &Method) -> proc_macro2::TokenStream {
         #api_static_init
         #payable_snippet
         #only_owner_snippet
+        #only_role_snippet
         #arg_load
         #body_with_result
     }
@@ -0,0 +1,14 @@
+use super::util::byte_str_literal;
+use crate::model::{Method, PublicRole};
+
+pub fn generate_only_role_snippet(m: &Method) -> proc_macro2::TokenStream {
+    if let PublicRole::Endpoint(endpoint_metadata) = &m.public_role {
+        if let Some(role) = &endpoint_metadata.only_role {
+            let role_literal = byte_str_literal(role.as_bytes());
+            return quote! {
+                self.check_caller_has_role(#role_literal);
+            };
+        }
+    }
+    quote! {}
+}
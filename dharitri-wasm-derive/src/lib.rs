@@ -46,7 +46,7 @@ pub fn proxy(
     macro_proxy::process_proxy(args, input)
 }
 
-#[proc_macro_derive(TypeAbi)]
+#[proc_macro_derive(TypeAbi, attributes(codec))]
 pub fn type_abi_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let ast = syn::parse(input).unwrap();
 
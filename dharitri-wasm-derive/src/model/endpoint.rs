@@ -10,6 +10,7 @@ pub struct EndpointMetadata {
     pub public_name: syn::Ident,
     pub payable: MethodPayableMetadata,
     pub only_owner: bool,
+    pub only_role: Option<String>,
     pub mutability: EndpointMutabilityMetadata,
     pub location: EndpointLocationMetadata,
 }
@@ -0,0 +1,90 @@
+use super::attr_names::*;
+use syn::{parse::ParseStream, punctuated::Punctuated, Token};
+
+/// The `#[codec(...)]` attributes, shared with the codec derives.
+/// Only the settings that affect the ABI are kept, the others are parsed and ignored.
+#[derive(Default)]
+pub struct CodecAttribute {
+    pub skip: bool,
+    pub discriminant: Option<u8>,
+    pub with: Option<syn::Path>,
+    pub version: Option<u8>,
+}
+
+impl CodecAttribute {
+    pub fn parse_all(attrs: &[syn::Attribute]) -> Self {
+        let mut result = CodecAttribute::default();
+        for attr in attrs.iter().filter(|attr| attr.path.is_ident(ATTR_CODEC)) {
+            let args = attr
+                .parse_args_with(Punctuated::<CodecArg, Token![,]>::parse_terminated)
+                .unwrap_or_else(|err| panic!("invalid `#[codec(...)]` attribute: {}", err));
+            for arg in args {
+                match arg {
+                    CodecArg::Skip => result.skip = true,
+                    CodecArg::Discriminant(discriminant) => {
+                        result.discriminant = Some(discriminant)
+                    },
+                    CodecArg::With(path) => result.with = Some(path),
+                    CodecArg::Version(version) => result.version = Some(version),
+                    CodecArg::Default | CodecArg::Versioned | CodecArg::Migrate => {},
+                }
+            }
+        }
+        result
+    }
+}
+
+enum CodecArg {
+    Skip,
+    Default,
+    Discriminant(u8),
+    With(syn::Path),
+    Versioned,
+    Version(u8),
+    Migrate,
+}
+
+fn parse_migration(input: ParseStream) -> syn::Result<()> {
+    input.parse::<syn::LitInt>()?;
+    input.parse::<Token![=]>()?;
+    input.parse::<syn::Path>()?;
+    Ok(())
+}
+
+impl syn::parse::Parse for CodecArg {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ident: syn::Ident = input.parse()?;
+        if ident == "skip" {
+            Ok(CodecArg::Skip)
+        } else if ident == "default" {
+            if input.peek(Token![=]) {
+                input.parse::<Token![=]>()?;
+                input.parse::<syn::Expr>()?;
+            }
+            Ok(CodecArg::Default)
+        } else if ident == "discriminant" {
+            input.parse::<Token![=]>()?;
+            let lit: syn::LitInt = input.parse()?;
+            Ok(CodecArg::Discriminant(lit.base10_parse()?))
+        } else if ident == "with" {
+            input.parse::<Token![=]>()?;
+            Ok(CodecArg::With(input.parse()?))
+        } else if ident == "versioned" {
+            Ok(CodecArg::Versioned)
+        } else if ident == "version" {
+            input.parse::<Token![=]>()?;
+            let lit: syn::LitInt = input.parse()?;
+            Ok(CodecArg::Version(lit.base10_parse()?))
+        } else if ident == "migrate" {
+            let content;
+            syn::parenthesized!(content in input);
+            Punctuated::<(), Token![,]>::parse_terminated_with(&content, parse_migration)?;
+            Ok(CodecArg::Migrate)
+        } else {
+            Err(syn::Error::new(
+                ident.span(),
+                "unknown codec attribute, expected one of: skip, default, discriminant, with, versioned, version, migrate",
+            ))
+        }
+    }
+}
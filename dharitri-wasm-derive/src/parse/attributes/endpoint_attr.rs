@@ -8,6 +8,17 @@ pub fn is_only_owner(attr: &syn::Attribute) -> bool {
     is_attribute_with_no_args(attr, ATTR_ONLY_OWNER)
 }
 
+pub struct OnlyRoleAttribute {
+    pub role: String,
+}
+
+impl OnlyRoleAttribute {
+    pub fn parse(attr: &syn::Attribute) -> Option<Self> {
+        is_attr_one_string_arg(attr, ATTR_ONLY_ROLE)
+            .map(|arg_str| OnlyRoleAttribute { role: arg_str })
+    }
+}
+
 pub fn is_callback_raw(attr: &syn::Attribute) -> bool {
     is_attribute_with_no_args(attr, ATTR_CALLBACK_RAW_DECL)
 }
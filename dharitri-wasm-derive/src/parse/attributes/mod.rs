@@ -1,5 +1,6 @@
 mod argument_attr;
 mod attr_names;
+mod codec_attr;
 mod doc_attr;
 mod endpoint_attr;
 mod event_attr;
@@ -10,6 +11,7 @@ mod trait_prop_names;
 mod util;
 
 pub use argument_attr::*;
+pub use codec_attr::CodecAttribute;
 pub use doc_attr::{extract_doc, OutputNameAttribute};
 pub use endpoint_attr::*;
 pub use event_attr::*;
@@ -6,7 +6,7 @@ use crate::model::{
 use super::{
     attributes::{
         is_callback_raw, is_init, is_only_owner, CallbackAttribute, EndpointAttribute,
-        ExternalViewAttribute, OutputNameAttribute, ViewAttribute,
+        ExternalViewAttribute, OnlyRoleAttribute, OutputNameAttribute, ViewAttribute,
     },
     MethodAttributesPass1,
 };
@@ -44,6 +44,21 @@ pub fn process_only_owner_attribute(
     is_only_owner
 }
 
+pub fn process_only_role_attribute(
+    attr: &syn::Attribute,
+    pass_1_data: &mut MethodAttributesPass1,
+) -> bool {
+    OnlyRoleAttribute::parse(attr)
+        .map(|only_role_attr| {
+            assert!(
+                pass_1_data.only_role.is_none(),
+                "only one `#[only_role]` attribute allowed per endpoint"
+            );
+            pass_1_data.only_role = Some(only_role_attr.role);
+        })
+        .is_some()
+}
+
 pub fn process_endpoint_attribute(
     attr: &syn::Attribute,
     pass_1_data: &MethodAttributesPass1,
@@ -60,6 +75,7 @@ pub fn process_endpoint_attribute(
                 public_name: endpoint_ident,
                 payable: pass_1_data.payable.clone(),
                 only_owner: pass_1_data.only_owner,
+                only_role: pass_1_data.only_role.clone(),
                 mutability: EndpointMutabilityMetadata::Mutable,
                 location: EndpointLocationMetadata::MainContract,
             });
@@ -83,6 +99,7 @@ pub fn process_view_attribute(
                 public_name: view_ident,
                 payable: pass_1_data.payable.clone(),
                 only_owner: pass_1_data.only_owner,
+                only_role: pass_1_data.only_role.clone(),
                 mutability: EndpointMutabilityMetadata::Readonly,
                 location: EndpointLocationMetadata::MainContract,
             });
@@ -106,6 +123,7 @@ pub fn process_external_view_attribute(
                 public_name: view_ident,
                 payable: pass_1_data.payable.clone(),
                 only_owner: pass_1_data.only_owner,
+                only_role: pass_1_data.only_role.clone(),
                 mutability: EndpointMutabilityMetadata::Readonly,
                 location: EndpointLocationMetadata::ViewContract,
             });
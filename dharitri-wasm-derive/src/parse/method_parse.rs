@@ -10,13 +10,14 @@ use super::{
     },
     extract_method_args, process_callback_attribute, process_callback_raw_attribute,
     process_endpoint_attribute, process_external_view_attribute, process_init_attribute,
-    process_only_owner_attribute, process_output_names_attribute, process_payable_attribute,
-    process_view_attribute,
+    process_only_owner_attribute, process_only_role_attribute, process_output_names_attribute,
+    process_payable_attribute, process_view_attribute,
 };
 pub struct MethodAttributesPass1 {
     pub method_name: String,
     pub payable: MethodPayableMetadata,
     pub only_owner: bool,
+    pub only_role: Option<String>,
 }
 
 pub fn process_method(m: &syn::TraitItemMethod, trait_attributes: &TraitProperties) -> Method {
@@ -32,6 +33,7 @@ pub fn process_method(m: &syn::TraitItemMethod, trait_attributes: &TraitProperti
         method_name: m.sig.ident.to_string(),
         payable: MethodPayableMetadata::NotPayable,
         only_owner: trait_attributes.only_owner,
+        only_role: None,
     };
     let mut first_pass_unprocessed_attributes = Vec::new();
 
@@ -81,6 +83,7 @@ fn process_attribute_first_pass(
 ) -> bool {
     process_payable_attribute(attr, first_pass_data)
         || process_only_owner_attribute(attr, first_pass_data)
+        || process_only_role_attribute(attr, first_pass_data)
 }
 
 fn process_attributes_second_pass(
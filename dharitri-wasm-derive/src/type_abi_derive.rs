@@ -1,15 +1,27 @@
-use super::parse::attributes::extract_doc;
+use super::parse::attributes::{extract_doc, CodecAttribute};
 use proc_macro::TokenStream;
 use quote::quote;
 
 fn field_snippet(index: usize, field: &syn::Field) -> proc_macro2::TokenStream {
+    let codec_attribute = CodecAttribute::parse_all(field.attrs.as_slice());
+    if codec_attribute.skip {
+        // skipped fields are not serialized, so they do not show up in the ABI
+        return quote! {};
+    }
+
     let field_docs = extract_doc(field.attrs.as_slice());
     let field_name_str = if let Some(ident) = &field.ident {
         ident.to_string()
     } else {
         index.to_string()
     };
-    let field_ty = &field.ty;
+    let field_ty = if let Some(with_module) = &codec_attribute.with {
+        // custom encoding, the module declares the type that describes it in the ABI
+        quote! { #with_module::AbiType }
+    } else {
+        let ty = &field.ty;
+        quote! { #ty }
+    };
     quote! {
         field_descriptions.push(dharitri_wasm::abi::StructFieldDescription {
             docs: &[ #(#field_docs),* ],
@@ -43,6 +55,10 @@ pub fn type_abi_derive(ast: &syn::DeriveInput) -> TokenStream {
     let type_description_impl = match &ast.data {
         syn::Data::Struct(data_struct) => {
             let struct_field_snippets = fields_snippets(&data_struct.fields);
+            let version = match CodecAttribute::parse_all(ast.attrs.as_slice()).version {
+                Some(version) => quote! { core::option::Option::Some(#version) },
+                None => quote! { core::option::Option::None },
+            };
             quote! {
                 fn provide_type_descriptions<TDC: dharitri_wasm::abi::TypeDescriptionContainer>(accumulator: &mut TDC) {
                     let type_name = Self::type_name();
@@ -56,6 +72,7 @@ pub fn type_abi_derive(ast: &syn::DeriveInput) -> TokenStream {
                                 docs: &[ #(#type_docs),* ],
                                 name: type_name,
                                 contents: dharitri_wasm::abi::TypeContents::Struct(field_descriptions),
+                                version: #version,
                             },
                         );
                     }
@@ -63,11 +80,17 @@ pub fn type_abi_derive(ast: &syn::DeriveInput) -> TokenStream {
             }
         },
         syn::Data::Enum(data_enum) => {
+            let mut next_discriminant = 0usize;
             let enum_variant_snippets: Vec<proc_macro2::TokenStream> = data_enum
                 .variants
                 .iter()
-                .enumerate()
-                .map(|(variant_index, variant)| {
+                .map(|variant| {
+                    let codec_attribute = CodecAttribute::parse_all(variant.attrs.as_slice());
+                    if let Some(discriminant) = codec_attribute.discriminant {
+                        next_discriminant = discriminant as usize;
+                    }
+                    let variant_discriminant = next_discriminant;
+                    next_discriminant += 1;
                     let variant_docs = extract_doc(variant.attrs.as_slice());
                     let variant_name_str = variant.ident.to_string();
                     let variant_field_snippets = fields_snippets(&variant.fields);
@@ -76,7 +99,7 @@ pub fn type_abi_derive(ast: &syn::DeriveInput) -> TokenStream {
                         #(#variant_field_snippets)*
                         variant_descriptions.push(dharitri_wasm::abi::EnumVariantDescription {
                             docs: &[ #(#variant_docs),* ],
-                            discriminant: #variant_index,
+                            discriminant: #variant_discriminant,
                             name: #variant_name_str,
                             fields: field_descriptions,
                         });
@@ -96,6 +119,7 @@ pub fn type_abi_derive(ast: &syn::DeriveInput) -> TokenStream {
                                 docs: &[ #(#type_docs),* ],
                                 name: type_name,
                                 contents: dharitri_wasm::abi::TypeContents::Enum(variant_descriptions),
+                                version: core::option::Option::None,
                             },
                         );
                     }
@@ -0,0 +1,140 @@
+dharitri_wasm::imports!();
+
+/// The role that administers all roles without an explicitly configured admin role.
+pub const DEFAULT_ADMIN_ROLE: &[u8] = b"DEFAULT_ADMIN";
+
+/// Standard smart contract module that, when added to a smart contract, offers role-based access control.
+///
+/// Roles are identified by name (e.g. `MINTER`, `OPERATOR`) and are granted per address.
+/// Each role has an admin role, whose members can grant and revoke it.
+/// Roles with no configured admin are administered by `DEFAULT_ADMIN`.
+/// The contract owner can always grant and revoke any role, so the first admins can be set up without an init call.
+///
+/// It offers:
+/// * endpoints to grant, revoke and renounce roles
+/// * an owner-only endpoint to change the admin role of a role
+/// * views to check role membership
+/// * `roleGranted`, `roleRevoked` and `roleAdminChanged` events
+///
+/// Endpoints can be restricted to a role with the `#[only_role("ROLE_NAME")]` attribute,
+/// which calls `check_caller_has_role` before the endpoint arguments are loaded.
+///
+#[dharitri_wasm::module]
+pub trait AccessControlModule {
+    #[endpoint(grantRole)]
+    fn grant_role_endpoint(&self, role: ManagedBuffer, address: ManagedAddress) {
+        self.require_caller_is_role_admin(&role);
+        self.grant_role(&role, address);
+    }
+
+    #[endpoint(revokeRole)]
+    fn revoke_role_endpoint(&self, role: ManagedBuffer, address: ManagedAddress) {
+        self.require_caller_is_role_admin(&role);
+        self.revoke_role(&role, &address);
+    }
+
+    /// Allows the caller to give up one of its own roles.
+    #[endpoint(renounceRole)]
+    fn renounce_role_endpoint(&self, role: ManagedBuffer) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.role_members(&role).contains(&caller),
+            "caller does not have role"
+        );
+        self.revoke_role(&role, &caller);
+    }
+
+    #[only_owner]
+    #[endpoint(setRoleAdmin)]
+    fn set_role_admin_endpoint(&self, role: ManagedBuffer, admin_role: ManagedBuffer) {
+        let previous_admin_role = self.get_role_admin(role.clone());
+        self.role_admin(&role).set(&admin_role);
+        self.role_admin_changed_event(&role, &previous_admin_role, &admin_role);
+    }
+
+    #[view(hasRole)]
+    fn has_role(&self, role: ManagedBuffer, address: ManagedAddress) -> bool {
+        self.role_members(&role).contains(&address)
+    }
+
+    #[view(getRoleAdmin)]
+    fn get_role_admin(&self, role: ManagedBuffer) -> ManagedBuffer {
+        let admin_role_mapper = self.role_admin(&role);
+        if admin_role_mapper.is_empty() {
+            ManagedBuffer::new_from_bytes(DEFAULT_ADMIN_ROLE)
+        } else {
+            admin_role_mapper.get()
+        }
+    }
+
+    /// Grants the role without checking the caller.
+    /// Useful in the contract constructor, to set up the initial admins.
+    fn grant_role(&self, role: &ManagedBuffer, address: ManagedAddress) {
+        if self.role_members(role).insert(address.clone()) {
+            let caller = self.blockchain().get_caller();
+            self.role_granted_event(role, &address, &caller);
+        }
+    }
+
+    /// Revokes the role without checking the caller.
+    fn revoke_role(&self, role: &ManagedBuffer, address: &ManagedAddress) {
+        if self.role_members(role).swap_remove(address) {
+            let caller = self.blockchain().get_caller();
+            self.role_revoked_event(role, address, &caller);
+        }
+    }
+
+    /// Called by endpoints annotated with `#[only_role("ROLE_NAME")]`.
+    fn check_caller_has_role(&self, role: &'static [u8]) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.role_members(&role.into()).contains(&caller),
+            "caller does not have the {} role",
+            role
+        );
+    }
+
+    fn require_caller_is_role_admin(&self, role: &ManagedBuffer) {
+        let caller = self.blockchain().get_caller();
+        if caller == self.blockchain().get_owner_address() {
+            return;
+        }
+
+        let admin_role = self.get_role_admin(role.clone());
+        require!(
+            self.role_members(&admin_role).contains(&caller),
+            "caller is not a role admin"
+        );
+    }
+
+    #[view(getRoleMembers)]
+    #[storage_mapper("access_control:members")]
+    fn role_members(&self, role: &ManagedBuffer) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("access_control:admin")]
+    fn role_admin(&self, role: &ManagedBuffer) -> SingleValueMapper<ManagedBuffer>;
+
+    #[event("roleGranted")]
+    fn role_granted_event(
+        &self,
+        #[indexed] role: &ManagedBuffer,
+        #[indexed] account: &ManagedAddress,
+        #[indexed] sender: &ManagedAddress,
+    );
+
+    #[event("roleRevoked")]
+    fn role_revoked_event(
+        &self,
+        #[indexed] role: &ManagedBuffer,
+        #[indexed] account: &ManagedAddress,
+        #[indexed] sender: &ManagedAddress,
+    );
+
+    #[event("roleAdminChanged")]
+    fn role_admin_changed_event(
+        &self,
+        #[indexed] role: &ManagedBuffer,
+        #[indexed] previous_admin_role: &ManagedBuffer,
+        #[indexed] new_admin_role: &ManagedBuffer,
+    );
+}
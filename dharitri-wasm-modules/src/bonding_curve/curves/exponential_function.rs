@@ -0,0 +1,32 @@
+dharitri_wasm::imports!();
+dharitri_wasm::derive_imports!();
+
+use crate::bonding_curve::{
+    curves::{curve_function::CurveFunction, math},
+    utils::structs::CurveArguments,
+};
+
+/// `y = a * e^(x / s)`, priced as the integral `a * s * (e^(x / s) - 1)`.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, PartialEq, Clone)]
+pub struct ExponentialFunction<M: ManagedTypeApi> {
+    pub initial_price: BigUint<M>,
+    pub scale: BigUint<M>,
+}
+
+impl<M: ManagedTypeApi> ExponentialFunction<M> {
+    fn primitive(&self, x: &BigUint<M>) -> BigUint<M> {
+        let growth = math::exp(x, &self.scale) - math::one();
+        &self.initial_price * &self.scale * growth / math::one()
+    }
+}
+
+impl<M: ManagedTypeApi> CurveFunction<M> for ExponentialFunction<M> {
+    fn calculate_price(
+        &self,
+        token_start: &BigUint<M>,
+        amount: &BigUint<M>,
+        _arguments: &CurveArguments<M>,
+    ) -> BigUint<M> {
+        self.primitive(&(token_start + amount)) - self.primitive(token_start)
+    }
+}
@@ -0,0 +1,39 @@
+dharitri_wasm::imports!();
+dharitri_wasm::derive_imports!();
+
+use crate::bonding_curve::{
+    curves::{curve_function::CurveFunction, math},
+    utils::structs::CurveArguments,
+};
+
+/// `y = a * ln(1 + x / s)`, priced as the integral `a * ((x + s) * ln(1 + x / s) - x)`.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, PartialEq, Clone)]
+pub struct LogarithmicFunction<M: ManagedTypeApi> {
+    pub coefficient: BigUint<M>,
+    pub scale: BigUint<M>,
+}
+
+impl<M: ManagedTypeApi> LogarithmicFunction<M> {
+    fn primitive(&self, x: &BigUint<M>) -> BigUint<M> {
+        let shifted = x + &self.scale;
+        let log = math::ln(&(&shifted * &math::one() / &self.scale));
+        let area = shifted * log;
+        let offset = x * &math::one();
+        if area <= offset {
+            // only reachable through rounding, the exact value is never negative
+            return BigUint::zero();
+        }
+        &self.coefficient * &(area - offset) / math::one()
+    }
+}
+
+impl<M: ManagedTypeApi> CurveFunction<M> for LogarithmicFunction<M> {
+    fn calculate_price(
+        &self,
+        token_start: &BigUint<M>,
+        amount: &BigUint<M>,
+        _arguments: &CurveArguments<M>,
+    ) -> BigUint<M> {
+        self.primitive(&(token_start + amount)) - self.primitive(token_start)
+    }
+}
@@ -0,0 +1,80 @@
+//! Fixed-point helpers used by the non-polynomial curves.
+//!
+//! All values are scaled by `10^18`, so `one()` represents `1.0`.
+//! Every operation rounds down, which keeps the results deterministic
+//! and monotonic in their arguments.
+
+dharitri_wasm::imports!();
+
+pub const PRECISION: u64 = 1_000_000_000_000_000_000;
+const E: u64 = 2_718_281_828_459_045_235;
+const LN_2: u64 = 693_147_180_559_945_309;
+const LOG2_FRACTIONAL_BITS: usize = 64;
+
+pub fn one<M: ManagedTypeApi>() -> BigUint<M> {
+    BigUint::from(PRECISION)
+}
+
+/// Computes `e^(numerator / denominator)`, scaled by `10^18`.
+pub fn exp<M: ManagedTypeApi>(numerator: &BigUint<M>, denominator: &BigUint<M>) -> BigUint<M> {
+    let one = one::<M>();
+    let integer_part = (numerator / denominator)
+        .to_u64()
+        .unwrap_or_else(|| M::error_api_impl().signal_error(b"exponent too large"));
+    let remainder = numerator % denominator;
+
+    // e^(remainder / denominator) < e, the Taylor series converges quickly
+    let mut term = one.clone();
+    let mut fractional_result = one.clone();
+    let mut k = 1u32;
+    while term > 0 {
+        term = term * &remainder / (denominator * k);
+        fractional_result += &term;
+        k += 1;
+    }
+
+    pow_fixed(&BigUint::from(E), integer_part) * fractional_result / one
+}
+
+/// Computes `ln(x)`, where both `x` and the result are scaled by `10^18`.
+/// Only defined for `x >= 1`.
+pub fn ln<M: ManagedTypeApi>(x: &BigUint<M>) -> BigUint<M> {
+    let one = one::<M>();
+    let two = &one * 2u32;
+    if x < &one {
+        M::error_api_impl().signal_error(b"logarithm argument must be at least 1");
+    }
+
+    let integer_part = (x / &one).log2();
+    let mut y = x / &BigUint::from(2u32).pow(integer_part);
+    let mut log2_result = &one * integer_part;
+
+    let mut bit = &one / 2u32;
+    for _ in 0..LOG2_FRACTIONAL_BITS {
+        y = &y * &y / &one;
+        if y >= two {
+            log2_result += &bit;
+            y /= 2u32;
+        }
+        bit /= 2u32;
+    }
+
+    log2_result * LN_2 / one
+}
+
+/// Computes `base^exp`, where `base` and the result are scaled by `10^18`.
+fn pow_fixed<M: ManagedTypeApi>(base: &BigUint<M>, mut exp: u64) -> BigUint<M> {
+    let one = one::<M>();
+    let mut result = one.clone();
+    let mut power = base.clone();
+    while exp > 0 {
+        if exp % 2 == 1 {
+            result = result * &power / &one;
+        }
+        exp /= 2;
+        if exp > 0 {
+            power = &power * &power / &one;
+        }
+    }
+    result
+}
@@ -1,2 +1,8 @@
 pub mod curve_function;
+pub mod exponential_function;
 pub mod linear_function;
+pub mod logarithmic_function;
+pub mod math;
+pub mod piecewise_linear_function;
+pub mod power_function;
+pub mod sigmoid_function;
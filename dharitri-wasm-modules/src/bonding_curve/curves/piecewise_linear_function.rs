@@ -0,0 +1,70 @@
+dharitri_wasm::imports!();
+dharitri_wasm::derive_imports!();
+
+use crate::bonding_curve::{curves::curve_function::CurveFunction, utils::structs::CurveArguments};
+
+#[derive(
+    ManagedVecItem, TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, PartialEq, Clone,
+)]
+pub struct CurvePoint<M: ManagedTypeApi> {
+    pub supply: BigUint<M>,
+    pub price: BigUint<M>,
+}
+
+/// Price linearly interpolated between the given points, ordered by strictly increasing supply.
+/// Before the first point and after the last one the price stays constant.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, PartialEq, Clone)]
+pub struct PiecewiseLinearFunction<M: ManagedTypeApi> {
+    pub points: ManagedVec<M, CurvePoint<M>>,
+}
+
+impl<M: ManagedTypeApi> PiecewiseLinearFunction<M> {
+    pub fn is_valid(&self) -> bool {
+        if self.points.is_empty() {
+            return false;
+        }
+        let mut previous = self.points.get(0);
+        for point in self.points.iter().skip(1) {
+            if point.supply <= previous.supply {
+                return false;
+            }
+            previous = point;
+        }
+        true
+    }
+
+    /// Sums up the trapezoids under the curve.
+    /// Areas are doubled until the end, to only round once per segment.
+    fn primitive(&self, x: &BigUint<M>) -> BigUint<M> {
+        let mut previous = self.points.get(0);
+        let flat_start = core::cmp::min(x, &previous.supply);
+        let mut doubled_area = flat_start * &previous.price * 2u32;
+
+        for point in self.points.iter().skip(1) {
+            if x <= &previous.supply {
+                return doubled_area / 2u32;
+            }
+            let width = &point.supply - &previous.supply;
+            let covered = core::cmp::min(x - &previous.supply, width.clone());
+            let heights = &previous.price * &(&width * 2u32 - &covered) + &point.price * &covered;
+            doubled_area += covered * heights / width;
+            previous = point;
+        }
+
+        if x > &previous.supply {
+            doubled_area += (x - &previous.supply) * &previous.price * 2u32;
+        }
+        doubled_area / 2u32
+    }
+}
+
+impl<M: ManagedTypeApi> CurveFunction<M> for PiecewiseLinearFunction<M> {
+    fn calculate_price(
+        &self,
+        token_start: &BigUint<M>,
+        amount: &BigUint<M>,
+        _arguments: &CurveArguments<M>,
+    ) -> BigUint<M> {
+        self.primitive(&(token_start + amount)) - self.primitive(token_start)
+    }
+}
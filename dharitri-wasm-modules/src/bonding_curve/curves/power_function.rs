@@ -0,0 +1,28 @@
+dharitri_wasm::imports!();
+dharitri_wasm::derive_imports!();
+
+use crate::bonding_curve::{curves::curve_function::CurveFunction, utils::structs::CurveArguments};
+
+/// `y = a * x^n`, priced as the integral `a * x^(n + 1) / (n + 1)`.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, PartialEq, Clone)]
+pub struct PowerFunction<M: ManagedTypeApi> {
+    pub coefficient: BigUint<M>,
+    pub exponent: u32,
+}
+
+impl<M: ManagedTypeApi> PowerFunction<M> {
+    fn primitive(&self, x: &BigUint<M>) -> BigUint<M> {
+        &self.coefficient * &x.pow(self.exponent + 1) / (self.exponent + 1)
+    }
+}
+
+impl<M: ManagedTypeApi> CurveFunction<M> for PowerFunction<M> {
+    fn calculate_price(
+        &self,
+        token_start: &BigUint<M>,
+        amount: &BigUint<M>,
+        _arguments: &CurveArguments<M>,
+    ) -> BigUint<M> {
+        self.primitive(&(token_start + amount)) - self.primitive(token_start)
+    }
+}
@@ -0,0 +1,41 @@
+dharitri_wasm::imports!();
+dharitri_wasm::derive_imports!();
+
+use crate::bonding_curve::{
+    curves::{curve_function::CurveFunction, math},
+    utils::structs::CurveArguments,
+};
+
+/// `y = a / (1 + e^(-(x - m) / s))`, priced as the integral `a * s * ln(1 + e^((x - m) / s))`.
+///
+/// The price starts close to 0, reaches `a / 2` at the `midpoint` and approaches `a` afterwards.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, PartialEq, Clone)]
+pub struct SigmoidFunction<M: ManagedTypeApi> {
+    pub max_price: BigUint<M>,
+    pub midpoint: BigUint<M>,
+    pub scale: BigUint<M>,
+}
+
+impl<M: ManagedTypeApi> SigmoidFunction<M> {
+    fn primitive(&self, x: &BigUint<M>) -> BigUint<M> {
+        let one = math::one::<M>();
+        let exp = if x >= &self.midpoint {
+            math::exp(&(x - &self.midpoint), &self.scale)
+        } else {
+            &one * &one / math::exp(&(&self.midpoint - x), &self.scale)
+        };
+        let log = math::ln(&(&one + &exp));
+        &self.max_price * &self.scale * log / one
+    }
+}
+
+impl<M: ManagedTypeApi> CurveFunction<M> for SigmoidFunction<M> {
+    fn calculate_price(
+        &self,
+        token_start: &BigUint<M>,
+        amount: &BigUint<M>,
+        _arguments: &CurveArguments<M>,
+    ) -> BigUint<M> {
+        self.primitive(&(token_start + amount)) - self.primitive(token_start)
+    }
+}
@@ -2,7 +2,12 @@ dharitri_wasm::imports!();
 dharitri_wasm::derive_imports!();
 
 use crate::bonding_curve::{
-    curves::{curve_function::CurveFunction, linear_function::LinearFunction},
+    curves::{
+        curve_function::CurveFunction, exponential_function::ExponentialFunction,
+        linear_function::LinearFunction, logarithmic_function::LogarithmicFunction,
+        piecewise_linear_function::PiecewiseLinearFunction, power_function::PowerFunction,
+        sigmoid_function::SigmoidFunction,
+    },
     utils::structs::CurveArguments,
 };
 
@@ -11,12 +16,30 @@ pub enum FunctionSelector<M: ManagedTypeApi> {
     Linear(LinearFunction<M>),
     CustomExample(BigUint<M>),
     None,
+    Exponential(ExponentialFunction<M>),
+    Power(PowerFunction<M>),
+    Logarithmic(LogarithmicFunction<M>),
+    Sigmoid(SigmoidFunction<M>),
+    PiecewiseLinear(PiecewiseLinearFunction<M>),
 }
 
 impl<M: ManagedTypeApi> FunctionSelector<M> {
     pub fn is_none(&self) -> bool {
         matches!(self, FunctionSelector::None)
     }
+
+    /// Checks the parameters that would otherwise cause divisions by zero or unordered intervals.
+    pub fn is_valid(&self) -> bool {
+        match &self {
+            FunctionSelector::Exponential(exponential_function) => exponential_function.scale > 0,
+            FunctionSelector::Logarithmic(logarithmic_function) => logarithmic_function.scale > 0,
+            FunctionSelector::Sigmoid(sigmoid_function) => sigmoid_function.scale > 0,
+            FunctionSelector::PiecewiseLinear(piecewise_linear_function) => {
+                piecewise_linear_function.is_valid()
+            },
+            _ => true,
+        }
+    }
 }
 
 impl<M: ManagedTypeApi> CurveFunction<M> for FunctionSelector<M> {
@@ -30,6 +53,26 @@ impl<M: ManagedTypeApi> CurveFunction<M> for FunctionSelector<M> {
             FunctionSelector::Linear(linear_function) => {
                 CurveFunction::calculate_price(linear_function, token_start, amount, arguments)
             },
+            FunctionSelector::Exponential(exponential_function) => {
+                CurveFunction::calculate_price(exponential_function, token_start, amount, arguments)
+            },
+            FunctionSelector::Power(power_function) => {
+                CurveFunction::calculate_price(power_function, token_start, amount, arguments)
+            },
+            FunctionSelector::Logarithmic(logarithmic_function) => {
+                CurveFunction::calculate_price(logarithmic_function, token_start, amount, arguments)
+            },
+            FunctionSelector::Sigmoid(sigmoid_function) => {
+                CurveFunction::calculate_price(sigmoid_function, token_start, amount, arguments)
+            },
+            FunctionSelector::PiecewiseLinear(piecewise_linear_function) => {
+                CurveFunction::calculate_price(
+                    piecewise_linear_function,
+                    token_start,
+                    amount,
+                    arguments,
+                )
+            },
 
             FunctionSelector::CustomExample(initial_cost) => {
                 let sum = token_start + amount;
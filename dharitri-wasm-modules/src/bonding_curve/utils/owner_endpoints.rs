@@ -60,6 +60,7 @@ pub trait OwnerEndpointsModule: storage::StorageModule + events::EventsModule {
             details.owner == caller,
             "The price function can only be set by the seller."
         );
+        require!(function.is_valid(), "Invalid bonding curve parameters");
         self.bonding_curve(&identifier).update(|bonding_curve| {
             bonding_curve.curve = function;
             bonding_curve.sell_availability = sell_availability
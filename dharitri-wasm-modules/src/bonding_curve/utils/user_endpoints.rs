@@ -206,7 +206,7 @@ pub trait UserEndpointsModule: storage::StorageModule + events::EventsModule {
         let bonding_curve = self.bonding_curve(issued_token).get();
 
         require!(
-            bonding_curve.curve.is_none(),
+            !bonding_curve.curve.is_none(),
             "The token price was not set yet!"
         );
         require!(amount > &0, "Must pay more than 0 tokens!");
@@ -1,5 +1,10 @@
 dharitri_wasm::imports!();
 
+use super::{
+    governance_proposal::{QuorumRule, PERCENTAGE_TOTAL},
+    strategy_selector::VotingStrategySelector,
+};
+
 /// # Dharitri smart contract module - Governance
 ///
 /// This is a standard smart contract module, that when added to a smart contract offers governance features:
@@ -10,7 +15,9 @@ dharitri_wasm::imports!();
 /// Voting can only be done by depositing a certain token, decided upon first time setup.  
 ///
 /// The module provides the following configurable parameters:  
-/// - `quorum` - the minimum number of (`votes` minus `downvotes`) at the end of voting period  
+/// - `quorum` - the minimum number of (`votes` minus `downvotes`) at the end of voting period, unless a different quorum rule is configured  
+/// - `quorumRule` - how `quorum` is checked: vote difference (default), total participation, or participation as percentage of a supply  
+/// - `votingStrategy` - how deposited tokens translate into voting power: linear (default) or quadratic  
 /// - `minTokenBalanceForProposing` - Minimum numbers of tokens the proposer has to deposit. These automatically count as `votes` as well  
 /// - `maxActionsPerProposal` - Maximum number of actions (transfers and/or smart contract calls) that a proposal may have  
 /// - `votingDelayInBlocks` - Number of blocks to wait after a block is proposed before being able to vote/downvote that proposal
@@ -92,6 +99,20 @@ pub trait GovernanceConfigurablePropertiesModule {
         self.try_change_voting_period_in_blocks(new_value);
     }
 
+    #[endpoint(changeQuorumRule)]
+    fn change_quorum_rule(&self, new_value: QuorumRule<Self::Api>) {
+        self.require_caller_self();
+
+        self.try_change_quorum_rule(new_value);
+    }
+
+    #[endpoint(changeVotingStrategy)]
+    fn change_voting_strategy(&self, new_value: VotingStrategySelector) {
+        self.require_caller_self();
+
+        self.voting_strategy().set(new_value);
+    }
+
     #[endpoint(changeLockTimeAfterVotingEndsInBlocks)]
     fn change_lock_time_after_voting_ends_in_blocks(&self, new_value: u64) {
         self.require_caller_self();
@@ -113,10 +134,28 @@ pub trait GovernanceConfigurablePropertiesModule {
 
     fn try_change_quorum(&self, new_value: BigUint) {
         require!(new_value != 0, "Quorum can't be set to 0");
+        if self.quorum_rule().get().is_percentage() {
+            require!(
+                new_value <= PERCENTAGE_TOTAL,
+                "Quorum percentage can't be over 100%"
+            );
+        }
 
         self.quorum().set(&new_value);
     }
 
+    fn try_change_quorum_rule(&self, new_value: QuorumRule<Self::Api>) {
+        if let QuorumRule::PercentageOfSupply(supply) = &new_value {
+            require!(*supply != 0, "Quorum supply can't be set to 0");
+            require!(
+                self.quorum().get() <= PERCENTAGE_TOTAL,
+                "Quorum percentage can't be over 100%"
+            );
+        }
+
+        self.quorum_rule().set(&new_value);
+    }
+
     fn try_change_min_token_balance_for_proposing(&self, new_value: BigUint) {
         require!(
             new_value != 0,
@@ -162,6 +201,14 @@ pub trait GovernanceConfigurablePropertiesModule {
     #[storage_mapper("governance:quorum")]
     fn quorum(&self) -> SingleValueMapper<BigUint>;
 
+    #[view(getQuorumRule)]
+    #[storage_mapper("governance:quorumRule")]
+    fn quorum_rule(&self) -> SingleValueMapper<QuorumRule<Self::Api>>;
+
+    #[view(getVotingStrategy)]
+    #[storage_mapper("governance:votingStrategy")]
+    fn voting_strategy(&self) -> SingleValueMapper<VotingStrategySelector>;
+
     #[view(getMinTokenBalanceForProposing)]
     #[storage_mapper("governance:minTokenBalanceForProposing")]
     fn min_token_balance_for_proposing(&self) -> SingleValueMapper<BigUint>;
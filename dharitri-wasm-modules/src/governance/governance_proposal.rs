@@ -3,6 +3,9 @@ dharitri_wasm::derive_imports!();
 
 pub(crate) const MAX_ACTIONS: usize = 20;
 
+/// Quorum percentages are expressed in hundredths of a percent, i.e. 10_000 is 100%.
+pub const PERCENTAGE_TOTAL: u64 = 10_000;
+
 pub type GovernanceActionAsMultiArg<M> = MultiValue7<
     u64,
     ManagedAddress<M>,
@@ -23,6 +26,55 @@ pub enum GovernanceProposalStatus {
     Queued,
 }
 
+#[derive(TypeAbi, TopEncode, TopDecode, PartialEq, Clone, Copy)]
+pub enum VoteType {
+    None,
+    UpVote,
+    DownVote,
+    AbstainVote,
+}
+
+/// Decides how the `quorum` parameter is checked at the end of the voting period.
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone)]
+pub enum QuorumRule<M: ManagedTypeApi> {
+    /// `votes` minus `downvotes` must reach `quorum`.
+    VoteDifference,
+
+    /// `votes`, `downvotes` and abstain votes added together must reach `quorum`.
+    Participation,
+
+    /// `votes`, `downvotes` and abstain votes added together must reach a percentage of the given supply.
+    /// In this case `quorum` is the percentage, relative to `PERCENTAGE_TOTAL`.
+    PercentageOfSupply(BigUint<M>),
+}
+
+impl<M: ManagedTypeApi> QuorumRule<M> {
+    pub fn is_percentage(&self) -> bool {
+        matches!(self, QuorumRule::PercentageOfSupply(_))
+    }
+
+    pub fn is_quorum_reached(
+        &self,
+        quorum: &BigUint<M>,
+        total_votes: &BigUint<M>,
+        total_downvotes: &BigUint<M>,
+        total_abstain_votes: &BigUint<M>,
+    ) -> bool {
+        match self {
+            QuorumRule::VoteDifference => {
+                total_votes > total_downvotes && &(total_votes - total_downvotes) >= quorum
+            },
+            QuorumRule::Participation => {
+                &(total_votes + total_downvotes + total_abstain_votes) >= quorum
+            },
+            QuorumRule::PercentageOfSupply(supply) => {
+                let participation = total_votes + total_downvotes + total_abstain_votes;
+                participation * PERCENTAGE_TOTAL >= supply * quorum
+            },
+        }
+    }
+}
+
 #[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode)]
 pub struct GovernanceAction<M: ManagedTypeApi> {
     pub gas_limit: u64,
@@ -5,6 +5,10 @@ pub mod governance_configurable;
 pub mod governance_proposal;
 use governance_proposal::*;
 
+pub mod strategy_selector;
+pub mod voting_strategies;
+use voting_strategies::voting_strategy::VotingStrategy;
+
 const MAX_GAS_LIMIT_PER_BLOCK: u64 = 1_500_000_000;
 
 #[dharitri_wasm::module]
@@ -42,11 +46,22 @@ pub trait GovernanceModule:
         let governance_token_id = self.governance_token_id().get();
         let nr_votes_tokens = self.votes(proposal_id).get(&caller).unwrap_or_default();
         let nr_downvotes_tokens = self.downvotes(proposal_id).get(&caller).unwrap_or_default();
-        let total_tokens = nr_votes_tokens + nr_downvotes_tokens;
+        let nr_abstain_tokens = self
+            .abstain_votes(proposal_id)
+            .get(&caller)
+            .unwrap_or_default();
+        let nr_delegated_tokens = self
+            .delegated_votes(proposal_id)
+            .get(&caller)
+            .unwrap_or_default();
+        let total_tokens =
+            nr_votes_tokens + nr_downvotes_tokens + nr_abstain_tokens + nr_delegated_tokens;
 
         if total_tokens > 0 {
             self.votes(proposal_id).remove(&caller);
             self.downvotes(proposal_id).remove(&caller);
+            self.abstain_votes(proposal_id).remove(&caller);
+            self.delegated_votes(proposal_id).remove(&caller);
 
             self.send()
                 .direct(&caller, &governance_token_id, 0, &total_tokens, &[]);
@@ -120,8 +135,10 @@ pub trait GovernanceModule:
 
         self.proposal_start_block(proposal_id).set(&current_block);
 
-        self.total_votes(proposal_id).set(&payment_amount);
-        self.votes(proposal_id).insert(proposer, payment_amount);
+        let voting_power =
+            self.deposit_vote_tokens(proposal_id, VoteType::UpVote, &proposer, &payment_amount);
+        self.total_votes(proposal_id).set(&voting_power);
+        self.record_voter_choice(proposal_id, &proposer, VoteType::UpVote);
 
         proposal_id
     }
@@ -129,45 +146,68 @@ pub trait GovernanceModule:
     #[payable("*")]
     #[endpoint]
     fn vote(&self, #[payment_amount] payment_amount: BigUint, proposal_id: usize) {
-        self.require_payment_token_governance_token();
-        self.require_valid_proposal_id(proposal_id);
-        require!(
-            self.get_proposal_status(proposal_id) == GovernanceProposalStatus::Active,
-            "Proposal is not active"
-        );
+        self.require_can_vote(proposal_id);
 
         let voter = self.blockchain().get_caller();
 
         self.vote_cast_event(&voter, proposal_id, &payment_amount);
 
-        self.total_votes(proposal_id)
-            .update(|total_votes| *total_votes += &payment_amount);
-        self.votes(proposal_id)
-            .entry(voter)
-            .and_modify(|nr_votes| *nr_votes += &payment_amount)
-            .or_insert(payment_amount);
+        self.cast_vote(proposal_id, VoteType::UpVote, &voter, &payment_amount);
     }
 
     #[payable("*")]
     #[endpoint]
     fn downvote(&self, #[payment_amount] payment_amount: BigUint, proposal_id: usize) {
-        self.require_payment_token_governance_token();
-        self.require_valid_proposal_id(proposal_id);
-        require!(
-            self.get_proposal_status(proposal_id) == GovernanceProposalStatus::Active,
-            "Proposal is not active"
-        );
+        self.require_can_vote(proposal_id);
 
         let downvoter = self.blockchain().get_caller();
 
         self.downvote_cast_event(&downvoter, proposal_id, &payment_amount);
 
-        self.total_downvotes(proposal_id)
-            .update(|total_downvotes| *total_downvotes += &payment_amount);
-        self.downvotes(proposal_id)
-            .entry(downvoter)
-            .and_modify(|nr_downvotes| *nr_downvotes += &payment_amount)
-            .or_insert(payment_amount);
+        self.cast_vote(proposal_id, VoteType::DownVote, &downvoter, &payment_amount);
+    }
+
+    /// Abstain votes count neither for nor against the proposal,
+    /// but they do count towards the quorum, if the quorum rule is based on participation.
+    #[payable("*")]
+    #[endpoint]
+    fn abstain(&self, #[payment_amount] payment_amount: BigUint, proposal_id: usize) {
+        self.require_can_vote(proposal_id);
+
+        let voter = self.blockchain().get_caller();
+
+        self.abstain_vote_cast_event(&voter, proposal_id, &payment_amount);
+
+        self.cast_vote(proposal_id, VoteType::AbstainVote, &voter, &payment_amount);
+    }
+
+    /// Deposits tokens whose voting power is used by the delegate.
+    /// The power goes in the same direction as the first vote of the delegate on the proposal,
+    /// or is kept pending until the delegate votes.
+    /// The tokens can be withdrawn by the delegator, same as regular votes.
+    #[payable("*")]
+    #[endpoint(delegateVote)]
+    fn delegate_vote(
+        &self,
+        #[payment_amount] payment_amount: BigUint,
+        proposal_id: usize,
+        delegate: ManagedAddress,
+    ) {
+        self.require_can_vote(proposal_id);
+
+        let delegator = self.blockchain().get_caller();
+        require!(delegator != delegate, "Cannot delegate to self");
+
+        self.delegate_vote_cast_event(&delegator, &delegate, proposal_id, &payment_amount);
+
+        let voting_power =
+            self.deposit_vote_tokens(proposal_id, VoteType::None, &delegator, &payment_amount);
+        match self.voter_choice(proposal_id, &delegate).get() {
+            VoteType::None => self
+                .pending_delegated_voting_power(proposal_id, &delegate)
+                .update(|pending| *pending += &voting_power),
+            choice => self.add_voting_power(proposal_id, choice, &voting_power),
+        }
     }
 
     #[endpoint]
@@ -289,9 +329,16 @@ pub trait GovernanceModule:
 
         let total_votes = self.total_votes(proposal_id).get();
         let total_downvotes = self.total_downvotes(proposal_id).get();
+        let total_abstain_votes = self.total_abstain_votes(proposal_id).get();
         let quorum = self.quorum().get();
+        let quorum_reached = self.quorum_rule().get().is_quorum_reached(
+            &quorum,
+            &total_votes,
+            &total_downvotes,
+            &total_abstain_votes,
+        );
 
-        if total_votes > total_downvotes && total_votes - total_downvotes >= quorum {
+        if total_votes > total_downvotes && quorum_reached {
             GovernanceProposalStatus::Succeeded
         } else {
             GovernanceProposalStatus::Defeated
@@ -337,6 +384,78 @@ pub trait GovernanceModule:
 
     // private
 
+    fn require_can_vote(&self, proposal_id: usize) {
+        self.require_payment_token_governance_token();
+        self.require_valid_proposal_id(proposal_id);
+        require!(
+            self.get_proposal_status(proposal_id) == GovernanceProposalStatus::Active,
+            "Proposal is not active"
+        );
+    }
+
+    fn cast_vote(
+        &self,
+        proposal_id: usize,
+        vote_type: VoteType,
+        voter: &ManagedAddress,
+        payment_amount: &BigUint,
+    ) {
+        let voting_power = self.deposit_vote_tokens(proposal_id, vote_type, voter, payment_amount);
+        self.add_voting_power(proposal_id, vote_type, &voting_power);
+        self.record_voter_choice(proposal_id, voter, vote_type);
+    }
+
+    /// Keeps track of the deposited tokens, so they can be withdrawn later,
+    /// and converts them to voting power, according to the configured voting strategy.
+    /// `VoteType::None` stands for tokens deposited via delegation.
+    fn deposit_vote_tokens(
+        &self,
+        proposal_id: usize,
+        vote_type: VoteType,
+        voter: &ManagedAddress,
+        payment_amount: &BigUint,
+    ) -> BigUint {
+        let mut deposits = match vote_type {
+            VoteType::UpVote => self.votes(proposal_id),
+            VoteType::DownVote => self.downvotes(proposal_id),
+            VoteType::AbstainVote => self.abstain_votes(proposal_id),
+            VoteType::None => self.delegated_votes(proposal_id),
+        };
+        let previous_tokens = deposits.get(voter).unwrap_or_default();
+        let voting_power = self
+            .voting_strategy()
+            .get()
+            .voting_power(&previous_tokens, payment_amount);
+        deposits.insert(voter.clone(), previous_tokens + payment_amount);
+
+        voting_power
+    }
+
+    fn add_voting_power(&self, proposal_id: usize, vote_type: VoteType, voting_power: &BigUint) {
+        let total_mapper = match vote_type {
+            VoteType::UpVote => self.total_votes(proposal_id),
+            VoteType::DownVote => self.total_downvotes(proposal_id),
+            VoteType::AbstainVote => self.total_abstain_votes(proposal_id),
+            VoteType::None => return,
+        };
+        total_mapper.update(|total| *total += voting_power);
+    }
+
+    /// Only the first vote of an address decides where the voting power delegated to it goes.
+    fn record_voter_choice(&self, proposal_id: usize, voter: &ManagedAddress, vote_type: VoteType) {
+        let choice_mapper = self.voter_choice(proposal_id, voter);
+        if choice_mapper.get() != VoteType::None {
+            return;
+        }
+        choice_mapper.set(vote_type);
+
+        let pending_mapper = self.pending_delegated_voting_power(proposal_id, voter);
+        if !pending_mapper.is_empty() {
+            self.add_voting_power(proposal_id, vote_type, &pending_mapper.get());
+            pending_mapper.clear();
+        }
+    }
+
     fn require_payment_token_governance_token(&self) {
         require!(
             self.call_value().token() == self.governance_token_id().get(),
@@ -380,6 +499,7 @@ pub trait GovernanceModule:
 
         self.total_votes(proposal_id).clear();
         self.total_downvotes(proposal_id).clear();
+        self.total_abstain_votes(proposal_id).clear();
     }
 
     // events
@@ -410,6 +530,23 @@ pub trait GovernanceModule:
         nr_downvotes: &BigUint,
     );
 
+    #[event("abstainVoteCast")]
+    fn abstain_vote_cast_event(
+        &self,
+        #[indexed] voter: &ManagedAddress,
+        #[indexed] proposal_id: usize,
+        nr_votes: &BigUint,
+    );
+
+    #[event("delegateVoteCast")]
+    fn delegate_vote_cast_event(
+        &self,
+        #[indexed] delegator: &ManagedAddress,
+        #[indexed] delegate: &ManagedAddress,
+        #[indexed] proposal_id: usize,
+        nr_votes: &BigUint,
+    );
+
     #[event("proposalCanceled")]
     fn proposal_canceled_event(&self, #[indexed] proposal_id: usize);
 
@@ -446,13 +583,41 @@ pub trait GovernanceModule:
     #[storage_mapper("governance:downvotes")]
     fn downvotes(&self, proposal_id: usize) -> MapMapper<ManagedAddress, BigUint>;
 
+    #[storage_mapper("governance:abstainVotes")]
+    fn abstain_votes(&self, proposal_id: usize) -> MapMapper<ManagedAddress, BigUint>;
+
+    /// Tokens deposited via `delegateVote`, by delegator.
+    #[storage_mapper("governance:delegatedVotes")]
+    fn delegated_votes(&self, proposal_id: usize) -> MapMapper<ManagedAddress, BigUint>;
+
+    /// Voting power delegated to an address that has not voted yet on the proposal.
+    #[storage_mapper("governance:pendingDelegatedVotingPower")]
+    fn pending_delegated_voting_power(
+        &self,
+        proposal_id: usize,
+        delegate: &ManagedAddress,
+    ) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("governance:voterChoice")]
+    fn voter_choice(
+        &self,
+        proposal_id: usize,
+        voter: &ManagedAddress,
+    ) -> SingleValueMapper<VoteType>;
+
+    /// Total voting power in favor of the proposal.
     /// Could be calculated by iterating over the "votes" mapper, but that costs a lot of gas
     #[view(getTotalVotes)]
     #[storage_mapper("governance:totalVotes")]
     fn total_votes(&self, proposal_id: usize) -> SingleValueMapper<BigUint>;
 
+    /// Total voting power against the proposal.
     /// Could be calculated by iterating over the "downvotes" mapper, but that costs a lot of gas
     #[view(getTotalDownvotes)]
     #[storage_mapper("governance:totalDownvotes")]
     fn total_downvotes(&self, proposal_id: usize) -> SingleValueMapper<BigUint>;
+
+    #[view(getTotalAbstainVotes)]
+    #[storage_mapper("governance:totalAbstainVotes")]
+    fn total_abstain_votes(&self, proposal_id: usize) -> SingleValueMapper<BigUint>;
 }
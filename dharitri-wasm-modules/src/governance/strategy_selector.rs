@@ -0,0 +1,25 @@
+dharitri_wasm::imports!();
+dharitri_wasm::derive_imports!();
+
+use crate::governance::voting_strategies::{
+    linear_voting::LinearVoting, quadratic_voting::QuadraticVoting, voting_strategy::VotingStrategy,
+};
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, PartialEq, Clone, Copy)]
+pub enum VotingStrategySelector {
+    Linear,
+    Quadratic,
+}
+
+impl<M: ManagedTypeApi> VotingStrategy<M> for VotingStrategySelector {
+    fn voting_power(&self, previous_tokens: &BigUint<M>, new_tokens: &BigUint<M>) -> BigUint<M> {
+        match &self {
+            VotingStrategySelector::Linear => {
+                VotingStrategy::<M>::voting_power(&LinearVoting, previous_tokens, new_tokens)
+            },
+            VotingStrategySelector::Quadratic => {
+                VotingStrategy::<M>::voting_power(&QuadraticVoting, previous_tokens, new_tokens)
+            },
+        }
+    }
+}
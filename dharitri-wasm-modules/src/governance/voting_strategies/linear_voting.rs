@@ -0,0 +1,12 @@
+dharitri_wasm::imports!();
+
+use crate::governance::voting_strategies::voting_strategy::VotingStrategy;
+
+/// One token, one vote.
+pub struct LinearVoting;
+
+impl<M: ManagedTypeApi> VotingStrategy<M> for LinearVoting {
+    fn voting_power(&self, _previous_tokens: &BigUint<M>, new_tokens: &BigUint<M>) -> BigUint<M> {
+        new_tokens.clone()
+    }
+}
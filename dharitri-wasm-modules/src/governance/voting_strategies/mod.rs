@@ -0,0 +1,3 @@
+pub mod linear_voting;
+pub mod quadratic_voting;
+pub mod voting_strategy;
@@ -0,0 +1,16 @@
+dharitri_wasm::imports!();
+
+use crate::governance::voting_strategies::voting_strategy::VotingStrategy;
+
+/// The voting power of a voter is the square root of the tokens it deposited.
+///
+/// Power is computed over the cumulated deposit,
+/// so splitting a deposit into several smaller votes does not yield more power.
+pub struct QuadraticVoting;
+
+impl<M: ManagedTypeApi> VotingStrategy<M> for QuadraticVoting {
+    fn voting_power(&self, previous_tokens: &BigUint<M>, new_tokens: &BigUint<M>) -> BigUint<M> {
+        let total_tokens = previous_tokens + new_tokens;
+        total_tokens.sqrt() - previous_tokens.sqrt()
+    }
+}
@@ -0,0 +1,7 @@
+dharitri_wasm::imports!();
+
+pub trait VotingStrategy<M: ManagedTypeApi> {
+    /// The voting power gained by depositing `new_tokens`,
+    /// given that the same voter already deposited `previous_tokens` in the same direction.
+    fn voting_power(&self, previous_tokens: &BigUint<M>, new_tokens: &BigUint<M>) -> BigUint<M>;
+}
@@ -1,10 +1,12 @@
 #![no_std]
 
+pub mod access_control;
 pub mod default_issue_callbacks;
 pub mod dns;
 pub mod dct;
 pub mod features;
 pub mod pause;
+pub mod timelock;
 
 // TODO: remove alloc feature from the following, after they have been cleaned
 
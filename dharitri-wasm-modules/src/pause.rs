@@ -3,36 +3,187 @@ dharitri_wasm::imports!();
 /// Standard smart contract module that, when added to a smart contract, offers pausability.
 ///
 /// It provides a flag that contracts can use to check if owner decided to pause the entire contract.
+/// Individual endpoints can also be paused, keyed by endpoint name.
 /// Use the features module for more granular on/off switches.
 ///
 /// It offers:
-/// * an endpoint where the owner can pause/unpause contract
-/// * a method to check if contract is paused or not
+/// * an endpoint where the owner or the pause guardian can pause the contract or a single endpoint
+/// * an endpoint where the owner can unpause the contract or a single endpoint
+/// * an optional timestamp at which a pause automatically expires
+/// * methods to check if the contract or an endpoint is paused or not
+/// * `pauseContract`/`unpauseContract` and `pauseEndpoint`/`unpauseEndpoint` events
+///
+/// The pause guardian can only pause, unpausing is reserved to the owner.
 ///
 #[dharitri_wasm::module]
 pub trait PauseModule {
+    /// Returns true if the contract is paused and the automatic unpause timestamp (if any) was not yet reached.
     #[view(isPaused)]
-    #[storage_get("pause_module:paused")]
-    fn is_paused(&self) -> bool;
+    fn is_paused(&self) -> bool {
+        self.is_pause_in_effect(self.paused_flag().get(), self.unpause_timestamp().get())
+    }
 
     fn not_paused(&self) -> bool {
         !self.is_paused()
     }
 
-    #[storage_set("pause_module:paused")]
-    fn set_paused(&self, paused: bool);
+    /// Returns true if either the whole contract or the given endpoint is paused.
+    #[view(isEndpointPaused)]
+    fn is_endpoint_paused(&self, endpoint_name: ManagedBuffer) -> bool {
+        self.is_paused()
+            || self.is_pause_in_effect(
+                self.endpoint_paused_flag(&endpoint_name).get(),
+                self.endpoint_unpause_timestamp(&endpoint_name).get(),
+            )
+    }
 
-    #[only_owner]
+    fn require_not_paused(&self) {
+        require!(self.not_paused(), "Contract is paused");
+    }
+
+    fn require_endpoint_not_paused(&self, endpoint_name: &'static [u8]) {
+        require!(
+            !self.is_endpoint_paused(endpoint_name.into()),
+            "Endpoint {} is paused",
+            endpoint_name
+        );
+    }
+
+    /// Sets the pause flag directly, without events or caller checks.
+    /// Also clears any scheduled automatic unpause.
+    fn set_paused(&self, paused: bool) {
+        self.paused_flag().set(paused);
+        self.unpause_timestamp().clear();
+    }
+
+    /// Pauses the whole contract.
+    /// If an unpause timestamp is provided, the pause expires automatically once the block timestamp reaches it.
     #[endpoint(pause)]
-    fn pause_endpoint(&self) {
-        self.set_paused(true);
-        // TODO: event
+    fn pause_endpoint(&self, opt_unpause_timestamp: OptionalValue<u64>) {
+        let caller = self.require_caller_can_pause();
+        let unpause_timestamp = self.check_unpause_timestamp(opt_unpause_timestamp);
+
+        self.paused_flag().set(true);
+        self.unpause_timestamp().set(unpause_timestamp);
+
+        self.pause_contract_event(&caller, unpause_timestamp);
     }
 
     #[only_owner]
     #[endpoint(unpause)]
     fn unpause_endpoint(&self) {
         self.set_paused(false);
-        // TODO: event
+
+        let caller = self.blockchain().get_caller();
+        self.unpause_contract_event(&caller);
+    }
+
+    /// Pauses a single endpoint, identified by its name.
+    /// If an unpause timestamp is provided, the pause expires automatically once the block timestamp reaches it.
+    #[endpoint(pauseEndpoint)]
+    fn pause_single_endpoint(
+        &self,
+        endpoint_name: ManagedBuffer,
+        opt_unpause_timestamp: OptionalValue<u64>,
+    ) {
+        let caller = self.require_caller_can_pause();
+        let unpause_timestamp = self.check_unpause_timestamp(opt_unpause_timestamp);
+
+        self.endpoint_paused_flag(&endpoint_name).set(true);
+        self.endpoint_unpause_timestamp(&endpoint_name)
+            .set(unpause_timestamp);
+
+        self.pause_endpoint_event(&endpoint_name, &caller, unpause_timestamp);
     }
+
+    #[only_owner]
+    #[endpoint(unpauseEndpoint)]
+    fn unpause_single_endpoint(&self, endpoint_name: ManagedBuffer) {
+        self.endpoint_paused_flag(&endpoint_name).clear();
+        self.endpoint_unpause_timestamp(&endpoint_name).clear();
+
+        let caller = self.blockchain().get_caller();
+        self.unpause_endpoint_event(&endpoint_name, &caller);
+    }
+
+    /// The pause guardian can pause the contract or endpoints, but cannot unpause them.
+    /// Calling without arguments removes the guardian.
+    #[only_owner]
+    #[endpoint(setPauseGuardian)]
+    fn set_pause_guardian(&self, opt_guardian: OptionalValue<ManagedAddress>) {
+        match opt_guardian {
+            OptionalValue::Some(guardian) => self.pause_guardian().set(guardian),
+            OptionalValue::None => self.pause_guardian().clear(),
+        }
+    }
+
+    fn require_caller_can_pause(&self) -> ManagedAddress {
+        let caller = self.blockchain().get_caller();
+        let guardian_mapper = self.pause_guardian();
+        let is_guardian = !guardian_mapper.is_empty() && guardian_mapper.get() == caller;
+        require!(
+            is_guardian || caller == self.blockchain().get_owner_address(),
+            "Only owner or pause guardian can pause"
+        );
+        caller
+    }
+
+    /// Yields 0 if no timestamp was provided, meaning the pause lasts until explicitly lifted.
+    fn check_unpause_timestamp(&self, opt_unpause_timestamp: OptionalValue<u64>) -> u64 {
+        match opt_unpause_timestamp {
+            OptionalValue::Some(unpause_timestamp) => {
+                require!(
+                    unpause_timestamp > self.blockchain().get_block_timestamp(),
+                    "Unpause timestamp must be in the future"
+                );
+                unpause_timestamp
+            },
+            OptionalValue::None => 0,
+        }
+    }
+
+    fn is_pause_in_effect(&self, paused: bool, unpause_timestamp: u64) -> bool {
+        paused
+            && (unpause_timestamp == 0
+                || self.blockchain().get_block_timestamp() < unpause_timestamp)
+    }
+
+    #[storage_mapper("pause_module:paused")]
+    fn paused_flag(&self) -> SingleValueMapper<bool>;
+
+    #[view(getUnpauseTimestamp)]
+    #[storage_mapper("pause_module:unpause_timestamp")]
+    fn unpause_timestamp(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("pause_module:endpoint_paused")]
+    fn endpoint_paused_flag(&self, endpoint_name: &ManagedBuffer) -> SingleValueMapper<bool>;
+
+    #[view(getEndpointUnpauseTimestamp)]
+    #[storage_mapper("pause_module:endpoint_unpause_timestamp")]
+    fn endpoint_unpause_timestamp(&self, endpoint_name: &ManagedBuffer) -> SingleValueMapper<u64>;
+
+    #[view(getPauseGuardian)]
+    #[storage_mapper("pause_module:guardian")]
+    fn pause_guardian(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[event("pauseContract")]
+    fn pause_contract_event(&self, #[indexed] caller: &ManagedAddress, unpause_timestamp: u64);
+
+    #[event("unpauseContract")]
+    fn unpause_contract_event(&self, #[indexed] caller: &ManagedAddress);
+
+    #[event("pauseEndpoint")]
+    fn pause_endpoint_event(
+        &self,
+        #[indexed] endpoint_name: &ManagedBuffer,
+        #[indexed] caller: &ManagedAddress,
+        unpause_timestamp: u64,
+    );
+
+    #[event("unpauseEndpoint")]
+    fn unpause_endpoint_event(
+        &self,
+        #[indexed] endpoint_name: &ManagedBuffer,
+        #[indexed] caller: &ManagedAddress,
+    );
 }
@@ -0,0 +1,215 @@
+dharitri_wasm::imports!();
+
+pub mod timelock_action;
+use timelock_action::*;
+
+/// # Dharitri smart contract module - Timelock
+///
+/// Standard smart contract module that, when added to a smart contract, forces sensitive owner operations
+/// (e.g. upgrades, fee changes) to be announced some time before they take effect.
+///
+/// Instead of performing the operation directly, the owner schedules a contract call,
+/// which can only be executed after the configured minimum delay has passed.
+/// Until then, the scheduled call can be inspected by anyone and cancelled by the owner.
+///
+/// The module provides the following parameter:
+/// - `timelockMinDelay` - the minimum number of seconds between scheduling and executing an action.
+///   It is set once in `initTimelockModule`, afterwards it can only be changed through a scheduled action with the SC as destination.
+///
+/// The module also provides events:
+/// - `actionScheduled` - provides the action ID, the timestamp from which it can be executed and the action itself
+/// - `actionCanceled` and `actionExecuted` - provide the ID of the specific action
+///
+#[dharitri_wasm::module]
+pub trait TimelockModule {
+    // endpoints - owner-only
+
+    #[only_owner]
+    #[endpoint(initTimelockModule)]
+    fn init_timelock_module(&self, min_delay: u64) {
+        require!(
+            self.timelock_min_delay().is_empty(),
+            "Timelock module already initialized"
+        );
+
+        self.try_change_timelock_min_delay(min_delay);
+    }
+
+    /// Schedules a contract call, to be executed no earlier than `delay` seconds from now.
+    /// The delay cannot be lower than the configured minimum delay.
+    #[only_owner]
+    #[endpoint(scheduleAction)]
+    fn schedule_action(&self, delay: u64, action: TimelockActionAsMultiArg<Self::Api>) -> usize {
+        require!(
+            !self.timelock_min_delay().is_empty(),
+            "Timelock module not initialized"
+        );
+        require!(
+            delay >= self.timelock_min_delay().get(),
+            "Delay is lower than the minimum delay"
+        );
+
+        let action = TimelockAction::from_multiarg(action);
+        let ready_timestamp = self.blockchain().get_block_timestamp() + delay;
+        let action_id = self.scheduled_actions().len() + 1;
+
+        self.action_scheduled_event(action_id, ready_timestamp, &action);
+
+        let scheduled_action = ScheduledTimelockAction {
+            action,
+            ready_timestamp,
+        };
+        let _ = self.scheduled_actions().push(&scheduled_action);
+
+        action_id
+    }
+
+    #[only_owner]
+    #[endpoint(cancelAction)]
+    fn cancel_action(&self, action_id: usize) {
+        require!(
+            self.get_action_status(action_id) != TimelockActionStatus::None,
+            "Action does not exist"
+        );
+
+        self.scheduled_actions().clear_entry(action_id);
+
+        self.action_canceled_event(action_id);
+    }
+
+    #[only_owner]
+    #[endpoint(executeAction)]
+    fn execute_action(&self, action_id: usize) {
+        match self.get_action_status(action_id) {
+            TimelockActionStatus::None => sc_panic!("Action does not exist"),
+            TimelockActionStatus::Pending => sc_panic!("Action is in timelock. Try again later"),
+            TimelockActionStatus::Ready => {},
+        }
+
+        let action = self.scheduled_actions().get(action_id).action;
+        let gas_left = self.blockchain().get_gas_left();
+        require!(
+            gas_left > action.gas_limit,
+            "Not enough gas to execute action"
+        );
+
+        // cleared before execution, so the action cannot be re-entered
+        self.scheduled_actions().clear_entry(action_id);
+
+        let mut contract_call = self
+            .send()
+            .contract_call::<()>(action.dest_address, action.function_name)
+            .with_gas_limit(action.gas_limit);
+
+        if action.amount > 0 {
+            contract_call = contract_call.add_token_transfer(
+                action.token_id,
+                action.token_nonce,
+                action.amount,
+            );
+        }
+
+        for arg in &action.arguments {
+            contract_call.push_arg_managed_buffer(arg);
+        }
+
+        contract_call.transfer_execute();
+
+        self.action_executed_event(action_id);
+    }
+
+    // endpoints - these can only be called by the SC itself.
+    // i.e. only by scheduling and executing an action with the SC as dest and the respective func name
+
+    #[endpoint(changeTimelockMinDelay)]
+    fn change_timelock_min_delay(&self, new_value: u64) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            caller == self.blockchain().get_sc_address(),
+            "Only the SC itself may call this function"
+        );
+
+        self.try_change_timelock_min_delay(new_value);
+    }
+
+    // views
+
+    #[view(getActionStatus)]
+    fn get_action_status(&self, action_id: usize) -> TimelockActionStatus {
+        if !self.action_exists(action_id) {
+            return TimelockActionStatus::None;
+        }
+
+        let ready_timestamp = self.scheduled_actions().get(action_id).ready_timestamp;
+        if self.blockchain().get_block_timestamp() < ready_timestamp {
+            TimelockActionStatus::Pending
+        } else {
+            TimelockActionStatus::Ready
+        }
+    }
+
+    #[view(getActionReadyTimestamp)]
+    fn get_action_ready_timestamp(&self, action_id: usize) -> OptionalValue<u64> {
+        if !self.action_exists(action_id) {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(self.scheduled_actions().get(action_id).ready_timestamp)
+        }
+    }
+
+    #[view(getScheduledAction)]
+    fn get_scheduled_action(
+        &self,
+        action_id: usize,
+    ) -> OptionalValue<TimelockActionAsMultiArg<Self::Api>> {
+        if !self.action_exists(action_id) {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(
+                self.scheduled_actions()
+                    .get(action_id)
+                    .action
+                    .into_multiarg(),
+            )
+        }
+    }
+
+    // private
+
+    fn action_exists(&self, action_id: usize) -> bool {
+        action_id >= 1
+            && action_id <= self.scheduled_actions().len()
+            && !self.scheduled_actions().item_is_empty(action_id)
+    }
+
+    fn try_change_timelock_min_delay(&self, new_value: u64) {
+        require!(new_value != 0, "Timelock min delay can't be set to 0");
+
+        self.timelock_min_delay().set(new_value);
+    }
+
+    // events
+
+    #[event("actionScheduled")]
+    fn action_scheduled_event(
+        &self,
+        #[indexed] action_id: usize,
+        #[indexed] ready_timestamp: u64,
+        action: &TimelockAction<Self::Api>,
+    );
+
+    #[event("actionCanceled")]
+    fn action_canceled_event(&self, #[indexed] action_id: usize);
+
+    #[event("actionExecuted")]
+    fn action_executed_event(&self, #[indexed] action_id: usize);
+
+    // storage
+
+    #[view(getTimelockMinDelay)]
+    #[storage_mapper("timelock:minDelay")]
+    fn timelock_min_delay(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("timelock:scheduledActions")]
+    fn scheduled_actions(&self) -> VecMapper<ScheduledTimelockAction<Self::Api>>;
+}
@@ -0,0 +1,66 @@
+dharitri_wasm::imports!();
+dharitri_wasm::derive_imports!();
+
+pub type TimelockActionAsMultiArg<M> = MultiValue7<
+    u64,
+    ManagedAddress<M>,
+    TokenIdentifier<M>,
+    u64,
+    BigUint<M>,
+    ManagedBuffer<M>,
+    ManagedVec<M, ManagedBuffer<M>>,
+>;
+
+#[derive(TypeAbi, TopEncode, TopDecode, PartialEq)]
+pub enum TimelockActionStatus {
+    None,
+    Pending,
+    Ready,
+}
+
+/// A contract call, encoded so that it can be stored until it is allowed to be executed.
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct TimelockAction<M: ManagedTypeApi> {
+    pub gas_limit: u64,
+    pub dest_address: ManagedAddress<M>,
+    pub token_id: TokenIdentifier<M>,
+    pub token_nonce: u64,
+    pub amount: BigUint<M>,
+    pub function_name: ManagedBuffer<M>,
+    pub arguments: ManagedVec<M, ManagedBuffer<M>>,
+}
+
+impl<M: ManagedTypeApi> TimelockAction<M> {
+    pub fn from_multiarg(multiarg: TimelockActionAsMultiArg<M>) -> Self {
+        let (gas_limit, dest_address, token_id, token_nonce, amount, function_name, arguments) =
+            multiarg.into_tuple();
+        TimelockAction {
+            gas_limit,
+            dest_address,
+            token_id,
+            token_nonce,
+            amount,
+            function_name,
+            arguments,
+        }
+    }
+
+    pub fn into_multiarg(self) -> TimelockActionAsMultiArg<M> {
+        (
+            self.gas_limit,
+            self.dest_address,
+            self.token_id,
+            self.token_nonce,
+            self.amount,
+            self.function_name,
+            self.arguments,
+        )
+            .into()
+    }
+}
+
+#[derive(TypeAbi, TopEncode, TopDecode)]
+pub struct ScheduledTimelockAction<M: ManagedTypeApi> {
+    pub action: TimelockAction<M>,
+    pub ready_timestamp: u64,
+}
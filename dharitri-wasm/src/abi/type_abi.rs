@@ -18,6 +18,7 @@ pub trait TypeAbi {
                 docs: &[],
                 name: Self::type_name(),
                 contents: TypeContents::NotSpecified,
+                version: None,
             },
         );
     }
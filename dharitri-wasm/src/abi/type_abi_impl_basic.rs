@@ -2,6 +2,7 @@ use super::*;
 use crate::arrayvec::ArrayVec;
 use alloc::{
     boxed::Box,
+    collections::{BTreeMap, BTreeSet, VecDeque},
     string::{String, ToString},
     vec::Vec,
 };
@@ -71,6 +72,39 @@ impl<T: TypeAbi, const CAP: usize> TypeAbi for ArrayVec<T, CAP> {
     }
 }
 
+impl<T: TypeAbi> TypeAbi for VecDeque<T> {
+    fn type_name() -> TypeName {
+        <&[T]>::type_name()
+    }
+
+    fn provide_type_descriptions<TDC: TypeDescriptionContainer>(accumulator: &mut TDC) {
+        T::provide_type_descriptions(accumulator);
+    }
+}
+
+/// Encoded just like a list of its items, in ascending order.
+impl<T: TypeAbi> TypeAbi for BTreeSet<T> {
+    fn type_name() -> TypeName {
+        <&[T]>::type_name()
+    }
+
+    fn provide_type_descriptions<TDC: TypeDescriptionContainer>(accumulator: &mut TDC) {
+        T::provide_type_descriptions(accumulator);
+    }
+}
+
+/// Encoded just like a list of key-value tuples, in ascending key order.
+impl<K: TypeAbi, V: TypeAbi> TypeAbi for BTreeMap<K, V> {
+    fn type_name() -> TypeName {
+        <&[(K, V)]>::type_name()
+    }
+
+    fn provide_type_descriptions<TDC: TypeDescriptionContainer>(accumulator: &mut TDC) {
+        K::provide_type_descriptions(accumulator);
+        V::provide_type_descriptions(accumulator);
+    }
+}
+
 impl<T: TypeAbi> TypeAbi for Box<[T]> {
     fn type_name() -> TypeName {
         <&[T]>::type_name()
@@ -116,12 +150,14 @@ type_abi_name_only!(u16, "u16");
 type_abi_name_only!(u32, "u32");
 type_abi_name_only!(usize, "u32");
 type_abi_name_only!(u64, "u64");
+type_abi_name_only!(u128, "u128");
 
 type_abi_name_only!(i8, "i8");
 type_abi_name_only!(i16, "i16");
 type_abi_name_only!(i32, "i32");
 type_abi_name_only!(isize, "i32");
 type_abi_name_only!(i64, "i64");
+type_abi_name_only!(i128, "i128");
 
 type_abi_name_only!(core::num::NonZeroUsize, "NonZeroUsize");
 type_abi_name_only!(bool, "bool");
@@ -5,6 +5,8 @@ pub struct TypeDescription {
     pub docs: &'static [&'static str],
     pub name: String,
     pub contents: TypeContents,
+    /// Version byte written in front of the fields, for structs declared with `#[codec(version = N)]`.
+    pub version: Option<u8>,
 }
 
 impl TypeDescription {
@@ -16,6 +18,7 @@ impl TypeDescription {
         docs: &[],
         name: String::new(),
         contents: TypeContents::NotSpecified,
+        version: None,
     };
 }
 
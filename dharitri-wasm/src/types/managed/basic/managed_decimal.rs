@@ -0,0 +1,235 @@
+use crate::{
+    abi::{TypeAbi, TypeName},
+    api::{ErrorApiImpl, ManagedTypeApi},
+    err_msg,
+    formatter::{FormatByteReceiver, SCDisplay},
+    types::{BigUint, ManagedType},
+};
+use dharitri_codec::{
+    DecodeErrorHandler, EncodeErrorHandler, NestedDecode, NestedDecodeInput, NestedEncode,
+    NestedEncodeOutput, TopDecode, TopDecodeInput, TopEncode, TopEncodeOutput,
+};
+
+/// Decides what happens to the digits that do not fit in the result of an operation.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Rounding {
+    /// Drops the extra digits, rounding towards zero.
+    Down,
+    /// Rounds away from zero whenever any of the extra digits is not zero.
+    Up,
+    /// Rounds to the nearest value, ties go away from zero.
+    HalfUp,
+}
+
+/// Fixed-point decimal number, with `DECIMALS` digits after the decimal point.
+///
+/// Stored as the number of raw units, i.e. the value multiplied by `10^DECIMALS`.
+/// For instance, `1.2345` is held as `12345` in a `ManagedDecimal<M, 4>`.
+///
+/// It is encoded exactly like its raw units `BigUint`.
+pub struct ManagedDecimal<M: ManagedTypeApi, const DECIMALS: usize> {
+    data: BigUint<M>,
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: usize> ManagedDecimal<M, DECIMALS> {
+    #[inline]
+    pub fn from_raw_units(data: BigUint<M>) -> Self {
+        ManagedDecimal { data }
+    }
+
+    pub fn from_integer(integer: BigUint<M>) -> Self {
+        ManagedDecimal {
+            data: integer * Self::scaling_factor(),
+        }
+    }
+
+    #[inline]
+    pub fn zero() -> Self {
+        Self::from_raw_units(BigUint::zero())
+    }
+
+    /// `10^DECIMALS`, the number of raw units in `1`.
+    pub fn scaling_factor() -> BigUint<M> {
+        BigUint::from(10u32).pow(DECIMALS as u32)
+    }
+
+    #[inline]
+    pub fn decimals(&self) -> usize {
+        DECIMALS
+    }
+
+    #[inline]
+    pub fn raw_units(&self) -> &BigUint<M> {
+        &self.data
+    }
+
+    #[inline]
+    pub fn into_raw_units(self) -> BigUint<M> {
+        self.data
+    }
+
+    /// The integer part, all decimals are discarded.
+    pub fn trunc(&self) -> BigUint<M> {
+        &self.data / &Self::scaling_factor()
+    }
+
+    /// Converts to a different number of decimals.
+    /// Rounding only matters when decreasing the number of decimals.
+    pub fn rescale<const NEW_DECIMALS: usize>(
+        &self,
+        rounding: Rounding,
+    ) -> ManagedDecimal<M, NEW_DECIMALS> {
+        let data = if NEW_DECIMALS >= DECIMALS {
+            &self.data * &BigUint::from(10u32).pow((NEW_DECIMALS - DECIMALS) as u32)
+        } else {
+            let divisor = BigUint::from(10u32).pow((DECIMALS - NEW_DECIMALS) as u32);
+            div_with_rounding(&self.data, &divisor, rounding)
+        };
+        ManagedDecimal::from_raw_units(data)
+    }
+
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        if self.data < other.data {
+            None
+        } else {
+            Some(Self::from_raw_units(&self.data - &other.data))
+        }
+    }
+
+    pub fn mul_with_rounding(&self, other: &Self, rounding: Rounding) -> Self {
+        let product = &self.data * &other.data;
+        Self::from_raw_units(div_with_rounding(
+            &product,
+            &Self::scaling_factor(),
+            rounding,
+        ))
+    }
+
+    /// Returns `None` when dividing by zero.
+    pub fn checked_div(&self, other: &Self, rounding: Rounding) -> Option<Self> {
+        if other.data == 0u32 {
+            return None;
+        }
+        let numerator = &self.data * &Self::scaling_factor();
+        Some(Self::from_raw_units(div_with_rounding(
+            &numerator,
+            &other.data,
+            rounding,
+        )))
+    }
+
+    /// Signals an error when dividing by zero.
+    pub fn div_with_rounding(&self, other: &Self, rounding: Rounding) -> Self {
+        self.checked_div(other, rounding)
+            .unwrap_or_else(|| M::error_api_impl().signal_error(err_msg::DIVISION_BY_ZERO))
+    }
+}
+
+/// Assumes a non-zero denominator.
+fn div_with_rounding<M: ManagedTypeApi>(
+    numerator: &BigUint<M>,
+    denominator: &BigUint<M>,
+    rounding: Rounding,
+) -> BigUint<M> {
+    match rounding {
+        Rounding::Down => numerator / denominator,
+        Rounding::Up => (numerator + denominator - 1u32) / denominator,
+        Rounding::HalfUp => (numerator + &(denominator / 2u32)) / denominator,
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: usize> Clone for ManagedDecimal<M, DECIMALS> {
+    fn clone(&self) -> Self {
+        Self::from_raw_units(self.data.clone())
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: usize> Default for ManagedDecimal<M, DECIMALS> {
+    #[inline]
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: usize> TopEncode for ManagedDecimal<M, DECIMALS> {
+    #[inline]
+    fn top_encode_or_handle_err<O, H>(&self, output: O, h: H) -> Result<(), H::HandledErr>
+    where
+        O: TopEncodeOutput,
+        H: EncodeErrorHandler,
+    {
+        self.data.top_encode_or_handle_err(output, h)
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: usize> NestedEncode for ManagedDecimal<M, DECIMALS> {
+    #[inline]
+    fn dep_encode_or_handle_err<O, H>(&self, dest: &mut O, h: H) -> Result<(), H::HandledErr>
+    where
+        O: NestedEncodeOutput,
+        H: EncodeErrorHandler,
+    {
+        self.data.dep_encode_or_handle_err(dest, h)
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: usize> TopDecode for ManagedDecimal<M, DECIMALS> {
+    #[inline]
+    fn top_decode_or_handle_err<I, H>(input: I, h: H) -> Result<Self, H::HandledErr>
+    where
+        I: TopDecodeInput,
+        H: DecodeErrorHandler,
+    {
+        Ok(Self::from_raw_units(BigUint::top_decode_or_handle_err(
+            input, h,
+        )?))
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: usize> NestedDecode for ManagedDecimal<M, DECIMALS> {
+    #[inline]
+    fn dep_decode_or_handle_err<I, H>(input: &mut I, h: H) -> Result<Self, H::HandledErr>
+    where
+        I: NestedDecodeInput,
+        H: DecodeErrorHandler,
+    {
+        Ok(Self::from_raw_units(BigUint::dep_decode_or_handle_err(
+            input, h,
+        )?))
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: usize> TypeAbi for ManagedDecimal<M, DECIMALS> {
+    /// Same as the raw units, the number of decimals is not part of the encoding.
+    fn type_name() -> TypeName {
+        BigUint::<M>::type_name()
+    }
+}
+
+/// Prints all decimals, e.g. `1.2345` or `2.5000`.
+impl<M: ManagedTypeApi, const DECIMALS: usize> SCDisplay for ManagedDecimal<M, DECIMALS> {
+    fn fmt<F: FormatByteReceiver>(&self, f: &mut F) {
+        SCDisplay::fmt(&self.trunc(), f);
+        if DECIMALS == 0 {
+            return;
+        }
+
+        f.append_bytes(&b"."[..]);
+        let fractional_part = &self.data % &Self::scaling_factor();
+        let mut threshold = Self::scaling_factor() / 10u32;
+        while threshold > 1u32 && fractional_part < threshold {
+            f.append_bytes(&b"0"[..]);
+            threshold /= 10u32;
+        }
+        SCDisplay::fmt(&fractional_part, f);
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: usize> core::fmt::Debug for ManagedDecimal<M, DECIMALS> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ManagedDecimal")
+            .field("handle", &self.data.get_raw_handle())
+            .field("decimals", &DECIMALS)
+            .finish()
+    }
+}
@@ -0,0 +1,28 @@
+use core::cmp::Ordering;
+
+use crate::api::ManagedTypeApi;
+
+use super::ManagedDecimal;
+
+impl<M: ManagedTypeApi, const DECIMALS: usize> PartialEq for ManagedDecimal<M, DECIMALS> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.raw_units() == other.raw_units()
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: usize> Eq for ManagedDecimal<M, DECIMALS> {}
+
+impl<M: ManagedTypeApi, const DECIMALS: usize> PartialOrd for ManagedDecimal<M, DECIMALS> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: usize> Ord for ManagedDecimal<M, DECIMALS> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.raw_units().cmp(other.raw_units())
+    }
+}
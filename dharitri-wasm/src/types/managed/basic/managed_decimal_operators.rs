@@ -0,0 +1,105 @@
+use crate::{
+    api::ManagedTypeApi,
+    types::{ManagedDecimal, Rounding},
+};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+
+impl<M: ManagedTypeApi, const DECIMALS: usize> Add for ManagedDecimal<M, DECIMALS> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::from_raw_units(self.into_raw_units() + other.into_raw_units())
+    }
+}
+
+impl<'a, 'b, M: ManagedTypeApi, const DECIMALS: usize> Add<&'b ManagedDecimal<M, DECIMALS>>
+    for &'a ManagedDecimal<M, DECIMALS>
+{
+    type Output = ManagedDecimal<M, DECIMALS>;
+
+    fn add(self, other: &ManagedDecimal<M, DECIMALS>) -> ManagedDecimal<M, DECIMALS> {
+        ManagedDecimal::from_raw_units(self.raw_units() + other.raw_units())
+    }
+}
+
+/// Signals an error if the result would be negative, use `checked_sub` to avoid it.
+impl<M: ManagedTypeApi, const DECIMALS: usize> Sub for ManagedDecimal<M, DECIMALS> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::from_raw_units(self.into_raw_units() - other.into_raw_units())
+    }
+}
+
+impl<'a, 'b, M: ManagedTypeApi, const DECIMALS: usize> Sub<&'b ManagedDecimal<M, DECIMALS>>
+    for &'a ManagedDecimal<M, DECIMALS>
+{
+    type Output = ManagedDecimal<M, DECIMALS>;
+
+    fn sub(self, other: &ManagedDecimal<M, DECIMALS>) -> ManagedDecimal<M, DECIMALS> {
+        ManagedDecimal::from_raw_units(self.raw_units() - other.raw_units())
+    }
+}
+
+/// Rounds down, use `mul_with_rounding` for other rounding modes.
+impl<M: ManagedTypeApi, const DECIMALS: usize> Mul for ManagedDecimal<M, DECIMALS> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        self.mul_with_rounding(&other, Rounding::Down)
+    }
+}
+
+impl<'a, 'b, M: ManagedTypeApi, const DECIMALS: usize> Mul<&'b ManagedDecimal<M, DECIMALS>>
+    for &'a ManagedDecimal<M, DECIMALS>
+{
+    type Output = ManagedDecimal<M, DECIMALS>;
+
+    fn mul(self, other: &ManagedDecimal<M, DECIMALS>) -> ManagedDecimal<M, DECIMALS> {
+        self.mul_with_rounding(other, Rounding::Down)
+    }
+}
+
+/// Rounds down, use `div_with_rounding` or `checked_div` for other rounding modes.
+impl<M: ManagedTypeApi, const DECIMALS: usize> Div for ManagedDecimal<M, DECIMALS> {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        self.div_with_rounding(&other, Rounding::Down)
+    }
+}
+
+impl<'a, 'b, M: ManagedTypeApi, const DECIMALS: usize> Div<&'b ManagedDecimal<M, DECIMALS>>
+    for &'a ManagedDecimal<M, DECIMALS>
+{
+    type Output = ManagedDecimal<M, DECIMALS>;
+
+    fn div(self, other: &ManagedDecimal<M, DECIMALS>) -> ManagedDecimal<M, DECIMALS> {
+        self.div_with_rounding(other, Rounding::Down)
+    }
+}
+
+macro_rules! assign_operator {
+    ($trait:ident, $method:ident, $op:tt) => {
+        impl<M: ManagedTypeApi, const DECIMALS: usize> $trait for ManagedDecimal<M, DECIMALS> {
+            #[inline]
+            fn $method(&mut self, other: Self) {
+                *self = &*self $op &other;
+            }
+        }
+
+        impl<'b, M: ManagedTypeApi, const DECIMALS: usize> $trait<&'b ManagedDecimal<M, DECIMALS>>
+            for ManagedDecimal<M, DECIMALS>
+        {
+            #[inline]
+            fn $method(&mut self, other: &ManagedDecimal<M, DECIMALS>) {
+                *self = &*self $op other;
+            }
+        }
+    };
+}
+
+assign_operator! {AddAssign, add_assign, +}
+assign_operator! {SubAssign, sub_assign, -}
+assign_operator! {MulAssign, mul_assign, *}
+assign_operator! {DivAssign, div_assign, /}
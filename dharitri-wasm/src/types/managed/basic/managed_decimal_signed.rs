@@ -0,0 +1,226 @@
+use crate::{
+    abi::{TypeAbi, TypeName},
+    api::{ErrorApiImpl, ManagedTypeApi},
+    err_msg,
+    formatter::{FormatByteReceiver, SCDisplay},
+    types::{BigInt, ManagedDecimal, ManagedType, Rounding, Sign},
+};
+use dharitri_codec::{
+    DecodeErrorHandler, EncodeErrorHandler, NestedDecode, NestedDecodeInput, NestedEncode,
+    NestedEncodeOutput, TopDecode, TopDecodeInput, TopEncode, TopEncodeOutput,
+};
+
+/// Signed fixed-point decimal number, with `DECIMALS` digits after the decimal point.
+///
+/// The `BigInt` counterpart of `ManagedDecimal`, stored as the number of raw units.
+/// For instance, `-1.2345` is held as `-12345` in a `ManagedDecimalSigned<M, 4>`.
+///
+/// Rounding applies to the absolute value, so `Rounding::Down` rounds towards zero
+/// and `Rounding::Up` away from zero, for negative numbers too.
+///
+/// It is encoded exactly like its raw units `BigInt`.
+pub struct ManagedDecimalSigned<M: ManagedTypeApi, const DECIMALS: usize> {
+    data: BigInt<M>,
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: usize> ManagedDecimalSigned<M, DECIMALS> {
+    #[inline]
+    pub fn from_raw_units(data: BigInt<M>) -> Self {
+        ManagedDecimalSigned { data }
+    }
+
+    pub fn from_integer(integer: BigInt<M>) -> Self {
+        ManagedDecimalSigned {
+            data: integer * Self::scaling_factor(),
+        }
+    }
+
+    /// The reverse of `sign` and `magnitude`.
+    pub fn from_parts(sign: Sign, magnitude: ManagedDecimal<M, DECIMALS>) -> Self {
+        Self::from_raw_units(BigInt::from_biguint(sign, magnitude.into_raw_units()))
+    }
+
+    #[inline]
+    pub fn zero() -> Self {
+        Self::from_raw_units(BigInt::zero())
+    }
+
+    /// `10^DECIMALS`, the number of raw units in `1`.
+    pub fn scaling_factor() -> BigInt<M> {
+        BigInt::from_biguint(Sign::Plus, ManagedDecimal::<M, DECIMALS>::scaling_factor())
+    }
+
+    #[inline]
+    pub fn decimals(&self) -> usize {
+        DECIMALS
+    }
+
+    #[inline]
+    pub fn raw_units(&self) -> &BigInt<M> {
+        &self.data
+    }
+
+    #[inline]
+    pub fn into_raw_units(self) -> BigInt<M> {
+        self.data
+    }
+
+    #[inline]
+    pub fn sign(&self) -> Sign {
+        self.data.sign()
+    }
+
+    /// The absolute value, as an unsigned decimal.
+    pub fn magnitude(&self) -> ManagedDecimal<M, DECIMALS> {
+        ManagedDecimal::from_raw_units(self.data.magnitude())
+    }
+
+    /// Converts to an unsigned decimal, if it's not negative.
+    pub fn into_unsigned(self) -> Option<ManagedDecimal<M, DECIMALS>> {
+        self.data.into_biguint().map(ManagedDecimal::from_raw_units)
+    }
+
+    /// The integer part, all decimals are discarded, i.e. rounds towards zero.
+    pub fn trunc(&self) -> BigInt<M> {
+        &self.data / &Self::scaling_factor()
+    }
+
+    /// Converts to a different number of decimals.
+    /// Rounding only matters when decreasing the number of decimals.
+    pub fn rescale<const NEW_DECIMALS: usize>(
+        &self,
+        rounding: Rounding,
+    ) -> ManagedDecimalSigned<M, NEW_DECIMALS> {
+        ManagedDecimalSigned::from_parts(
+            self.sign(),
+            self.magnitude().rescale::<NEW_DECIMALS>(rounding),
+        )
+    }
+
+    pub fn mul_with_rounding(&self, other: &Self, rounding: Rounding) -> Self {
+        Self::from_parts(
+            self.product_sign(other),
+            self.magnitude()
+                .mul_with_rounding(&other.magnitude(), rounding),
+        )
+    }
+
+    /// Returns `None` when dividing by zero.
+    pub fn checked_div(&self, other: &Self, rounding: Rounding) -> Option<Self> {
+        let magnitude = self.magnitude().checked_div(&other.magnitude(), rounding)?;
+        Some(Self::from_parts(self.product_sign(other), magnitude))
+    }
+
+    /// Signals an error when dividing by zero.
+    pub fn div_with_rounding(&self, other: &Self, rounding: Rounding) -> Self {
+        self.checked_div(other, rounding)
+            .unwrap_or_else(|| M::error_api_impl().signal_error(err_msg::DIVISION_BY_ZERO))
+    }
+
+    /// The sign of a product or quotient, a zero magnitude ends up as zero anyway.
+    fn product_sign(&self, other: &Self) -> Sign {
+        if self.sign().is_minus() == other.sign().is_minus() {
+            Sign::Plus
+        } else {
+            Sign::Minus
+        }
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: usize> From<ManagedDecimal<M, DECIMALS>>
+    for ManagedDecimalSigned<M, DECIMALS>
+{
+    #[inline]
+    fn from(unsigned: ManagedDecimal<M, DECIMALS>) -> Self {
+        Self::from_parts(Sign::Plus, unsigned)
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: usize> Clone for ManagedDecimalSigned<M, DECIMALS> {
+    fn clone(&self) -> Self {
+        Self::from_raw_units(self.data.clone())
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: usize> Default for ManagedDecimalSigned<M, DECIMALS> {
+    #[inline]
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: usize> TopEncode for ManagedDecimalSigned<M, DECIMALS> {
+    #[inline]
+    fn top_encode_or_handle_err<O, H>(&self, output: O, h: H) -> Result<(), H::HandledErr>
+    where
+        O: TopEncodeOutput,
+        H: EncodeErrorHandler,
+    {
+        self.data.top_encode_or_handle_err(output, h)
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: usize> NestedEncode for ManagedDecimalSigned<M, DECIMALS> {
+    #[inline]
+    fn dep_encode_or_handle_err<O, H>(&self, dest: &mut O, h: H) -> Result<(), H::HandledErr>
+    where
+        O: NestedEncodeOutput,
+        H: EncodeErrorHandler,
+    {
+        self.data.dep_encode_or_handle_err(dest, h)
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: usize> TopDecode for ManagedDecimalSigned<M, DECIMALS> {
+    #[inline]
+    fn top_decode_or_handle_err<I, H>(input: I, h: H) -> Result<Self, H::HandledErr>
+    where
+        I: TopDecodeInput,
+        H: DecodeErrorHandler,
+    {
+        Ok(Self::from_raw_units(BigInt::top_decode_or_handle_err(
+            input, h,
+        )?))
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: usize> NestedDecode for ManagedDecimalSigned<M, DECIMALS> {
+    #[inline]
+    fn dep_decode_or_handle_err<I, H>(input: &mut I, h: H) -> Result<Self, H::HandledErr>
+    where
+        I: NestedDecodeInput,
+        H: DecodeErrorHandler,
+    {
+        Ok(Self::from_raw_units(BigInt::dep_decode_or_handle_err(
+            input, h,
+        )?))
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: usize> TypeAbi for ManagedDecimalSigned<M, DECIMALS> {
+    /// Same as the raw units, the number of decimals is not part of the encoding.
+    fn type_name() -> TypeName {
+        BigInt::<M>::type_name()
+    }
+}
+
+/// Prints all decimals, with a leading `-` for negative numbers, e.g. `-1.2345`.
+impl<M: ManagedTypeApi, const DECIMALS: usize> SCDisplay for ManagedDecimalSigned<M, DECIMALS> {
+    fn fmt<F: FormatByteReceiver>(&self, f: &mut F) {
+        if self.sign().is_minus() {
+            f.append_bytes(&b"-"[..]);
+        }
+        SCDisplay::fmt(&self.magnitude(), f);
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: usize> core::fmt::Debug
+    for ManagedDecimalSigned<M, DECIMALS>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ManagedDecimalSigned")
+            .field("handle", &self.data.get_raw_handle())
+            .field("decimals", &DECIMALS)
+            .finish()
+    }
+}
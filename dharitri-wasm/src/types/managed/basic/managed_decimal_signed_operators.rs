@@ -0,0 +1,112 @@
+use crate::{
+    api::ManagedTypeApi,
+    types::{ManagedDecimalSigned, Rounding},
+};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+impl<M: ManagedTypeApi, const DECIMALS: usize> Add for ManagedDecimalSigned<M, DECIMALS> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::from_raw_units(self.into_raw_units() + other.into_raw_units())
+    }
+}
+
+impl<'a, 'b, M: ManagedTypeApi, const DECIMALS: usize> Add<&'b ManagedDecimalSigned<M, DECIMALS>>
+    for &'a ManagedDecimalSigned<M, DECIMALS>
+{
+    type Output = ManagedDecimalSigned<M, DECIMALS>;
+
+    fn add(self, other: &ManagedDecimalSigned<M, DECIMALS>) -> ManagedDecimalSigned<M, DECIMALS> {
+        ManagedDecimalSigned::from_raw_units(self.raw_units() + other.raw_units())
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: usize> Sub for ManagedDecimalSigned<M, DECIMALS> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::from_raw_units(self.into_raw_units() - other.into_raw_units())
+    }
+}
+
+impl<'a, 'b, M: ManagedTypeApi, const DECIMALS: usize> Sub<&'b ManagedDecimalSigned<M, DECIMALS>>
+    for &'a ManagedDecimalSigned<M, DECIMALS>
+{
+    type Output = ManagedDecimalSigned<M, DECIMALS>;
+
+    fn sub(self, other: &ManagedDecimalSigned<M, DECIMALS>) -> ManagedDecimalSigned<M, DECIMALS> {
+        ManagedDecimalSigned::from_raw_units(self.raw_units() - other.raw_units())
+    }
+}
+
+/// Rounds towards zero, use `mul_with_rounding` for other rounding modes.
+impl<M: ManagedTypeApi, const DECIMALS: usize> Mul for ManagedDecimalSigned<M, DECIMALS> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        self.mul_with_rounding(&other, Rounding::Down)
+    }
+}
+
+impl<'a, 'b, M: ManagedTypeApi, const DECIMALS: usize> Mul<&'b ManagedDecimalSigned<M, DECIMALS>>
+    for &'a ManagedDecimalSigned<M, DECIMALS>
+{
+    type Output = ManagedDecimalSigned<M, DECIMALS>;
+
+    fn mul(self, other: &ManagedDecimalSigned<M, DECIMALS>) -> ManagedDecimalSigned<M, DECIMALS> {
+        self.mul_with_rounding(other, Rounding::Down)
+    }
+}
+
+/// Rounds towards zero, use `div_with_rounding` or `checked_div` for other rounding modes.
+impl<M: ManagedTypeApi, const DECIMALS: usize> Div for ManagedDecimalSigned<M, DECIMALS> {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        self.div_with_rounding(&other, Rounding::Down)
+    }
+}
+
+impl<'a, 'b, M: ManagedTypeApi, const DECIMALS: usize> Div<&'b ManagedDecimalSigned<M, DECIMALS>>
+    for &'a ManagedDecimalSigned<M, DECIMALS>
+{
+    type Output = ManagedDecimalSigned<M, DECIMALS>;
+
+    fn div(self, other: &ManagedDecimalSigned<M, DECIMALS>) -> ManagedDecimalSigned<M, DECIMALS> {
+        self.div_with_rounding(other, Rounding::Down)
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: usize> Neg for ManagedDecimalSigned<M, DECIMALS> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::from_raw_units(-self.into_raw_units())
+    }
+}
+
+macro_rules! assign_operator {
+    ($trait:ident, $method:ident, $op:tt) => {
+        impl<M: ManagedTypeApi, const DECIMALS: usize> $trait for ManagedDecimalSigned<M, DECIMALS> {
+            #[inline]
+            fn $method(&mut self, other: Self) {
+                *self = &*self $op &other;
+            }
+        }
+
+        impl<'b, M: ManagedTypeApi, const DECIMALS: usize> $trait<&'b ManagedDecimalSigned<M, DECIMALS>>
+            for ManagedDecimalSigned<M, DECIMALS>
+        {
+            #[inline]
+            fn $method(&mut self, other: &ManagedDecimalSigned<M, DECIMALS>) {
+                *self = &*self $op other;
+            }
+        }
+    };
+}
+
+assign_operator! {AddAssign, add_assign, +}
+assign_operator! {SubAssign, sub_assign, -}
+assign_operator! {MulAssign, mul_assign, *}
+assign_operator! {DivAssign, div_assign, /}
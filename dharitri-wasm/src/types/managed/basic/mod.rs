@@ -7,9 +7,17 @@ mod big_uint_cmp;
 mod big_uint_operators;
 mod elliptic_curve;
 mod managed_buffer;
+mod managed_decimal;
+mod managed_decimal_cmp;
+mod managed_decimal_operators;
+mod managed_decimal_signed;
+mod managed_decimal_signed_cmp;
+mod managed_decimal_signed_operators;
 
 pub use big_int::BigInt;
 pub use big_int_sign::Sign;
 pub use big_uint::BigUint;
 pub use elliptic_curve::{EllipticCurve, EllipticCurveComponents};
 pub use managed_buffer::ManagedBuffer;
+pub use managed_decimal::{ManagedDecimal, Rounding};
+pub use managed_decimal_signed::ManagedDecimalSigned;
@@ -7,8 +7,10 @@ use crate::{
         ManagedVecRefIterator,
     },
 };
+
+use super::managed_vec_item::MAX_PAYLOAD_SIZE;
 use alloc::vec::Vec;
-use core::{borrow::Borrow, marker::PhantomData};
+use core::{borrow::Borrow, cmp::Ordering, marker::PhantomData};
 use dharitri_codec::{
     DecodeErrorHandler, EncodeErrorHandler, NestedDecode, NestedDecodeInput, NestedEncode,
     NestedEncodeOutput, TopDecode, TopDecodeInput, TopEncode, TopEncodeMultiOutput,
@@ -265,6 +267,226 @@ where
     pub fn iter(&self) -> ManagedVecRefIterator<M, T> {
         ManagedVecRefIterator::new(self)
     }
+
+    /// Inserts an item at position `index`, shifting all items after it to the right.
+    /// Signals an error if `index > len`.
+    pub fn insert(&mut self, index: usize, item: T) {
+        item.to_byte_writer(|bytes| {
+            self.insert_payload(index, bytes);
+        });
+    }
+
+    /// Reverses the order of the items, in place.
+    pub fn reverse(&mut self) {
+        let len = self.len();
+        for i in 0..len / 2 {
+            self.swap_payloads(i, len - 1 - i);
+        }
+    }
+
+    /// Keeps only the items for which the predicate returns `true`, preserving their order.
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let len = self.len();
+        let mut retained_len = 0;
+        for i in 0..len {
+            let keep = f(self.get(i).borrow());
+            if keep {
+                if retained_len < i {
+                    self.copy_payload(i, retained_len);
+                }
+                retained_len += 1;
+            }
+        }
+        self.truncate_payloads(retained_len);
+    }
+
+    /// Stable sort, in place, using the given comparator.
+    ///
+    /// It is a binary insertion sort: `O(n log n)` comparisons, but `O(n^2)` payload moves in the worst case.
+    /// Payloads are shifted in place, one fixed-size slice at a time,
+    /// without heap allocation or reallocating the underlying buffer.
+    /// Prefer `sort_unstable_by` for long vecs, when stability is not needed.
+    pub fn sort_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let len = self.len();
+        for i in 1..len {
+            // the first `i` items are already sorted,
+            // item `i` goes after all items that are not greater than it, to keep the sort stable
+            let insert_index = {
+                let item = self.get(i);
+                let mut low = 0;
+                let mut high = i;
+                while low < high {
+                    let mid = (low + high) / 2;
+                    if compare(self.get(mid).borrow(), item.borrow()) == Ordering::Greater {
+                        high = mid;
+                    } else {
+                        low = mid + 1;
+                    }
+                }
+                low
+            };
+            if insert_index < i {
+                self.move_payload(i, insert_index);
+            }
+        }
+    }
+
+    /// Stable sort, in place, by the key extracted from each item.
+    /// The key gets extracted twice per comparison.
+    pub fn sort_by_key<K, F>(&mut self, mut f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.sort_by(|a, b| f(a).cmp(&f(b)));
+    }
+
+    /// Unstable sort, in place, using the given comparator.
+    ///
+    /// It is a heapsort: `O(n log n)` comparisons and swaps, without heap allocation.
+    pub fn sort_unstable_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let len = self.len();
+        for start in (0..len / 2).rev() {
+            self.sift_down(start, len, &mut compare);
+        }
+        for end in (1..len).rev() {
+            self.swap_payloads(0, end);
+            self.sift_down(0, end, &mut compare);
+        }
+    }
+
+    fn sift_down<F>(&mut self, mut root: usize, end: usize, compare: &mut F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        loop {
+            let mut child = 2 * root + 1;
+            if child >= end {
+                return;
+            }
+            if child + 1 < end
+                && compare(self.get(child).borrow(), self.get(child + 1).borrow()) == Ordering::Less
+            {
+                child += 1;
+            }
+            if compare(self.get(root).borrow(), self.get(child).borrow()) != Ordering::Less {
+                return;
+            }
+            self.swap_payloads(root, child);
+            root = child;
+        }
+    }
+
+    /// Binary search in a vec sorted according to the given comparator.
+    /// Same semantics as `slice::binary_search_by`:
+    /// `Ok` with the index of a matching item, or `Err` with the index where it could be inserted.
+    pub fn binary_search_by<F>(&self, mut f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        let mut low = 0;
+        let mut high = self.len();
+        while low < high {
+            let mid = (low + high) / 2;
+            match f(self.get(mid).borrow()) {
+                Ordering::Less => low = mid + 1,
+                Ordering::Greater => high = mid,
+                Ordering::Equal => return Ok(mid),
+            }
+        }
+        Err(low)
+    }
+
+    fn load_payload(&self, index: usize, dest: &mut [u8]) {
+        if self
+            .buffer
+            .load_slice(index * T::PAYLOAD_SIZE, dest)
+            .is_err()
+        {
+            M::error_api_impl().signal_error(INDEX_OUT_OF_RANGE_MSG);
+        }
+    }
+
+    fn store_payload(&mut self, index: usize, payload: &[u8]) {
+        if self
+            .buffer
+            .set_slice(index * T::PAYLOAD_SIZE, payload)
+            .is_err()
+        {
+            M::error_api_impl().signal_error(INDEX_OUT_OF_RANGE_MSG);
+        }
+    }
+
+    /// Items are swapped as raw payloads, so handles are not duplicated or lost.
+    fn swap_payloads(&mut self, i: usize, j: usize) {
+        if i == j {
+            return;
+        }
+        let mut payload_i_arr = [0u8; MAX_PAYLOAD_SIZE];
+        let mut payload_j_arr = [0u8; MAX_PAYLOAD_SIZE];
+        let payload_i = &mut payload_i_arr[..T::PAYLOAD_SIZE];
+        let payload_j = &mut payload_j_arr[..T::PAYLOAD_SIZE];
+        self.load_payload(i, payload_i);
+        self.load_payload(j, payload_j);
+        self.store_payload(i, payload_j);
+        self.store_payload(j, payload_i);
+    }
+
+    fn copy_payload(&mut self, from_index: usize, to_index: usize) {
+        let mut payload_arr = [0u8; MAX_PAYLOAD_SIZE];
+        let payload = &mut payload_arr[..T::PAYLOAD_SIZE];
+        self.load_payload(from_index, payload);
+        self.store_payload(to_index, payload);
+    }
+
+    /// Moves an item to an earlier position, shifting the ones in between to the right.
+    /// Works in place, the buffer keeps its length.
+    fn move_payload(&mut self, from_index: usize, to_index: usize) {
+        let mut payload_arr = [0u8; MAX_PAYLOAD_SIZE];
+        let payload = &mut payload_arr[..T::PAYLOAD_SIZE];
+        self.load_payload(from_index, payload);
+        for index in (to_index..from_index).rev() {
+            self.copy_payload(index, index + 1);
+        }
+        self.store_payload(to_index, payload);
+    }
+
+    fn insert_payload(&mut self, index: usize, payload: &[u8]) {
+        let byte_index = index * T::PAYLOAD_SIZE;
+        let byte_len = self.byte_len();
+        if byte_index > byte_len {
+            M::error_api_impl().signal_error(INDEX_OUT_OF_RANGE_MSG);
+        }
+        let mut new_buffer = match self.buffer.copy_slice(0, byte_index) {
+            Some(part_before) => part_before,
+            None => M::error_api_impl().signal_error(INDEX_OUT_OF_RANGE_MSG),
+        };
+        new_buffer.append_bytes(payload);
+        match self.buffer.copy_slice(byte_index, byte_len - byte_index) {
+            Some(part_after) => new_buffer.append(&part_after),
+            None => M::error_api_impl().signal_error(INDEX_OUT_OF_RANGE_MSG),
+        }
+        self.buffer = new_buffer;
+    }
+
+    /// Drops all items starting from `new_len`.
+    fn truncate_payloads(&mut self, new_len: usize) {
+        if new_len < self.len() {
+            self.buffer = match self.slice(0, new_len) {
+                Some(s) => s.buffer,
+                None => M::error_api_impl().signal_error(INDEX_OUT_OF_RANGE_MSG),
+            };
+        }
+    }
 }
 
 impl<M, T> Clone for ManagedVec<M, T>
@@ -385,6 +607,67 @@ where
     pub fn contains(&self, item: &T) -> bool {
         self.find(item).is_some()
     }
+
+    /// Removes consecutive repeated items, in place.
+    /// If the vec is sorted, this removes all duplicates.
+    pub fn dedup(&mut self) {
+        let len = self.len();
+        if len == 0 {
+            return;
+        }
+        let mut dedup_len = 1;
+        for i in 1..len {
+            let is_duplicate = self.get(i).borrow() == self.get(dedup_len - 1).borrow();
+            if !is_duplicate {
+                if dedup_len < i {
+                    self.copy_payload(i, dedup_len);
+                }
+                dedup_len += 1;
+            }
+        }
+        self.truncate_payloads(dedup_len);
+    }
+}
+
+impl<M, T> ManagedVec<M, T>
+where
+    M: ManagedTypeApi,
+    T: ManagedVecItem + PartialOrd,
+{
+    /// Checks that every item is less than or equal to the next one.
+    pub fn is_sorted(&self) -> bool {
+        let len = self.len();
+        for i in 1..len {
+            if self.get(i - 1).borrow() > self.get(i).borrow() {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+impl<M, T> ManagedVec<M, T>
+where
+    M: ManagedTypeApi,
+    T: ManagedVecItem + Ord,
+{
+    /// Stable sort, in place. See `sort_by`.
+    #[inline]
+    pub fn sort(&mut self) {
+        self.sort_by(T::cmp);
+    }
+
+    /// Unstable sort, in place. See `sort_unstable_by`.
+    #[inline]
+    pub fn sort_unstable(&mut self) {
+        self.sort_unstable_by(T::cmp);
+    }
+
+    /// Binary search for the given item, in a sorted vec. See `binary_search_by`.
+    #[inline]
+    pub fn binary_search(&self, item: &T) -> Result<usize, usize> {
+        self.binary_search_by(|probe| probe.cmp(item))
+    }
 }
 
 impl<M, T> TopDecode for ManagedVec<M, T>
@@ -11,7 +11,7 @@ use crate::{
 /// We assume that no payloads will exceed this value.
 /// This limit cannot be determined at compile-time for types with generics, due to current Rust compiler contraints.
 /// TODO: find a way to validate this assumption, if possible at compile time.
-const MAX_PAYLOAD_SIZE: usize = 200;
+pub(crate) const MAX_PAYLOAD_SIZE: usize = 200;
 
 /// Types that implement this trait can be items inside a `ManagedVec`.
 /// All these types need a payload, i.e a representation that gets stored